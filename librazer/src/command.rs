@@ -82,6 +82,19 @@ pub fn get_gpu_boost(device: &Device) -> Result<GpuBoost> {
 }
 
 pub fn set_fan_rpm(device: &Device, rpm: u16, check_mode: bool) -> Result<()> {
+    [FanZone::Zone1, FanZone::Zone2]
+        .into_iter()
+        .enumerate()
+        // The mode only needs checking once for both zones
+        .try_for_each(|(index, zone)| set_fan_zone_rpm(device, zone, rpm, check_mode && index == 0))
+}
+
+pub fn set_fan_zone_rpm(
+    device: &Device,
+    fan_zone: FanZone,
+    rpm: u16,
+    check_mode: bool,
+) -> Result<()> {
     ensure!((0..=5500).contains(&rpm));
     if check_mode {
         ensure!(
@@ -90,9 +103,7 @@ pub fn set_fan_rpm(device: &Device, rpm: u16, check_mode: bool) -> Result<()> {
             FanMode::Manual
         );
     }
-    [FanZone::Zone1, FanZone::Zone2].into_iter().try_for_each(|zone| {
-        _send_command(device, 0x0d01, &[0, zone as u8, (rpm / 100) as u8]).map(|_| ())
-    })
+    _send_command(device, 0x0d01, &[0, fan_zone as u8, (rpm / 100) as u8]).map(|_| ())
 }
 
 pub fn get_fan_rpm(device: &Device, fan_zone: FanZone) -> Result<u16> {
@@ -107,6 +118,13 @@ pub fn get_fan_actual_rpm(device: &Device, fan_zone: FanZone) -> Result<u16> {
     Ok(response.get_args()[2] as u16 * 100)
 }
 
+/// Firmware version as (major, minor)
+pub fn get_firmware_version(device: &Device) -> Result<(u8, u8)> {
+    let response = device.send(Packet::new(0x0081, &[0, 0]))?;
+    let args = response.get_args();
+    Ok((args[0], args[1]))
+}
+
 pub fn send_command(device: &Device, command: u16, args: &[u8]) -> Result<Packet> {
     let response = device.send(Packet::new(command, args))?;
     Ok(response)
@@ -196,6 +214,35 @@ pub fn set_keyboard_brightness(device: &Device, brightness: u8) -> Result<()> {
     Ok(())
 }
 
+/// Backlight power, separate from brightness (brightness 0 is still "on")
+pub fn get_keyboard_backlight(device: &Device) -> Result<bool> {
+    let response = device.send(Packet::new(0x0380, &[1, 5, 0]))?;
+    ensure!(response.get_args()[1] == 5);
+    match response.get_args()[2] {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => bail!("Invalid keyboard backlight state"),
+    }
+}
+
+pub fn set_keyboard_backlight(device: &Device, on: bool) -> Result<()> {
+    _send_command(device, 0x0300, &[1, 5, on as u8]).map(|_| ())
+}
+
+pub fn get_keyboard_color(device: &Device) -> Result<[u8; 3]> {
+    let response = device.send(Packet::new(0x0381, &[1, 5, 0, 0, 0]))?;
+    let args = response.get_args();
+    ensure!(args[1] == 5);
+    Ok([args[2], args[3], args[4]])
+}
+
+/// Set a single static color for the whole keyboard backlight
+pub fn set_keyboard_color(device: &Device, rgb: [u8; 3]) -> Result<()> {
+    _send_command(device, 0x0301, &[1, 5, rgb[0], rgb[1], rgb[2]])?;
+    _send_command(device, 0x0302, &[1, 5, 0])?;
+    Ok(())
+}
+
 pub fn get_lights_always_on(device: &Device) -> Result<LightsAlwaysOn> {
     device.send(Packet::new(0x0084, &[0, 0]))?.get_args()[0].try_into()
 }
@@ -19,6 +19,11 @@ pub struct Descriptor {
 
     // Optional list of disallowed (CPU,GPU) boost combinations
     pub disallowed_boost_pairs: Option<&'static [(CpuBoost, GpuBoost)]>,
+
+    // Optional (min, max) manual fan RPM the firmware accepts (if not listed, app defaults).
+    // No model lists one yet: every model uses the app's defaults, and this is only a hook
+    // for a model found to need other limits.
+    pub fan_rpm_range: Option<(u16, u16)>,
 }
 pub const SUPPORTED: &[Descriptor] = &[
     Descriptor {
@@ -36,6 +41,7 @@ pub const SUPPORTED: &[Descriptor] = &[
         cpu_boosts: None,
         gpu_boosts: None,
         disallowed_boost_pairs: None,
+        fan_rpm_range: None,
     },
     Descriptor {
         model_number_prefix: "RZ09-0421",
@@ -47,6 +53,7 @@ pub const SUPPORTED: &[Descriptor] = &[
         cpu_boosts: None,
         gpu_boosts: None,
         disallowed_boost_pairs: None,
+        fan_rpm_range: None,
     },
     Descriptor {
         model_number_prefix: "RZ09-0423",
@@ -63,6 +70,7 @@ pub const SUPPORTED: &[Descriptor] = &[
         cpu_boosts: None,
         gpu_boosts: None,
         disallowed_boost_pairs: None,
+        fan_rpm_range: None,
     },
     Descriptor {
         model_number_prefix: "RZ09-0482",
@@ -74,6 +82,7 @@ pub const SUPPORTED: &[Descriptor] = &[
         cpu_boosts: None,
         gpu_boosts: None,
         disallowed_boost_pairs: None,
+        fan_rpm_range: None,
     },
     Descriptor {
         model_number_prefix: "RZ09-0483",
@@ -85,6 +94,7 @@ pub const SUPPORTED: &[Descriptor] = &[
         cpu_boosts: None,
         gpu_boosts: None,
         disallowed_boost_pairs: None,
+        fan_rpm_range: None,
     },
     Descriptor {
         model_number_prefix: "RZ09-0528",
@@ -105,6 +115,7 @@ pub const SUPPORTED: &[Descriptor] = &[
         disallowed_boost_pairs: Some(&[
             (CpuBoost::High, GpuBoost::High),
         ]),
+        fan_rpm_range: None,
     },
     Descriptor {
         model_number_prefix: "RZ09-05306",
@@ -125,6 +136,7 @@ pub const SUPPORTED: &[Descriptor] = &[
         disallowed_boost_pairs: Some(&[
             (CpuBoost::High, GpuBoost::High),
         ]),
+        fan_rpm_range: None,
     },
 ];
 
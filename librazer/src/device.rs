@@ -2,8 +2,13 @@ use crate::descriptor::{Descriptor, SUPPORTED};
 use crate::packet::Packet;
 
 use anyhow::{anyhow, Context, Result};
+use std::sync::Mutex;
 use std::{thread, time};
 
+// Handles opened on different threads share the device's single feature report, so each
+// request/response pair must finish before another handle sends
+static TRANSACTION: Mutex<()> = Mutex::new(());
+
 pub struct Device {
     device: hidapi::HidDevice,
     pub info: Descriptor,
@@ -23,7 +28,7 @@ fn read_device_model() -> Result<String> {
 }
 
 impl Device {
-    const RAZER_VID: u16 = 0x1532;
+    pub const RAZER_VID: u16 = 0x1532;
 
     pub fn info(&self) -> &Descriptor {
         &self.info
@@ -53,6 +58,7 @@ impl Device {
         const MAX_RETRIES: usize = 5;
 
         for attempt in 0..MAX_RETRIES {
+            let transaction = TRANSACTION.lock().unwrap_or_else(|e| e.into_inner());
             thread::sleep(time::Duration::from_micros(1000));
 
             self.device
@@ -60,7 +66,7 @@ impl Device {
                     [0_u8; 1] // report id
                         .iter()
                         .copied()
-                        .chain(Into::<Vec<u8>>::into(&report).into_iter())
+                        .chain(Into::<Vec<u8>>::into(&report))
                         .collect::<Vec<_>>()
                         .as_slice(),
                 )
@@ -82,6 +88,7 @@ impl Device {
             } else if attempt == MAX_RETRIES - 1 {
                 return Err(anyhow!("Failed to match report after {} attempts", MAX_RETRIES));
             }
+            drop(transaction);
 
             // Add a small delay before retrying
             thread::sleep(time::Duration::from_millis(500));
@@ -115,7 +122,7 @@ impl Device {
 
         match SUPPORTED
             .iter()
-            .find(|supported| model_number_prefix.starts_with(&supported.model_number_prefix))
+            .find(|supported| model_number_prefix.starts_with(supported.model_number_prefix))
         {
             Some(supported) => Device::new(supported.clone()),
             None => anyhow::bail!(
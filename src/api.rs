@@ -0,0 +1,218 @@
+// Local HTTP endpoint for external automation (Stream Deck, scripts).
+//
+// `GET /state` returns the device state as JSON; `POST /perf {"mode":"Balanced"}` and
+// `POST /profile {"name":"Gaming"}` are forwarded to the UI thread. Every request must send
+// `Authorization: Bearer <token>` with the token from the settings.
+
+use crate::device::CompleteDeviceState;
+use anyhow::{Context, Result};
+use eframe::egui;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// How often the accept loop checks whether it should stop
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// A client that stalls mid-request is dropped after this long
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(2);
+/// Larger bodies are rejected; commands are a few bytes of JSON
+const MAX_BODY_BYTES: usize = 4096;
+
+/// Commands forwarded from the endpoint to the app
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiCommand {
+    SetPerformanceMode(String),
+    ApplyProfile(String),
+}
+
+pub struct ApiServer {
+    port: u16,
+    token: Arc<Mutex<String>>,
+    receiver: mpsc::Receiver<ApiCommand>,
+    /// Latest device state as served by `GET /state`
+    state: Arc<Mutex<Option<CompleteDeviceState>>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ApiServer {
+    /// Bind to 127.0.0.1:`port` and serve requests on a background thread
+    pub fn start(ctx: &egui::Context, port: u16, token: &str) -> Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+            .with_context(|| format!("Failed to listen on port {}", port))?;
+        listener.set_nonblocking(true)?;
+
+        let (sender, receiver) = mpsc::channel();
+        let state = Arc::new(Mutex::new(None));
+        let stop = Arc::new(AtomicBool::new(false));
+        let token = Arc::new(Mutex::new(token.to_string()));
+
+        let handler =
+            RequestHandler { token: token.clone(), sender, state: state.clone(), ctx: ctx.clone() };
+        let thread_stop = stop.clone();
+        let thread = std::thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        if let Err(e) = handler.handle(stream) {
+                            crate::utils::log_debug(&format!("Automation request failed: {}", e));
+                        }
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                        std::thread::sleep(ACCEPT_POLL_INTERVAL);
+                    }
+                    Err(e) => {
+                        crate::utils::log_error(&format!("Automation endpoint stopped: {}", e));
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Self { port, token, receiver, state, stop, thread: Some(thread) })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Replace the shared token; takes effect for the next request
+    pub fn set_token(&self, token: &str) {
+        if let Ok(mut current) = self.token.lock() {
+            if *current != token {
+                *current = token.to_string();
+            }
+        }
+    }
+
+    /// Next pending command, if any (non-blocking)
+    pub fn try_recv(&self) -> Option<ApiCommand> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Update the state served by `GET /state`
+    pub fn publish_state(&self, current: Option<&CompleteDeviceState>) {
+        if let Ok(mut state) = self.state.lock() {
+            if state.as_ref() != current {
+                *state = current.cloned();
+            }
+        }
+    }
+}
+
+impl Drop for ApiServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        // Wait for the listener to close so the port can be bound again right away
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+struct RequestHandler {
+    token: Arc<Mutex<String>>,
+    sender: mpsc::Sender<ApiCommand>,
+    state: Arc<Mutex<Option<CompleteDeviceState>>>,
+    ctx: egui::Context,
+}
+
+impl RequestHandler {
+    fn handle(&self, stream: TcpStream) -> Result<()> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+        stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_string();
+        let path = parts.next().unwrap_or_default().to_string();
+
+        let token = self.token.lock().map(|token| token.clone()).unwrap_or_default();
+        let mut content_length = 0usize;
+        let mut authorized = false;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+                break;
+            }
+            let Some((name, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.parse().unwrap_or(0);
+            } else if name.eq_ignore_ascii_case("authorization") {
+                authorized = !token.is_empty()
+                    && value.strip_prefix("Bearer ").is_some_and(|sent| sent == token);
+            }
+        }
+
+        if !authorized {
+            return respond(stream, 401, "Unauthorized", r#"{"error":"invalid token"}"#);
+        }
+        if content_length > MAX_BODY_BYTES {
+            return respond(stream, 413, "Payload Too Large", r#"{"error":"body too large"}"#);
+        }
+        let mut body = vec![0u8; content_length];
+        reader.read_exact(&mut body)?;
+
+        match (method.as_str(), path.as_str()) {
+            ("GET", "/state") => {
+                let state = self.state.lock().ok().and_then(|state| state.clone());
+                match state {
+                    Some(state) => respond(stream, 200, "OK", &serde_json::to_string(&state)?),
+                    None => respond(
+                        stream,
+                        503,
+                        "Service Unavailable",
+                        r#"{"error":"no device connected"}"#,
+                    ),
+                }
+            }
+            ("POST", "/perf") => match json_field(&body, "mode") {
+                Some(mode) => self.forward(stream, ApiCommand::SetPerformanceMode(mode)),
+                None => respond(stream, 400, "Bad Request", r#"{"error":"expected {\"mode\":…}"}"#),
+            },
+            ("POST", "/profile") => match json_field(&body, "name") {
+                Some(name) => self.forward(stream, ApiCommand::ApplyProfile(name)),
+                None => respond(stream, 400, "Bad Request", r#"{"error":"expected {\"name\":…}"}"#),
+            },
+            (_, "/state" | "/perf" | "/profile") => {
+                respond(stream, 405, "Method Not Allowed", r#"{"error":"method not allowed"}"#)
+            }
+            _ => respond(stream, 404, "Not Found", r#"{"error":"not found"}"#),
+        }
+    }
+
+    /// Hand a command to the UI thread; it is applied (and reported) there
+    fn forward(&self, stream: TcpStream, command: ApiCommand) -> Result<()> {
+        self.sender.send(command).context("App is shutting down")?;
+        self.ctx.request_repaint();
+        respond(stream, 202, "Accepted", r#"{"status":"accepted"}"#)
+    }
+}
+
+/// String field of a JSON object body
+fn json_field(body: &[u8], field: &str) -> Option<String> {
+    let json: serde_json::Value = serde_json::from_slice(body).ok()?;
+    json.get(field)?.as_str().map(str::to_string)
+}
+
+fn respond(mut stream: TcpStream, status: u16, reason: &str, body: &str) -> Result<()> {
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes())?;
+    stream.flush()?;
+    Ok(())
+}
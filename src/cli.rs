@@ -0,0 +1,166 @@
+//! Headless command-line mode
+//!
+//! `rhelper --apply-profile Gaming` (and friends) talk to the device directly and exit
+//! without opening a window, so profiles can be applied from shortcuts or scheduled tasks.
+
+use crate::config;
+use crate::device::CompleteDeviceState;
+use anyhow::{Context, Result};
+use clap::Parser;
+use librazer::types::{FanMode, PerfMode};
+
+#[derive(Parser, Debug)]
+#[command(name = "rhelper", version, about = "R-Helper - Razer Blade control interface")]
+struct CliArgs {
+    /// Apply a saved profile by name ("AC" and "Battery" select the power profiles)
+    #[arg(long, value_name = "NAME")]
+    apply_profile: Option<String>,
+
+    /// Set the performance mode
+    #[arg(long, value_enum, value_name = "MODE")]
+    set_perf: Option<PerfMode>,
+
+    /// Set the fans to "auto" or a fixed RPM
+    #[arg(long, value_name = "auto|RPM", value_parser = parse_fan_setting)]
+    fan: Option<FanSetting>,
+
+    /// List saved profiles
+    #[arg(long)]
+    list_profiles: bool,
+
+    /// Open the window read-only: readouts keep updating, nothing is written to the device
+    #[arg(long, conflicts_with_all = ["apply_profile", "set_perf", "fan", "list_profiles"])]
+    monitor: bool,
+
+    /// Start hidden to the tray (minimized when there is no tray icon)
+    #[arg(long, conflicts_with_all = ["apply_profile", "set_perf", "fan", "list_profiles"])]
+    minimized: bool,
+}
+
+/// What `main` should do after looking at the command line
+pub enum Launch {
+    /// Open the window; `monitor_only` disables every device write, `minimized` starts hidden
+    Gui { monitor_only: bool, minimized: bool },
+    /// A headless command ran; exit with this code
+    Exit(i32),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum FanSetting {
+    Auto,
+    Manual(u16),
+}
+
+fn parse_fan_setting(value: &str) -> Result<FanSetting, String> {
+    if value.eq_ignore_ascii_case("auto") {
+        return Ok(FanSetting::Auto);
+    }
+    // The range depends on the model, so `r_helper::set_fan_rpm` checks it once detected
+    let rpm: u16 =
+        value.parse().map_err(|_| format!("expected \"auto\" or an RPM, got '{}'", value))?;
+    Ok(FanSetting::Manual(rpm))
+}
+
+/// Run the CLI when arguments were given.
+/// Launches the GUI when started without arguments or with only `--monitor`/`--minimized`.
+pub fn run_from_args() -> Launch {
+    if std::env::args_os().len() <= 1 {
+        return Launch::Gui { monitor_only: false, minimized: false };
+    }
+
+    attach_parent_console();
+    let args = CliArgs::parse();
+    if args.monitor || args.minimized {
+        return Launch::Gui { monitor_only: args.monitor, minimized: args.minimized };
+    }
+
+    match run(&args) {
+        Ok(()) => Launch::Exit(0),
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            Launch::Exit(1)
+        }
+    }
+}
+
+fn run(args: &CliArgs) -> Result<()> {
+    let profiles = config::load_config().profiles;
+
+    if args.list_profiles {
+        println!("AC: {}", describe_profile(&profiles.ac));
+        println!("Battery: {}", describe_profile(&profiles.battery));
+        for (name, profile) in &profiles.named {
+            println!("{}: {}", name, describe_profile(profile));
+        }
+    }
+
+    if args.apply_profile.is_none() && args.set_perf.is_none() && args.fan.is_none() {
+        return Ok(());
+    }
+
+    let device = r_helper::detect()?;
+
+    if let Some(name) = &args.apply_profile {
+        let profile = find_profile(&profiles, name)
+            .with_context(|| format!("No saved profile named '{}'", name))?;
+        r_helper::apply_profile(&device, profile)?;
+        println!("Applied profile '{}'", name);
+    }
+
+    if let Some(perf_mode) = args.set_perf {
+        r_helper::set_perf_mode(&device, perf_mode)?;
+        println!("Performance mode set to {:?}", perf_mode);
+    }
+
+    match args.fan {
+        Some(FanSetting::Auto) => {
+            r_helper::set_fan_auto(&device)?;
+            println!("Fans set to Auto");
+        }
+        Some(FanSetting::Manual(rpm)) => {
+            r_helper::set_fan_rpm(&device, rpm)?;
+            println!("Fans set to {} RPM", rpm);
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+fn find_profile<'a>(
+    profiles: &'a config::StoredProfiles,
+    name: &str,
+) -> Option<&'a CompleteDeviceState> {
+    if let Some((_, profile)) = profiles.named.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)) {
+        return Some(profile);
+    }
+    match name.to_ascii_lowercase().as_str() {
+        "ac" => Some(&profiles.ac),
+        "battery" => Some(&profiles.battery),
+        _ => None,
+    }
+}
+
+fn describe_profile(profile: &CompleteDeviceState) -> String {
+    let fan = match profile.fan_rpm {
+        Some(rpm) if profile.fan_mode == FanMode::Manual => format!("{} RPM", rpm),
+        _ => "Auto".to_string(),
+    };
+    let boosts = match (profile.perf_mode, profile.cpu_boost, profile.gpu_boost) {
+        (PerfMode::Custom, Some(cpu), Some(gpu)) => format!(" (CPU {:?}, GPU {:?})", cpu, gpu),
+        _ => String::new(),
+    };
+    format!("{:?}{}, fan {}", profile.perf_mode, boosts, fan)
+}
+
+/// The GUI build has no console; reuse the launching terminal's so output is visible
+#[cfg(target_os = "windows")]
+fn attach_parent_console() {
+    use windows::Win32::System::Console::{AttachConsole, ATTACH_PARENT_PROCESS};
+    unsafe {
+        let _ = AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn attach_parent_console() {}
@@ -0,0 +1,581 @@
+//! Persistent configuration
+//!
+//! Stores user profiles and app settings together in one versioned `config.json` under
+//! `%APPDATA%\r-helper` so they survive restarts. Older layouts are migrated on load.
+
+use crate::device::{CompleteDeviceState, FanCurve};
+use crate::hotkey::DEFAULT_CYCLE_HOTKEY;
+use crate::i18n::Language;
+use crate::messaging::MessageDurations;
+use crate::power::PowerPlan;
+use crate::system::{SystemSpecs, TempUnit, Theme};
+use crate::utils;
+pub use crate::utils::config_dir;
+use anyhow::{Context, Result};
+use librazer::types::{LogoMode, PerfMode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CONFIG_FILE_NAME: &str = "config.json";
+/// Where an unreadable `config.json` is moved so the next save does not overwrite it
+const INVALID_CONFIG_FILE_NAME: &str = "config.json.invalid";
+/// Separate files written before `config.json`; read once to migrate
+const LEGACY_PROFILES_FILE_NAME: &str = "profiles.json";
+const LEGACY_SETTINGS_FILE_NAME: &str = "settings.json";
+const SPECS_CACHE_FILE_NAME: &str = "specs_cache.json";
+/// Extension of shared profile files
+pub const PROFILE_FILE_EXTENSION: &str = "rhp";
+
+/// Current `AppConfig::version`; bump it and extend [`migrate`] when the layout changes
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Range of the UI scale setting, in physical pixels per point
+pub const UI_SCALE_MIN: f32 = 0.8;
+pub const UI_SCALE_MAX: f32 = 2.0;
+
+// ============================================================================
+// App Config
+// ============================================================================
+
+/// Everything persisted between launches, written as a whole
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    /// Layout version of the file; 0 when missing (written before versioning)
+    pub version: u32,
+    pub settings: Settings,
+    pub profiles: StoredProfiles,
+    /// Set when `config.json` could not be read and defaults were loaded instead
+    #[serde(skip)]
+    pub load_error: Option<LoadError>,
+}
+
+/// An unreadable `config.json` replaced by defaults, reported once at startup
+#[derive(Debug, Clone)]
+pub struct LoadError {
+    /// Where the file was moved; `None` when moving it failed as well
+    pub backup: Option<PathBuf>,
+}
+
+/// Load the config, migrating older layouts; falls back to defaults when there is none.
+/// A file that does not parse (e.g. from a newer release) is moved aside first.
+pub fn load_config() -> AppConfig {
+    let default = || AppConfig { version: CONFIG_VERSION, ..AppConfig::default() };
+    match read_config() {
+        Ok(Some(config)) => config,
+        Ok(None) => load_legacy_config().unwrap_or_else(default),
+        Err(e) => {
+            utils::log_error(&format!("Ignoring invalid config file {:#}", e));
+            let backup =
+                move_invalid_config().map_err(|e| utils::log_error(&format!("{:#}", e))).ok();
+            AppConfig { load_error: Some(LoadError { backup }), ..default() }
+        }
+    }
+}
+
+/// Rename `config.json` to `INVALID_CONFIG_FILE_NAME`, replacing an older one
+fn move_invalid_config() -> Result<PathBuf> {
+    let dir = config_dir().context("Config directory not available")?;
+    let backup = dir.join(INVALID_CONFIG_FILE_NAME);
+    fs::rename(dir.join(CONFIG_FILE_NAME), &backup)
+        .with_context(|| format!("Failed to move invalid config to {}", backup.display()))?;
+    Ok(backup)
+}
+
+/// Parse and migrate `config.json`; `Ok(None)` when there is none
+fn read_config() -> Result<Option<AppConfig>> {
+    let Some(value) = read_json::<serde_json::Value>(CONFIG_FILE_NAME)? else {
+        return Ok(None);
+    };
+
+    let version = value.get("version").and_then(|version| version.as_u64()).unwrap_or(0) as u32;
+    if version > CONFIG_VERSION {
+        // Written by a newer release; read what this one understands
+        eprintln!("Config version {} is newer than supported {}", version, CONFIG_VERSION);
+    }
+    serde_json::from_value(migrate(value, version)).map(Some).context(CONFIG_FILE_NAME)
+}
+
+/// Save the whole config, stamped with the current version
+pub fn save_config(config: &AppConfig) -> Result<()> {
+    let config = AppConfig { version: CONFIG_VERSION, ..config.clone() };
+    save_json(CONFIG_FILE_NAME, &config)
+}
+
+/// Upgrade an older layout step by step to `CONFIG_VERSION`
+fn migrate(mut value: serde_json::Value, version: u32) -> serde_json::Value {
+    if version < 1 {
+        // Version 1 introduced the field itself; the layout is unchanged
+        value["version"] = serde_json::json!(1);
+    }
+    value
+}
+
+/// Combine the separate settings/profiles files of earlier releases into one config and
+/// write it, so later launches only read `config.json`
+fn load_legacy_config() -> Option<AppConfig> {
+    let settings = load_json::<Settings>(LEGACY_SETTINGS_FILE_NAME);
+    let profiles = load_json::<StoredProfiles>(LEGACY_PROFILES_FILE_NAME);
+    if settings.is_none() && profiles.is_none() {
+        return None;
+    }
+
+    let config = AppConfig {
+        version: CONFIG_VERSION,
+        settings: settings.unwrap_or_default(),
+        profiles: profiles.unwrap_or_default(),
+        load_error: None,
+    };
+    if let Err(e) = save_config(&config) {
+        eprintln!("Failed to migrate config: {}", e);
+    }
+    Some(config)
+}
+
+// ============================================================================
+// Profiles
+// ============================================================================
+
+/// AC/Battery and user-named profiles as stored on disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredProfiles {
+    pub ac: CompleteDeviceState,
+    pub battery: CompleteDeviceState,
+    #[serde(default)]
+    pub named: Vec<(String, CompleteDeviceState)>,
+}
+
+impl Default for StoredProfiles {
+    fn default() -> Self {
+        Self {
+            ac: CompleteDeviceState::default(),
+            battery: CompleteDeviceState {
+                perf_mode: PerfMode::Battery,
+                ..CompleteDeviceState::default()
+            },
+            named: Vec::new(),
+        }
+    }
+}
+
+/// Write named profiles to a shareable file
+pub fn export_profiles(path: &Path, profiles: &[(String, CompleteDeviceState)]) -> Result<()> {
+    let contents = serde_json::to_string_pretty(profiles)?;
+    fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Read named profiles from a file written by [`export_profiles`]
+pub fn import_profiles(path: &Path) -> Result<Vec<(String, CompleteDeviceState)>> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&contents).context("Not a valid profiles file")
+}
+
+// ============================================================================
+// Settings
+// ============================================================================
+
+/// App preferences (missing fields fall back to their defaults)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Hide to the tray instead of exiting when the window is closed
+    pub close_to_tray: bool,
+    /// Launch hidden to the tray (or minimized without one) instead of showing the window
+    pub start_minimized: bool,
+    /// Show a desktop notification when the AC/Battery profile auto-switches
+    pub switch_notifications: bool,
+    /// Temperature-driven fan curve; `None` keeps the fixed manual RPM
+    pub fan_curve: Option<FanCurve>,
+    /// Global accelerator that cycles performance modes, e.g. "Ctrl+Alt+P"
+    pub cycle_perf_hotkey: String,
+    pub low_battery: LowBatterySettings,
+    pub auto_switch: AutoSwitchSettings,
+    pub power_plan: PowerPlanSettings,
+    /// Named profile applied once at every launch, whatever the power source
+    pub startup_profile: Option<String>,
+    pub app_rules: AppRulesSettings,
+    pub api: ApiSettings,
+    /// Stream mode, fan RPM and battery as JSON lines on `\\.\pipe\r-helper`
+    pub telemetry_pipe: bool,
+    /// How long status and error messages stay visible
+    pub message_durations: MessageDurations,
+    /// Messages stacked in the header at once; 1 shows only the latest
+    pub visible_messages: usize,
+    /// Also show fan speeds as a percentage of the maximum RPM (and take percent input)
+    pub fan_speed_percent: bool,
+    /// RPM span of the fan readout colors as [min, max]; `None` follows the device's range
+    pub fan_color_range: Option<[u16; 2]>,
+    /// Ramp keyboard brightness through intermediate values instead of jumping (more writes)
+    pub brightness_fade: bool,
+    /// Display unit for CPU/GPU temperatures
+    pub temp_unit: TempUnit,
+    /// CPU temperature (°C) at which the high-power mode buttons warn of throttling
+    pub throttle_warning_temp: u8,
+    pub theme: Theme,
+    /// UI language; missing translations fall back to English
+    pub language: Language,
+    /// Pixels per point for the whole UI; `None` follows the system DPI
+    pub ui_scale: Option<f32>,
+    /// Last window position and size; `None` opens at the default size
+    pub window: Option<WindowGeometry>,
+    /// Show the single-row always-on-top readout instead of the full window
+    pub compact_mode: bool,
+    /// Never write on the app's own initiative: no fan RPM/curve enforcement and no automatic
+    /// AC/Battery, low battery or app rule profile switching
+    pub manual_control_only: bool,
+    /// Footer "Debug" toggle: extra readouts, optional status messages and debug-only controls
+    pub status_messages: bool,
+    /// Keep the message history panel open
+    pub show_message_history: bool,
+    /// Read-only telemetry: every control is disabled and nothing is written to the device
+    pub monitor_only: bool,
+    /// Ask before a mode change while the fans are manual (the fans briefly return to Auto)
+    pub confirm_perf_change_manual_fan: bool,
+    /// Never show the "not running as administrator" banner
+    pub hide_elevation_warning: bool,
+    /// Ask GitHub once per launch whether a newer release exists
+    pub check_for_updates: bool,
+    /// State written by "Reset to home"
+    pub home_state: CompleteDeviceState,
+}
+
+/// Raw keyboard brightness for 50%
+const HOME_KEYBOARD_BRIGHTNESS: u8 = 128;
+
+/// Out-of-the-box "home" state: Balanced, fans on Auto, logo off, half brightness, battery care on
+pub fn default_home_state() -> CompleteDeviceState {
+    CompleteDeviceState {
+        perf_mode: PerfMode::Balanced,
+        keyboard_brightness: HOME_KEYBOARD_BRIGHTNESS,
+        ..CompleteDeviceState::default()
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            close_to_tray: false,
+            start_minimized: false,
+            switch_notifications: true,
+            fan_curve: None,
+            cycle_perf_hotkey: DEFAULT_CYCLE_HOTKEY.to_string(),
+            low_battery: LowBatterySettings::default(),
+            auto_switch: AutoSwitchSettings::default(),
+            app_rules: AppRulesSettings::default(),
+            api: ApiSettings::default(),
+            telemetry_pipe: false,
+            power_plan: PowerPlanSettings::default(),
+            startup_profile: None,
+            message_durations: MessageDurations::default(),
+            visible_messages: 1,
+            fan_speed_percent: false,
+            fan_color_range: None,
+            brightness_fade: false,
+            temp_unit: TempUnit::Celsius,
+            throttle_warning_temp: 90,
+            theme: Theme::Dark,
+            language: Language::default(),
+            ui_scale: None,
+            window: None,
+            compact_mode: false,
+            manual_control_only: false,
+            monitor_only: false,
+            confirm_perf_change_manual_fan: true,
+            status_messages: false,
+            show_message_history: false,
+            hide_elevation_warning: false,
+            check_for_updates: true,
+            home_state: default_home_state(),
+        }
+    }
+}
+
+/// Switch to a named profile when the battery drops below a threshold
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LowBatterySettings {
+    pub enabled: bool,
+    /// Charge percentage at or below which the profile is applied
+    pub threshold: u8,
+    /// Name of the named profile to apply
+    pub profile: Option<String>,
+}
+
+impl Default for LowBatterySettings {
+    fn default() -> Self {
+        Self { enabled: false, threshold: 20, profile: None }
+    }
+}
+
+/// What the AC/Battery auto-switch writes from each power profile
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoSwitchSettings {
+    /// Apply the AC/Battery profile when the power source changes
+    pub enabled: bool,
+    pub ac: AutoSwitchScope,
+    pub battery: AutoSwitchScope,
+    /// Keyboard backlight to step 0 when switching to battery, restored on AC
+    pub battery_lights_off: bool,
+    /// Also turn the logo off on battery
+    pub battery_logo_off: bool,
+    /// Lights from before the switch to battery; restored (and cleared) on AC
+    pub saved_lights: Option<LightsState>,
+}
+
+impl Default for AutoSwitchSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ac: AutoSwitchScope::default(),
+            battery: AutoSwitchScope::default(),
+            battery_lights_off: false,
+            battery_logo_off: false,
+            saved_lights: None,
+        }
+    }
+}
+
+/// Keyboard brightness and (optionally) logo mode written over an auto-switch profile
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LightsState {
+    pub keyboard_brightness: u8,
+    /// `None` leaves the logo as the profile sets it
+    pub logo_mode: Option<LogoMode>,
+}
+
+impl LightsState {
+    pub fn apply_to(&self, state: &mut CompleteDeviceState) {
+        state.keyboard_brightness = self.keyboard_brightness;
+        if let Some(logo_mode) = self.logo_mode {
+            state.logo_mode = Some(logo_mode);
+        }
+    }
+}
+
+/// Settings an auto-switch takes from the profile besides the performance mode (and its
+/// Custom boosts); everything else keeps its current value
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoSwitchScope {
+    pub logo: bool,
+    /// Brightness, color and backlight power
+    pub keyboard: bool,
+    pub fan: bool,
+    pub lights_always_on: bool,
+    pub battery_care: bool,
+}
+
+impl AutoSwitchScope {
+    /// The state to write: `profile` for the selected settings, `current` for the rest
+    pub fn merge(
+        &self,
+        current: &CompleteDeviceState,
+        profile: &CompleteDeviceState,
+    ) -> CompleteDeviceState {
+        let mut target = current.clone();
+        target.perf_mode = profile.perf_mode;
+        target.cpu_boost = profile.cpu_boost;
+        target.gpu_boost = profile.gpu_boost;
+        if self.logo {
+            target.logo_mode = profile.logo_mode;
+        }
+        if self.keyboard {
+            target.keyboard_brightness = profile.keyboard_brightness;
+            target.keyboard_color = profile.keyboard_color.or(current.keyboard_color);
+            target.keyboard_backlight = profile.keyboard_backlight.or(current.keyboard_backlight);
+        }
+        if self.fan {
+            target.fan_mode = profile.fan_mode;
+            target.fan_rpm = profile.fan_rpm;
+            target.fan2_rpm = profile.fan2_rpm;
+        }
+        if self.lights_always_on {
+            target.lights_always_on = profile.lights_always_on;
+        }
+        if self.battery_care {
+            target.battery_care = profile.battery_care;
+        }
+        target
+    }
+}
+
+/// Switch performance mode based on the focused application
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppRulesSettings {
+    pub enabled: bool,
+    /// Executable name (matched case-insensitively) and the mode to use while it is focused
+    pub rules: Vec<(String, PerfMode)>,
+    /// Mode used when no rule matches the focused application
+    pub default_mode: PerfMode,
+}
+
+impl Default for AppRulesSettings {
+    fn default() -> Self {
+        Self { enabled: false, rules: Vec::new(), default_mode: PerfMode::Balanced }
+    }
+}
+
+/// Named profiles applied when Windows switches power plan, independent of AC/Battery
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PowerPlanSettings {
+    pub enabled: bool,
+    /// Profile name per plan; `None` leaves the device alone on that plan
+    pub high_performance: Option<String>,
+    pub balanced: Option<String>,
+    pub power_saver: Option<String>,
+}
+
+impl PowerPlanSettings {
+    pub fn profile_mut(&mut self, plan: PowerPlan) -> &mut Option<String> {
+        match plan {
+            PowerPlan::HighPerformance => &mut self.high_performance,
+            PowerPlan::Balanced => &mut self.balanced,
+            PowerPlan::PowerSaver => &mut self.power_saver,
+        }
+    }
+
+    /// Follow a renamed (`Some`) or deleted (`None`) profile
+    pub fn rename_profile(&mut self, old: &str, new: Option<&str>) {
+        for plan in PowerPlan::ALL {
+            let slot = self.profile_mut(plan);
+            if slot.as_deref() == Some(old) {
+                *slot = new.map(str::to_string);
+            }
+        }
+    }
+}
+
+/// Local HTTP endpoint for external automation, see `crate::api`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApiSettings {
+    pub enabled: bool,
+    /// Port on 127.0.0.1
+    pub port: u16,
+    /// Shared secret clients send as `Authorization: Bearer <token>`; empty rejects everything
+    pub token: String,
+}
+
+impl Default for ApiSettings {
+    fn default() -> Self {
+        Self { enabled: false, port: 43110, token: String::new() }
+    }
+}
+
+/// Outer position and inner size of the main window, in egui points
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    /// Pixels per point when saved, to map back onto physical monitor coordinates
+    pub scale: f32,
+}
+
+// ============================================================================
+// System Specs Cache
+// ============================================================================
+
+/// Specs from the last launch, valid while the machine fingerprint matches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecsCache {
+    /// Model, CPU and OS build the specs were queried on
+    pub fingerprint: String,
+    pub specs: SystemSpecs,
+}
+
+pub fn load_specs_cache() -> Option<SpecsCache> {
+    load_json(SPECS_CACHE_FILE_NAME)
+}
+
+pub fn save_specs_cache(cache: &SpecsCache) -> Result<()> {
+    save_json(SPECS_CACHE_FILE_NAME, cache)
+}
+
+// ============================================================================
+// File Helpers
+// ============================================================================
+
+/// Parse a file in the config directory; `Ok(None)` when it is missing or unreadable
+fn read_json<T: DeserializeOwned>(file_name: &str) -> Result<Option<T>> {
+    let Some(path) = config_dir().map(|dir| dir.join(file_name)) else {
+        return Ok(None);
+    };
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return Ok(None);
+    };
+    serde_json::from_str(&contents).map(Some).with_context(|| path.display().to_string())
+}
+
+/// Like `read_json`, logging and ignoring a file that does not parse
+fn load_json<T: DeserializeOwned>(file_name: &str) -> Option<T> {
+    read_json(file_name).unwrap_or_else(|e| {
+        eprintln!("Ignoring invalid config file {:#}", e);
+        None
+    })
+}
+
+fn save_json<T: Serialize>(file_name: &str, value: &T) -> Result<()> {
+    let dir = config_dir().context("Config directory not available")?;
+    let contents = serde_json::to_string_pretty(value)?;
+    write_atomically(&dir, file_name, &contents)
+}
+
+/// Write via a temp file + rename so a crash mid-write never leaves a truncated file
+fn write_atomically(dir: &Path, file_name: &str, contents: &str) -> Result<()> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create config directory {}", dir.display()))?;
+    let path = dir.join(file_name);
+    let tmp_path = dir.join(format!("{}.tmp", file_name));
+    fs::write(&tmp_path, contents)
+        .with_context(|| format!("Failed to write {}", tmp_path.display()))?;
+    fs::rename(&tmp_path, &path)
+        .with_context(|| format!("Failed to replace {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use librazer::types::FanMode;
+
+    fn manual_profile() -> CompleteDeviceState {
+        CompleteDeviceState {
+            perf_mode: PerfMode::Performance,
+            fan_mode: FanMode::Manual,
+            fan_rpm: Some(4200),
+            fan2_rpm: Some(3800),
+            ..CompleteDeviceState::default()
+        }
+    }
+
+    #[test]
+    fn auto_switch_merge_restores_profile_fan_rpm() {
+        let device = CompleteDeviceState::default();
+        let scope = AutoSwitchScope { fan: true, ..AutoSwitchScope::default() };
+        let target = scope.merge(&device, &manual_profile());
+        assert_eq!(
+            (target.fan_mode, target.fan_rpm, target.fan2_rpm),
+            (FanMode::Manual, Some(4200), Some(3800))
+        );
+    }
+
+    #[test]
+    fn auto_switch_merge_without_fans_keeps_device_fans() {
+        let device = CompleteDeviceState {
+            fan_mode: FanMode::Manual,
+            fan_rpm: Some(3000),
+            fan2_rpm: Some(3100),
+            ..CompleteDeviceState::default()
+        };
+        let target = AutoSwitchScope::default().merge(&device, &manual_profile());
+        assert_eq!((target.fan_rpm, target.fan2_rpm), (Some(3000), Some(3100)));
+    }
+}
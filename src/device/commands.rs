@@ -0,0 +1,95 @@
+// Device commands used when writing a state, behind a trait so the apply path can run
+// against a fake device in tests
+use anyhow::Result;
+use librazer::command;
+use librazer::device::Device;
+use librazer::types::{
+    BatteryCare, CpuBoost, FanMode, FanZone, GpuBoost, LightsAlwaysOn, LogoMode, PerfMode,
+};
+
+/// The reads and writes [`super::CompleteDeviceState::apply_to_device`] needs; each mirrors
+/// the `librazer::command` function of the same name
+pub trait DeviceCommands {
+    fn set_perf_mode(&self, perf_mode: PerfMode) -> Result<()>;
+    fn set_cpu_boost(&self, boost: CpuBoost) -> Result<()>;
+    fn set_gpu_boost(&self, boost: GpuBoost) -> Result<()>;
+    fn set_fan_mode(&self, mode: FanMode) -> Result<()>;
+    fn set_fan_rpm(&self, rpm: u16, check_mode: bool) -> Result<()>;
+    fn set_fan_zone_rpm(&self, zone: FanZone, rpm: u16, check_mode: bool) -> Result<()>;
+    fn get_fan_rpm(&self, zone: FanZone) -> Result<u16>;
+    fn get_logo_mode(&self) -> Result<LogoMode>;
+    fn set_logo_mode(&self, mode: LogoMode) -> Result<()>;
+    fn get_keyboard_brightness(&self) -> Result<u8>;
+    fn set_keyboard_brightness(&self, brightness: u8) -> Result<()>;
+    fn set_keyboard_color(&self, rgb: [u8; 3]) -> Result<()>;
+    fn set_keyboard_backlight(&self, on: bool) -> Result<()>;
+    fn set_lights_always_on(&self, lights_always_on: LightsAlwaysOn) -> Result<()>;
+    fn get_battery_care(&self) -> Result<BatteryCare>;
+    fn set_battery_care(&self, mode: BatteryCare) -> Result<()>;
+}
+
+impl DeviceCommands for Device {
+    fn set_perf_mode(&self, perf_mode: PerfMode) -> Result<()> {
+        command::set_perf_mode(self, perf_mode)
+    }
+
+    fn set_cpu_boost(&self, boost: CpuBoost) -> Result<()> {
+        command::set_cpu_boost(self, boost)
+    }
+
+    fn set_gpu_boost(&self, boost: GpuBoost) -> Result<()> {
+        command::set_gpu_boost(self, boost)
+    }
+
+    fn set_fan_mode(&self, mode: FanMode) -> Result<()> {
+        command::set_fan_mode(self, mode)
+    }
+
+    fn set_fan_rpm(&self, rpm: u16, check_mode: bool) -> Result<()> {
+        command::set_fan_rpm(self, rpm, check_mode)
+    }
+
+    fn set_fan_zone_rpm(&self, zone: FanZone, rpm: u16, check_mode: bool) -> Result<()> {
+        command::set_fan_zone_rpm(self, zone, rpm, check_mode)
+    }
+
+    fn get_fan_rpm(&self, zone: FanZone) -> Result<u16> {
+        command::get_fan_rpm(self, zone)
+    }
+
+    fn get_logo_mode(&self) -> Result<LogoMode> {
+        command::get_logo_mode(self)
+    }
+
+    fn set_logo_mode(&self, mode: LogoMode) -> Result<()> {
+        command::set_logo_mode(self, mode)
+    }
+
+    fn get_keyboard_brightness(&self) -> Result<u8> {
+        command::get_keyboard_brightness(self)
+    }
+
+    fn set_keyboard_brightness(&self, brightness: u8) -> Result<()> {
+        command::set_keyboard_brightness(self, brightness)
+    }
+
+    fn set_keyboard_color(&self, rgb: [u8; 3]) -> Result<()> {
+        command::set_keyboard_color(self, rgb)
+    }
+
+    fn set_keyboard_backlight(&self, on: bool) -> Result<()> {
+        command::set_keyboard_backlight(self, on)
+    }
+
+    fn set_lights_always_on(&self, lights_always_on: LightsAlwaysOn) -> Result<()> {
+        command::set_lights_always_on(self, lights_always_on)
+    }
+
+    fn get_battery_care(&self) -> Result<BatteryCare> {
+        command::get_battery_care(self)
+    }
+
+    fn set_battery_care(&self, mode: BatteryCare) -> Result<()> {
+        command::set_battery_care(self, mode)
+    }
+}
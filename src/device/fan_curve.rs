@@ -0,0 +1,98 @@
+// Temperature-to-RPM fan curve
+use super::{MAX_MANUAL_RPM, MIN_MANUAL_RPM};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Highest temperature a curve point may use (°C)
+pub const MAX_CURVE_TEMP: u8 = 100;
+
+/// Piecewise-linear fan curve as (°C, RPM) points sorted by temperature.
+///
+/// Only constructed through [`FanCurve::new`], so every instance is validated:
+/// at least two points, strictly increasing temperatures, non-decreasing RPM.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<(u8, u16)>", into = "Vec<(u8, u16)>")]
+pub struct FanCurve {
+    points: Vec<(u8, u16)>,
+}
+
+impl FanCurve {
+    /// Build a curve, clamping RPMs to the manual fan range and rejecting non-monotonic input
+    pub fn new(points: Vec<(u8, u16)>) -> Result<Self> {
+        if points.len() < 2 {
+            return Err(anyhow::anyhow!("A fan curve needs at least two points"));
+        }
+
+        let points: Vec<(u8, u16)> = points
+            .into_iter()
+            .map(|(temp, rpm)| {
+                (temp.min(MAX_CURVE_TEMP), rpm.clamp(MIN_MANUAL_RPM, MAX_MANUAL_RPM))
+            })
+            .collect();
+
+        for pair in points.windows(2) {
+            let ((t0, r0), (t1, r1)) = (pair[0], pair[1]);
+            if t1 <= t0 {
+                return Err(anyhow::anyhow!("Temperatures must increase ({}°C → {}°C)", t0, t1));
+            }
+            if r1 < r0 {
+                return Err(anyhow::anyhow!(
+                    "RPM must not decrease as temperature rises ({}°C: {} → {}°C: {})",
+                    t0,
+                    r0,
+                    t1,
+                    r1
+                ));
+            }
+        }
+
+        Ok(Self { points })
+    }
+
+    pub fn points(&self) -> &[(u8, u16)] {
+        &self.points
+    }
+
+    /// Interpolated RPM for a temperature, holding the end values outside the curve
+    pub fn target_rpm(&self, temp_c: f32) -> u16 {
+        let (first_temp, first_rpm) = self.points[0];
+        let (last_temp, last_rpm) = self.points[self.points.len() - 1];
+
+        if temp_c <= first_temp as f32 {
+            return first_rpm;
+        }
+        if temp_c >= last_temp as f32 {
+            return last_rpm;
+        }
+
+        for pair in self.points.windows(2) {
+            let ((t0, r0), (t1, r1)) = (pair[0], pair[1]);
+            if temp_c <= t1 as f32 {
+                let ratio = (temp_c - t0 as f32) / (t1 as f32 - t0 as f32);
+                return (r0 as f32 + ratio * (r1 as f32 - r0 as f32)).round() as u16;
+            }
+        }
+
+        last_rpm
+    }
+}
+
+impl Default for FanCurve {
+    fn default() -> Self {
+        Self { points: vec![(50, 2000), (70, 3500), (85, 5000), (95, MAX_MANUAL_RPM)] }
+    }
+}
+
+impl TryFrom<Vec<(u8, u16)>> for FanCurve {
+    type Error = anyhow::Error;
+
+    fn try_from(points: Vec<(u8, u16)>) -> Result<Self> {
+        Self::new(points)
+    }
+}
+
+impl From<FanCurve> for Vec<(u8, u16)> {
+    fn from(curve: FanCurve) -> Self {
+        curve.points
+    }
+}
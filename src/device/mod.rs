@@ -1,53 +1,405 @@
 // Device domain types and helpers
-use anyhow::Result;
-use librazer::types::{BatteryCare, FanMode, LightsAlwaysOn, LogoMode, PerfMode};
+pub mod commands;
+pub mod fan_curve;
+pub mod poller;
+
+pub use commands::DeviceCommands;
+pub use fan_curve::FanCurve;
+pub use poller::{DevicePoller, DeviceSnapshot};
+
+use crate::utils::with_default_retry;
+use anyhow::{Context, Result};
+use librazer::descriptor::Descriptor;
+use librazer::types::{
+    BatteryCare, CpuBoost, FanMode, FanZone, GpuBoost, LightsAlwaysOn, LogoMode, PerfMode,
+};
 use librazer::{command, device};
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+/// Range accepted for a manual fan RPM
+pub const MIN_MANUAL_RPM: u16 = 2000;
+pub const MAX_MANUAL_RPM: u16 = 5500;
+
+/// Manual RPM range a model's descriptor lists. No model lists one yet, so this is only a
+/// hook for firmware found to need other limits; everything else uses the defaults above.
+pub fn listed_rpm_range(descriptor: &Descriptor) -> Option<RangeInclusive<u16>> {
+    descriptor.fan_rpm_range.filter(|(min, max)| min < max).map(|(min, max)| min..=max)
+}
+
+/// Manual RPM range to validate against for a model: its listed range, else the defaults
+pub fn manual_rpm_range(descriptor: &Descriptor) -> RangeInclusive<u16> {
+    listed_rpm_range(descriptor).unwrap_or(MIN_MANUAL_RPM..=MAX_MANUAL_RPM)
+}
+
+/// Time given to the firmware to commit a performance mode before fan control is re-applied
+const FAN_RESTORE_DELAY: Duration = Duration::from_millis(50);
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CompleteDeviceState {
     pub perf_mode: PerfMode,
+    /// Custom mode CPU/GPU boosts; only read while in Custom
+    #[serde(default)]
+    pub cpu_boost: Option<CpuBoost>,
+    #[serde(default)]
+    pub gpu_boost: Option<GpuBoost>,
     pub fan_mode: FanMode,
     pub fan_rpm: Option<u16>,
-    pub logo_mode: LogoMode,
+    /// Second fan zone RPM; `None` in Auto or on single-fan devices
+    #[serde(default)]
+    pub fan2_rpm: Option<u16>,
+    /// `None` on devices without logo control
+    #[serde(default)]
+    pub logo_mode: Option<LogoMode>,
     pub keyboard_brightness: u8,
+    /// Static keyboard RGB color; `None` on devices without color control
+    #[serde(default)]
+    pub keyboard_color: Option<[u8; 3]>,
+    /// Keyboard backlight power; `None` on devices without a separate on/off state
+    #[serde(default)]
+    pub keyboard_backlight: Option<bool>,
     pub lights_always_on: LightsAlwaysOn,
-    pub battery_care: BatteryCare,
+    /// `None` on devices without battery care
+    #[serde(default)]
+    pub battery_care: Option<BatteryCare>,
 }
 
 impl Default for CompleteDeviceState {
     fn default() -> Self {
         Self {
             perf_mode: PerfMode::Performance,
+            cpu_boost: None,
+            gpu_boost: None,
             fan_mode: FanMode::Auto,
             fan_rpm: None,
-            logo_mode: LogoMode::Off,
+            fan2_rpm: None,
+            logo_mode: Some(LogoMode::Off),
             keyboard_brightness: 50,
+            keyboard_color: None,
+            keyboard_backlight: None,
             lights_always_on: LightsAlwaysOn::Disable,
-            battery_care: BatteryCare::Enable,
+            battery_care: Some(BatteryCare::Enable),
         }
     }
 }
 
 impl CompleteDeviceState {
     pub fn read_from_device(device: &device::Device) -> Result<Self> {
-        let (perf_mode, fan_mode) = command::get_perf_mode(device)?;
-        let fan_rpm = match fan_mode {
-            FanMode::Manual => Some(command::get_fan_rpm(device, librazer::types::FanZone::Zone1)?),
-            FanMode::Auto => None,
+        let (perf_mode, fan_mode) = with_default_retry(|| command::get_perf_mode(device))?;
+        let (cpu_boost, gpu_boost) = if perf_mode == PerfMode::Custom {
+            (
+                Some(with_default_retry(|| command::get_cpu_boost(device))?),
+                Some(with_default_retry(|| command::get_gpu_boost(device))?),
+            )
+        } else {
+            (None, None)
         };
-        let logo_mode = command::get_logo_mode(device)?;
-        let keyboard_brightness = command::get_keyboard_brightness(device)?;
-        let lights_always_on = command::get_lights_always_on(device)?;
-        let battery_care = command::get_battery_care(device)?;
+        let (fan_rpm, fan2_rpm) = match fan_mode {
+            FanMode::Manual => (
+                Some(with_default_retry(|| command::get_fan_rpm(device, FanZone::Zone1))?),
+                // Single-fan devices reject Zone2 queries
+                command::get_fan_rpm(device, FanZone::Zone2).ok(),
+            ),
+            FanMode::Auto => (None, None),
+        };
+        // Models without logo control or battery care reject those reads; the rest still counts
+        let logo_mode = with_default_retry(|| command::get_logo_mode(device)).ok();
+        let keyboard_brightness = with_default_retry(|| command::get_keyboard_brightness(device))?;
+        let keyboard_color = command::get_keyboard_color(device).ok();
+        let keyboard_backlight = command::get_keyboard_backlight(device).ok();
+        let lights_always_on = with_default_retry(|| command::get_lights_always_on(device))?;
+        let battery_care = with_default_retry(|| command::get_battery_care(device)).ok();
 
         Ok(Self {
             perf_mode,
+            cpu_boost,
+            gpu_boost,
             fan_mode,
             fan_rpm,
+            fan2_rpm,
             logo_mode,
             keyboard_brightness,
+            keyboard_color,
+            keyboard_backlight,
             lights_always_on,
             battery_care,
         })
     }
+
+    /// Keep stored manual fan speeds within what the device accepts
+    pub fn clamp_fan_rpm(&mut self, range: &RangeInclusive<u16>) {
+        let clamp = |rpm: u16| rpm.clamp(*range.start(), *range.end());
+        self.fan_rpm = self.fan_rpm.map(clamp);
+        self.fan2_rpm = self.fan2_rpm.map(clamp);
+    }
+
+    /// Fill in what the device does not report right now from `profile`: the boosts are only
+    /// readable in Custom, and the manual RPM is kept while the fans are on Auto so switching
+    /// back to manual picks up where it left off
+    pub fn keep_unread_from(&mut self, profile: &CompleteDeviceState) {
+        if self.cpu_boost.is_none() {
+            self.cpu_boost = profile.cpu_boost;
+        }
+        if self.gpu_boost.is_none() {
+            self.gpu_boost = profile.gpu_boost;
+        }
+        if self.fan_rpm.is_none() {
+            self.fan_rpm = profile.fan_rpm;
+            self.fan2_rpm = profile.fan2_rpm;
+        }
+    }
+
+    /// Names of the settings that differ from `other`, for status messages
+    pub fn changed_settings(&self, other: &Self) -> Vec<&'static str> {
+        let mut changed = Vec::new();
+        if (self.perf_mode, self.cpu_boost, self.gpu_boost)
+            != (other.perf_mode, other.cpu_boost, other.gpu_boost)
+        {
+            changed.push("performance mode");
+        }
+        if (self.fan_mode, self.fan_rpm, self.fan2_rpm)
+            != (other.fan_mode, other.fan_rpm, other.fan2_rpm)
+        {
+            changed.push("fan");
+        }
+        if self.logo_mode != other.logo_mode {
+            changed.push("logo");
+        }
+        if (self.keyboard_brightness, self.keyboard_color, self.keyboard_backlight)
+            != (other.keyboard_brightness, other.keyboard_color, other.keyboard_backlight)
+        {
+            changed.push("keyboard");
+        }
+        if self.lights_always_on != other.lights_always_on {
+            changed.push("lights always on");
+        }
+        if self.battery_care != other.battery_care {
+            changed.push("battery care");
+        }
+        changed
+    }
+
+    /// Write this state to the device (performance mode first, then the other settings).
+    /// Every setting is attempted; the error lists the ones the device rejected. Logo and
+    /// battery care are skipped on models that don't have them.
+    pub fn apply_to_device(&self, device: &impl DeviceCommands) -> Result<()> {
+        let mut failed = Vec::new();
+        let mut attempt = |setting: &str, result: Result<()>| {
+            if let Err(e) = result {
+                failed.push(format!("{} ({})", setting, e));
+            }
+        };
+
+        let manual_fan = match self.fan_mode {
+            FanMode::Manual => self.fan_rpm.map(|rpm| (rpm, self.fan2_rpm)),
+            FanMode::Auto => None,
+        };
+        let boosts = (self.cpu_boost, self.gpu_boost);
+        for (setting, e) in set_perf_mode_restoring(device, self.perf_mode, boosts, manual_fan) {
+            attempt(setting, Err(e));
+        }
+
+        // Models without logo control or battery care also reject the read; those settings are
+        // skipped there so only real write failures get reported
+        let logo_mode = self.logo_mode.filter(|_| device.get_logo_mode().is_ok());
+        if let Some(logo_mode) = logo_mode {
+            attempt("logo", device.set_logo_mode(logo_mode));
+        }
+
+        let brightness_matches = device
+            .get_keyboard_brightness()
+            .is_ok_and(|current| current == self.keyboard_brightness);
+        if !brightness_matches {
+            attempt(
+                "keyboard brightness",
+                device.set_keyboard_brightness(self.keyboard_brightness),
+            );
+        }
+
+        if let Some(rgb) = self.keyboard_color {
+            attempt("keyboard color", device.set_keyboard_color(rgb));
+        }
+
+        if let Some(on) = self.keyboard_backlight {
+            attempt("keyboard backlight", device.set_keyboard_backlight(on));
+        }
+
+        attempt("lights always on", device.set_lights_always_on(self.lights_always_on));
+
+        let battery_care = self.battery_care.filter(|_| device.get_battery_care().is_ok());
+        if let Some(battery_care) = battery_care {
+            attempt("battery care", device.set_battery_care(battery_care));
+        }
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            anyhow::bail!("could not set {}", failed.join("; "))
+        }
+    }
+}
+
+/// Write a performance mode, then put back what the change resets: the Custom boosts (once
+/// the mode is set) and the manual fan, `(rpm, rpm2)`. Profile applies and the mode buttons
+/// both go through here. Every part is attempted; the failed ones are returned by name.
+pub fn set_perf_mode_restoring(
+    device: &impl DeviceCommands,
+    perf_mode: PerfMode,
+    (cpu_boost, gpu_boost): (Option<CpuBoost>, Option<GpuBoost>),
+    manual_fan: Option<(u16, Option<u16>)>,
+) -> Vec<(&'static str, anyhow::Error)> {
+    let mut failed = Vec::new();
+    let perf_mode_set = match device.set_perf_mode(perf_mode) {
+        Ok(()) => true,
+        Err(e) => {
+            failed.push(("performance mode", e));
+            false
+        }
+    };
+
+    if perf_mode_set && perf_mode == PerfMode::Custom {
+        if let Some(Err(e)) = cpu_boost.map(|boost| device.set_cpu_boost(boost)) {
+            failed.push(("CPU boost", e));
+        }
+        if let Some(Err(e)) = gpu_boost.map(|boost| device.set_gpu_boost(boost)) {
+            failed.push(("GPU boost", e));
+        }
+    }
+
+    // Changing the performance mode resets the fans to Auto
+    if let Some((rpm, rpm2)) = manual_fan {
+        if let Err(e) = restore_manual_fan(device, rpm, rpm2) {
+            failed.push(("fan", e));
+        }
+    }
+    failed
+}
+
+/// Put the fans back under manual control after a performance mode change.
+/// `rpm2` sets the second zone separately; otherwise both zones get `rpm`.
+pub fn restore_manual_fan(device: &impl DeviceCommands, rpm: u16, rpm2: Option<u16>) -> Result<()> {
+    std::thread::sleep(FAN_RESTORE_DELAY);
+    device.set_fan_mode(FanMode::Manual).context("Failed to restore manual fan mode")?;
+    std::thread::sleep(FAN_RESTORE_DELAY);
+    match rpm2 {
+        Some(rpm2) => device
+            .set_fan_zone_rpm(FanZone::Zone1, rpm, true)
+            .and_then(|_| device.set_fan_zone_rpm(FanZone::Zone2, rpm2, false)),
+        None => device.set_fan_rpm(rpm, true),
+    }
+    .context("Failed to restore fan RPM")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// Records fan writes the way the firmware applies them: a performance mode change puts
+    /// the fans back on Auto, and a mode-checked RPM write needs Manual
+    struct FakeDevice {
+        fan_mode: Cell<FanMode>,
+        fan_rpm: [Cell<u16>; 2],
+    }
+
+    impl FakeDevice {
+        fn new() -> Self {
+            Self { fan_mode: Cell::new(FanMode::Auto), fan_rpm: [Cell::new(0), Cell::new(0)] }
+        }
+    }
+
+    impl DeviceCommands for FakeDevice {
+        fn set_perf_mode(&self, _perf_mode: PerfMode) -> Result<()> {
+            self.fan_mode.set(FanMode::Auto);
+            Ok(())
+        }
+        fn set_cpu_boost(&self, _boost: CpuBoost) -> Result<()> {
+            Ok(())
+        }
+        fn set_gpu_boost(&self, _boost: GpuBoost) -> Result<()> {
+            Ok(())
+        }
+        fn set_fan_mode(&self, mode: FanMode) -> Result<()> {
+            self.fan_mode.set(mode);
+            Ok(())
+        }
+        fn set_fan_rpm(&self, rpm: u16, check_mode: bool) -> Result<()> {
+            self.set_fan_zone_rpm(FanZone::Zone1, rpm, check_mode)?;
+            self.set_fan_zone_rpm(FanZone::Zone2, rpm, false)
+        }
+        fn set_fan_zone_rpm(&self, zone: FanZone, rpm: u16, check_mode: bool) -> Result<()> {
+            anyhow::ensure!(!check_mode || self.fan_mode.get() == FanMode::Manual);
+            self.fan_rpm[zone as usize - 1].set(rpm);
+            Ok(())
+        }
+        fn get_fan_rpm(&self, zone: FanZone) -> Result<u16> {
+            Ok(self.fan_rpm[zone as usize - 1].get())
+        }
+        fn get_logo_mode(&self) -> Result<LogoMode> {
+            Ok(LogoMode::Off)
+        }
+        fn set_logo_mode(&self, _mode: LogoMode) -> Result<()> {
+            Ok(())
+        }
+        fn get_keyboard_brightness(&self) -> Result<u8> {
+            Ok(0)
+        }
+        fn set_keyboard_brightness(&self, _brightness: u8) -> Result<()> {
+            Ok(())
+        }
+        fn set_keyboard_color(&self, _rgb: [u8; 3]) -> Result<()> {
+            Ok(())
+        }
+        fn set_keyboard_backlight(&self, _on: bool) -> Result<()> {
+            Ok(())
+        }
+        fn set_lights_always_on(&self, _lights_always_on: LightsAlwaysOn) -> Result<()> {
+            Ok(())
+        }
+        fn get_battery_care(&self) -> Result<BatteryCare> {
+            Ok(BatteryCare::Enable)
+        }
+        fn set_battery_care(&self, _mode: BatteryCare) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn manual_profile() -> CompleteDeviceState {
+        CompleteDeviceState {
+            perf_mode: PerfMode::Performance,
+            fan_mode: FanMode::Manual,
+            fan_rpm: Some(4200),
+            fan2_rpm: Some(3800),
+            ..CompleteDeviceState::default()
+        }
+    }
+
+    #[test]
+    fn device_state_on_auto_keeps_profile_fan_rpm() {
+        let mut device =
+            CompleteDeviceState { perf_mode: PerfMode::Balanced, ..CompleteDeviceState::default() };
+        device.keep_unread_from(&manual_profile());
+        assert_eq!(
+            (device.fan_mode, device.fan_rpm, device.fan2_rpm),
+            (FanMode::Auto, Some(4200), Some(3800))
+        );
+    }
+
+    #[test]
+    fn applying_manual_fan_profile_writes_profile_rpm() {
+        for (fan2_rpm, expected) in [(Some(3800), (4200, 3800)), (None, (4200, 4200))] {
+            let device = FakeDevice::new();
+            let profile = CompleteDeviceState { fan2_rpm, ..manual_profile() };
+            profile.apply_to_device(&device).unwrap();
+            assert_eq!(device.fan_mode.get(), FanMode::Manual);
+            assert_eq!(
+                (
+                    device.get_fan_rpm(FanZone::Zone1).unwrap(),
+                    device.get_fan_rpm(FanZone::Zone2).unwrap()
+                ),
+                expected
+            );
+        }
+    }
 }
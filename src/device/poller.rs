@@ -0,0 +1,231 @@
+// Periodic device reads on a worker thread, so USB round-trips never stall rendering
+use super::CompleteDeviceState;
+use crate::utils::with_default_retry;
+use anyhow::{anyhow, Result};
+use librazer::command;
+use librazer::device::Device;
+use librazer::types::{FanMode, FanZone};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Cadence of the quick status reads (fan speeds, fan mode, brightness)
+const STATUS_INTERVAL: Duration = Duration::from_millis(500);
+/// Re-detection backoff while the device is missing
+const MIN_REOPEN_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_REOPEN_BACKOFF: Duration = Duration::from_secs(30);
+
+/// A job queued to the worker; runs between reads on the worker's handle, which is `None`
+/// when the device could not be opened
+type Job = Box<dyn FnOnce(Option<&Device>) + Send + 'static>;
+
+/// Called on the worker thread after each published snapshot and finished job, e.g. to
+/// repaint the UI
+type Wake = Box<dyn Fn() + Send + 'static>;
+
+/// One round of reads
+#[derive(Debug)]
+pub struct DeviceSnapshot {
+    /// When the reads began; writes made before this are reflected
+    pub taken_at: Instant,
+    /// How long the round took
+    pub read_time: Duration,
+    pub fan_mode: Option<FanMode>,
+    pub fan_actual_rpm: Option<u16>,
+    pub fan2_actual_rpm: Option<u16>,
+    pub keyboard_brightness: Option<u8>,
+    /// Full state, read every `state_interval` while enabled
+    pub state: Option<Result<CompleteDeviceState>>,
+}
+
+/// Which reads the worker should make; toggled by the owner as it needs them
+#[derive(Default)]
+struct Polling {
+    status: AtomicBool,
+    state: AtomicBool,
+    second_fan: AtomicBool,
+}
+
+enum Request {
+    Run(Job),
+    Stop,
+}
+
+/// Worker thread that owns its own device handle, publishes snapshots and runs queued jobs
+pub struct DevicePoller {
+    requests: mpsc::Sender<Request>,
+    snapshots: mpsc::Receiver<DeviceSnapshot>,
+    polling: Arc<Polling>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl DevicePoller {
+    /// Start the worker; it opens the device itself and reopens it after a failed read.
+    /// Full state reads happen every `state_interval`.
+    pub fn start(state_interval: Duration, wake: impl Fn() + Send + 'static) -> Self {
+        let (requests, request_receiver) = mpsc::channel();
+        let (snapshot_sender, snapshots) = mpsc::channel();
+        let polling = Arc::new(Polling::default());
+        let mut worker = Worker {
+            device: None,
+            reopen_backoff: MIN_REOPEN_BACKOFF,
+            last_open_attempt: None,
+            last_state_read: None,
+            state_interval,
+            polling: polling.clone(),
+            snapshots: snapshot_sender,
+            wake: Box::new(wake),
+        };
+        let thread = std::thread::spawn(move || worker.run(request_receiver));
+        Self { requests, snapshots, polling, thread: Some(thread) }
+    }
+
+    /// Enable the quick status reads and the periodic full state read
+    pub fn set_polling(&self, status: bool, state: bool) {
+        self.polling.status.store(status, Ordering::Relaxed);
+        self.polling.state.store(state, Ordering::Relaxed);
+    }
+
+    /// Also read the second fan zone
+    pub fn set_second_fan(&self, second_fan: bool) {
+        self.polling.second_fan.store(second_fan, Ordering::Relaxed);
+    }
+
+    /// Snapshots published since the last call, oldest first (non-blocking)
+    pub fn snapshots(&self) -> Vec<DeviceSnapshot> {
+        self.snapshots.try_iter().collect()
+    }
+
+    /// Queue a read or write; the receiver gets its result once the worker has run it
+    pub fn run<T: Send + 'static>(
+        &self,
+        job: impl FnOnce(&Device) -> Result<T> + Send + 'static,
+    ) -> mpsc::Receiver<Result<T>> {
+        let (sender, receiver) = mpsc::channel();
+        let stopped = sender.clone();
+        let job: Job = Box::new(move |device| {
+            let result =
+                device.ok_or_else(|| anyhow!("No supported Razer device found")).and_then(job);
+            let _ = sender.send(result);
+        });
+        if self.requests.send(Request::Run(job)).is_err() {
+            let _ = stopped.send(Err(anyhow!("Device worker stopped")));
+        }
+        receiver
+    }
+}
+
+impl Drop for DevicePoller {
+    fn drop(&mut self) {
+        let _ = self.requests.send(Request::Stop);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+struct Worker {
+    device: Option<Device>,
+    reopen_backoff: Duration,
+    last_open_attempt: Option<Instant>,
+    last_state_read: Option<Instant>,
+    state_interval: Duration,
+    polling: Arc<Polling>,
+    snapshots: mpsc::Sender<DeviceSnapshot>,
+    wake: Wake,
+}
+
+impl Worker {
+    fn run(&mut self, requests: mpsc::Receiver<Request>) {
+        let mut next_round = Instant::now();
+        loop {
+            match requests.recv_timeout(next_round.saturating_duration_since(Instant::now())) {
+                Ok(Request::Run(job)) => {
+                    self.run_job(job);
+                    (self.wake)();
+                    continue;
+                }
+                Ok(Request::Stop) | Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+            }
+            next_round = Instant::now() + STATUS_INTERVAL;
+
+            let status = self.polling.status.load(Ordering::Relaxed);
+            let state = self.polling.state.load(Ordering::Relaxed)
+                && self.last_state_read.is_none_or(|at| at.elapsed() >= self.state_interval);
+            if !status && !state {
+                continue;
+            }
+            if let Some(snapshot) = self.read(status, state) {
+                if self.snapshots.send(snapshot).is_err() {
+                    break;
+                }
+                (self.wake)();
+            }
+        }
+    }
+
+    /// Open the device unless it is missing and the backoff has not passed yet
+    fn device(&mut self) -> Option<&Device> {
+        if self.device.is_none()
+            && self.last_open_attempt.is_none_or(|at| at.elapsed() >= self.reopen_backoff)
+        {
+            self.last_open_attempt = Some(Instant::now());
+            match Device::detect() {
+                Ok(device) => {
+                    self.device = Some(device);
+                    self.reopen_backoff = MIN_REOPEN_BACKOFF;
+                }
+                Err(e) => {
+                    crate::utils::log_debug(&format!("Device worker could not open device: {}", e));
+                    self.reopen_backoff = (self.reopen_backoff * 2).min(MAX_REOPEN_BACKOFF);
+                }
+            }
+        }
+        self.device.as_ref()
+    }
+
+    fn run_job(&mut self, job: Job) {
+        // A queued job is worth an immediate reopen attempt
+        self.last_open_attempt = None;
+        job(self.device());
+    }
+
+    fn read(&mut self, status: bool, state: bool) -> Option<DeviceSnapshot> {
+        let second_fan = self.polling.second_fan.load(Ordering::Relaxed);
+        let device = self.device()?;
+        let taken_at = Instant::now();
+        let mut snapshot = DeviceSnapshot {
+            taken_at,
+            read_time: Duration::ZERO,
+            fan_mode: None,
+            fan_actual_rpm: None,
+            fan2_actual_rpm: None,
+            keyboard_brightness: None,
+            state: None,
+        };
+
+        if status {
+            snapshot.fan_actual_rpm = command::get_fan_actual_rpm(device, FanZone::Zone1).ok();
+            if second_fan {
+                snapshot.fan2_actual_rpm = command::get_fan_actual_rpm(device, FanZone::Zone2).ok();
+            }
+            snapshot.fan_mode =
+                with_default_retry(|| command::get_perf_mode(device)).map(|(_, fan)| fan).ok();
+            snapshot.keyboard_brightness = command::get_keyboard_brightness(device).ok();
+        }
+        if state {
+            snapshot.state = Some(CompleteDeviceState::read_from_device(device));
+            self.last_state_read = Some(taken_at);
+        }
+        snapshot.read_time = taken_at.elapsed();
+
+        // A failed status read reopens the handle on the next round; a failed state read alone
+        // may just be one unsupported setting, so the owner decides whether the device is gone
+        if status && snapshot.fan_mode.is_none() {
+            self.device = None;
+        }
+        Some(snapshot)
+    }
+}
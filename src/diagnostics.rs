@@ -0,0 +1,69 @@
+//! Plain-text diagnostics report for bug reports
+//!
+//! Collects app, system and device details in the format maintainers usually ask for,
+//! ready to paste into a GitHub issue.
+
+use crate::device::CompleteDeviceState;
+use crate::system::SystemSpecs;
+use librazer::types::PerfMode;
+use librazer::{command, device::Device};
+use std::fmt::Write;
+
+const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Build the report, reading the current state straight from the device (so on the device
+/// worker when one is given)
+pub fn build_report(
+    specs: &SystemSpecs,
+    device: Option<&Device>,
+    available_performance_modes: &[PerfMode],
+) -> String {
+    let mut report = String::new();
+
+    // Writing to a String cannot fail
+    let _ = writeln!(report, "R-Helper {}", APP_VERSION);
+    let _ = writeln!(report, "OS: {} ({})", std::env::consts::OS, std::env::consts::ARCH);
+    let _ = writeln!(report);
+
+    let _ = writeln!(report, "[System]");
+    let unmatched = if specs.device_model_unmatched { " (librazer not matched)" } else { "" };
+    let _ = writeln!(report, "Model: {}{}", specs.device_model, unmatched);
+    for gpu in &specs.gpu_models {
+        let _ = writeln!(report, "GPU: {}", gpu);
+    }
+    if specs.integrated_gpu_only {
+        let _ = writeln!(report, "GPU note: only an integrated GPU is visible");
+    }
+    let _ = writeln!(report);
+
+    let _ = writeln!(report, "[Device]");
+    match device {
+        Some(device) => {
+            let info = device.info();
+            let _ = writeln!(report, "Name: {}", info.name);
+            let _ = writeln!(report, "Model prefix: {}", info.model_number_prefix);
+            let _ = writeln!(report, "VID:PID: {:04x}:{:04x}", Device::RAZER_VID, info.pid);
+            if let Ok((major, minor)) = command::get_firmware_version(device) {
+                let _ = writeln!(report, "Firmware: v{}.{}", major, minor);
+            }
+            let _ = writeln!(report, "Features: {}", info.features.join(", "));
+            let _ = writeln!(report, "Performance modes: {:?}", available_performance_modes);
+            let _ = writeln!(report);
+
+            let _ = writeln!(report, "[State]");
+            match CompleteDeviceState::read_from_device(device) {
+                Ok(state) => {
+                    let _ = writeln!(report, "{:#?}", state);
+                }
+                Err(e) => {
+                    let _ = writeln!(report, "Failed to read device state: {}", e);
+                }
+            }
+        }
+        None => {
+            let _ = writeln!(report, "No device connected");
+        }
+    }
+
+    report
+}
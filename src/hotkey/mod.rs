@@ -0,0 +1,64 @@
+// Global hotkey for cycling performance modes while the window is unfocused
+use anyhow::Result;
+use eframe::egui;
+use std::sync::mpsc;
+
+/// Default accelerator, in the `global-hotkey` string format
+pub const DEFAULT_CYCLE_HOTKEY: &str = "Ctrl+Alt+P";
+
+pub struct AppHotkey {
+    #[cfg(target_os = "windows")]
+    manager: global_hotkey::GlobalHotKeyManager,
+    #[cfg(target_os = "windows")]
+    hotkey: global_hotkey::hotkey::HotKey,
+    receiver: mpsc::Receiver<()>,
+}
+
+impl AppHotkey {
+    /// Whether the hotkey was pressed since the last call (non-blocking)
+    pub fn pressed(&self) -> bool {
+        self.receiver.try_iter().count() > 0
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl AppHotkey {
+    /// Register the hotkey. Must be called on the UI (event loop) thread.
+    pub fn new(ctx: &egui::Context, accelerator: &str) -> Result<Self> {
+        use global_hotkey::hotkey::HotKey;
+        use global_hotkey::{GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState};
+
+        let hotkey: HotKey = accelerator
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid hotkey '{}': {}", accelerator, e))?;
+        let manager = GlobalHotKeyManager::new()?;
+        manager.register(hotkey)?;
+
+        let (sender, receiver) = mpsc::channel();
+        let hotkey_id = hotkey.id();
+        let event_ctx = ctx.clone();
+        GlobalHotKeyEvent::set_event_handler(Some(move |event: GlobalHotKeyEvent| {
+            if event.id() == hotkey_id && event.state() == HotKeyState::Pressed {
+                let _ = sender.send(());
+                event_ctx.request_repaint();
+            }
+        }));
+
+        Ok(Self { manager, hotkey, receiver })
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl Drop for AppHotkey {
+    fn drop(&mut self) {
+        // Release the system-wide registration so other apps can use the combination
+        let _ = self.manager.unregister(self.hotkey);
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+impl AppHotkey {
+    pub fn new(_ctx: &egui::Context, _accelerator: &str) -> Result<Self> {
+        Err(anyhow::anyhow!("Global hotkeys are only supported on Windows"))
+    }
+}
@@ -0,0 +1,817 @@
+//! Translated UI strings.
+//!
+//! Render code looks labels up by key with `t!("section.key")`. English is the reference table;
+//! a key missing from another language falls back to English, and a key missing everywhere is
+//! shown as-is so it stands out. Icons and emoji stay in the render code, outside the text.
+//! `t!("section.key", value, ...)` fills the text's `{}` placeholders in order and returns a
+//! `String`, so word order can differ between languages.
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// UI language picked in the settings menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::German];
+
+    /// Name of the language in that language, so it can be found without reading the current one
+    pub fn label(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+        }
+    }
+
+    fn table(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::English => ENGLISH,
+            Language::German => GERMAN,
+        }
+    }
+}
+
+/// Index into `Language::ALL` of the active language
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// Switch the language used by `t!` from the next frame on
+pub fn set_language(language: Language) {
+    let index = Language::ALL.iter().position(|known| *known == language).unwrap_or(0);
+    CURRENT.store(index as u8, Ordering::Relaxed);
+}
+
+fn current() -> Language {
+    Language::ALL.get(CURRENT.load(Ordering::Relaxed) as usize).copied().unwrap_or_default()
+}
+
+/// Text for `key` in the active language; see `t!`
+pub fn tr(key: &'static str) -> &'static str {
+    let find = |table: &'static [(&'static str, &'static str)]| {
+        table.iter().find(|(known, _)| *known == key).map(|(_, text)| *text)
+    };
+    find(current().table()).or_else(|| find(ENGLISH)).unwrap_or(key)
+}
+
+/// Replace each `{}` in `template` with the next value; see `t!`
+pub fn fill(template: &str, values: &[&dyn std::fmt::Display]) -> String {
+    let mut values = values.iter();
+    let mut parts = template.split("{}");
+    let mut text = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        if let Some(value) = values.next() {
+            text.push_str(&value.to_string());
+        }
+        text.push_str(part);
+    }
+    text
+}
+
+/// Look up a UI string by key in the active language
+macro_rules! t {
+    ($key:literal) => {
+        $crate::i18n::tr($key)
+    };
+    ($key:literal, $($value:expr),+ $(,)?) => {
+        $crate::i18n::fill($crate::i18n::tr($key), &[$(&$value as &dyn std::fmt::Display),+])
+    };
+}
+pub(crate) use t;
+
+const ENGLISH: &[(&str, &str)] = &[
+    ("theme.system", "System"),
+    ("theme.dark", "Dark"),
+    ("theme.light", "Light"),
+    ("header.connected_device", "Connected Device"),
+    ("header.no_razer_device", "No Razer Device"),
+    ("header.detecting", "Detecting device…"),
+    ("header.no_device", "No device detected"),
+    ("header.retry", "Retry"),
+    ("performance.title", "Performance Mode"),
+    ("performance.ac_power", "AC Power"),
+    ("performance.battery", "Battery"),
+    ("fan.title", "Fan Control"),
+    ("lighting.title", "Lighting"),
+    ("battery.title", "Battery"),
+    ("battery.charging", "Charging"),
+    ("battery.not_charging", "Not charging"),
+    ("battery.full", "Full"),
+    ("battery.discharging", "On battery"),
+    ("battery.low", "Low"),
+    ("battery.critical", "Critical"),
+    ("profiles.title", "Profiles"),
+    ("profiles.export", "Export profiles…"),
+    ("profiles.import", "Import profiles…"),
+    ("profiles.save_current", "Save current"),
+    ("app_rules.title", "App rules"),
+    ("history.title", "Message history"),
+    ("footer.settings", "Settings"),
+    ("footer.debug", "Debug"),
+    ("settings.appearance", "Appearance"),
+    ("settings.language", "Language"),
+    ("settings.theme", "Theme"),
+    ("settings.ui_scale", "UI scale"),
+    ("settings.temperature", "Temperature"),
+    ("settings.fan_percent", "Fan speed in %"),
+    ("settings.fade_brightness", "Fade brightness"),
+    ("settings.messages", "Messages"),
+    ("settings.notify_switch", "Notify on profile switch"),
+    ("settings.show_messages", "Show messages"),
+    ("settings.messages_shown", "Messages shown"),
+    ("settings.control", "Control"),
+    ("settings.monitor_only", "Monitor only"),
+    ("settings.manual_only", "Manual control only"),
+    ("settings.confirm_mode", "Confirm mode changes"),
+    ("settings.throttle_warning", "Throttle warning"),
+    ("settings.integration", "Integration"),
+    ("settings.close_to_tray", "Close to tray"),
+    ("settings.start_minimized", "Start minimized"),
+    ("settings.autostart", "Start with Windows"),
+    ("settings.check_updates", "Check for updates"),
+    ("settings.compact_view", "Compact view"),
+    ("settings.reset_window", "Reset window"),
+    ("settings.copy_diagnostics", "Copy diagnostics"),
+    ("confirm.discard_title", "Discard changes to '{}'?"),
+    ("confirm.discard_body", "The current settings differ from the saved profile. Switching replaces them; use 💾 in the header to keep them first."),
+    ("confirm.discard_switch", "Discard and switch"),
+    ("confirm.cancel", "Cancel"),
+    ("confirm.reset_home_title", "Reset to home?"),
+    ("confirm.reset_home_body", "This replaces the current device settings with: {}."),
+    ("confirm.reset", "Reset"),
+    ("confirm.perf_title", "Switch to {}?"),
+    ("confirm.perf_body", "Changing the performance mode briefly returns the fans to Auto. They will be restored to {} RPM right after."),
+    ("confirm.switch", "Switch"),
+    ("confirm.switch_dont_ask", "Switch, don't ask again"),
+    ("elevation.title", "Not running as administrator"),
+    ("elevation.body", "Some controls may fail on this machine without elevated rights. Relaunch as administrator if settings do not apply."),
+    ("elevation.relaunch", "Relaunch as administrator"),
+    ("elevation.dont_show_again", "Don't show again"),
+    ("elevation.dismiss", "Dismiss"),
+    ("app_rules.enabled", "Switch mode for focused app"),
+    ("app_rules.enabled_hint", "Manual mode changes are respected for a few seconds"),
+    ("app_rules.otherwise", "Otherwise:"),
+    ("app_rules.no_rules", "No rules"),
+    ("app_rules.delete", "Delete rule"),
+    ("app_rules.add", "Add"),
+    ("app_rules.use_last_app", "Use last app"),
+    ("battery.care", "Battery Health Optimizer"),
+    ("battery.care_unsupported", "Not supported on this device"),
+    ("battery.remaining", "{} remaining"),
+    ("battery.to_full", "{} to full"),
+    ("battery.held_at_limit", "Plugged in; Battery Health Optimizer is holding the charge at its limit"),
+    ("battery.not_taking_charge", "Plugged in, but the battery is not taking charge"),
+    ("battery.care_active", "Active (Hardware default: 80%)"),
+    ("battery.care_disabled", "Disabled"),
+    ("battery.below", "Below"),
+    ("battery.below_hint", "Apply a saved profile once when the battery runs low"),
+    ("battery.apply", "apply"),
+    ("battery.select_profile", "Select profile"),
+    ("profiles.none", "No saved profiles"),
+    ("footer.read_time", "read: {}ms"),
+    ("footer.read_time_hint", "Duration of the last full device read"),
+    ("footer.update_available", "Update available: {}"),
+    ("footer.open_release", "Open the release page"),
+    ("footer.made_by", "Made with ♥ by Fatalution"),
+    ("compact.next_mode", "Switch to the next performance mode"),
+    ("compact.full_view", "Full view"),
+    ("history.empty", "No messages yet"),
+    ("history.seconds_ago", "{}s ago"),
+    ("history.minutes_ago", "{}m ago"),
+    ("history.hours_ago", "{}h ago"),
+    ("fan.auto", "Auto"),
+    ("fan.manual", "Manual"),
+    ("fan.curve", "Curve"),
+    ("fan.max", "Max"),
+    ("fan.a11y_auto", "Fan mode Auto"),
+    ("fan.a11y_manual", "Fan mode Manual"),
+    ("fan.a11y_curve", "Fan mode Curve"),
+    ("fan.a11y_max", "Max fan speed"),
+    ("fan.mode_shortcut", "Shortcut: F toggles Auto/Manual"),
+    ("fan.curve_hint", "Set fan speed from CPU temperature"),
+    ("fan.max_hint", "Run the fans at full speed until turned off"),
+    ("fan.fan1", "Fan 1:"),
+    ("fan.fan2", "Fan 2:"),
+    ("fan.set_rpm", "Set: {}"),
+    ("fan.set_auto", "Set: Auto"),
+    ("fan.supported_range", "Supported range: {}–{} RPM"),
+    ("fan.default_range", "Default range: {}–{} RPM (no limits are listed for this model)"),
+    ("fan.cpu_temp_unavailable", "CPU temperature unavailable"),
+    ("fan.apply_curve", "Apply curve"),
+    ("fan.reset_curve", "Reset"),
+    ("fan.reset_curve_hint", "Discard unapplied edits"),
+    ("fan.history_range", "Last 60s: {}–{} RPM"),
+    ("fan.current_mode", "Current: {}"),
+    ("lighting.unsupported", "Not supported on this device"),
+    ("lighting.logo_mode", "Logo Mode:"),
+    ("lighting.logo_static", "Static"),
+    ("lighting.logo_breathing", "Breathing"),
+    ("lighting.logo_off", "Off"),
+    ("lighting.a11y_logo_mode", "Logo mode {}"),
+    ("lighting.keyboard_brightness", "Keyboard Brightness:"),
+    ("lighting.backlight_off", "Off"),
+    ("lighting.a11y_backlight_off", "Keyboard backlight off"),
+    ("lighting.backlight_off_hint", "Turn the keyboard backlight off"),
+    ("lighting.a11y_lights", "Keyboard lights"),
+    ("lighting.dim_hint", "Set brightness to 0; click again to restore it"),
+    ("lighting.restore_hint", "Restore the previous brightness"),
+    ("lighting.brightness_shortcut", "Shortcut: + / -"),
+    ("lighting.keyboard_color", "Keyboard Color:"),
+    ("lighting.always_on", "Keyboard Backlight Always On"),
+    ("performance.describe_battery", "Battery: lowest power limits, for the longest runtime unplugged"),
+    ("performance.describe_silent", "Silent: low power and quiet fans, best for light work on battery"),
+    ("performance.describe_balanced", "Balanced: everyday power limits and moderate fan noise"),
+    ("performance.describe_performance", "Performance: higher CPU/GPU power, louder fans"),
+    ("performance.describe_hyperboost", "Hyperboost: maximum power limits and fans, best plugged in"),
+    ("performance.describe_custom", "Custom: pick the CPU and GPU boost levels yourself"),
+    ("performance.a11y_cpu_boost", "CPU boost {}"),
+    ("performance.a11y_gpu_boost", "GPU boost {}"),
+    ("performance.a11y_mode", "{} performance mode"),
+    ("performance.a11y_show_hidden", "Show hidden modes and boosts"),
+    ("performance.show_hidden_hint", "Show/Hide hidden modes & boosts"),
+    ("performance.activate_custom", "Activate Custom mode to apply"),
+    ("performance.combination_not_allowed", "Combination not allowed by firmware descriptor"),
+    ("performance.unconfirmed_pairs", "Allowed combinations not confirmed for this model; the firmware may reject some"),
+    ("performance.undervolt_inactive", "Hidden preset (Undervolt). Activate Custom mode to apply."),
+    ("performance.undervolt_unconfirmed", "Hidden preset (Undervolt). Behavior not fully confirmed."),
+    ("performance.mode_shortcut", "Shortcut: {}"),
+    ("performance.hidden_mode", "Hidden / unsupported by descriptor"),
+    ("performance.custom_active", "Custom mode active"),
+    ("performance.custom_switch", "Switch to Custom mode"),
+    ("performance.thermal_warning", "CPU at {}, likely thermal-throttling; a higher mode may not help"),
+    ("profiles.export_hint", "Save the profiles to a file to share"),
+    ("profiles.import_hint", "Add profiles from a shared file"),
+    ("profiles.delete", "Delete profile"),
+    ("profiles.rename", "Rename profile"),
+    ("profiles.apply", "Apply"),
+    ("profiles.new_name", "New profile name"),
+    ("profiles.duplicate_name", "A profile with this name already exists"),
+    ("profiles.save_current_hint", "Save the current device settings as a profile"),
+    ("profiles.scope_logo", "Logo"),
+    ("profiles.scope_keyboard", "Keyboard"),
+    ("profiles.scope_fan", "Fan"),
+    ("profiles.scope_lights_always_on", "Lights always on"),
+    ("profiles.scope_battery_care", "Battery care"),
+    ("profiles.auto_switch_scope", "Auto-switch also applies"),
+    ("profiles.auto_switch", "Switch on AC/Battery changes"),
+    ("profiles.auto_switch_hint", "Apply the AC or Battery profile when the power source changes"),
+    ("profiles.mode_follows_power", "The performance mode always follows AC/Battery"),
+    ("profiles.ac", "AC"),
+    ("profiles.battery", "Battery"),
+    ("profiles.battery_lights_off", "Lights off on battery"),
+    ("profiles.battery_lights_off_hint", "Keyboard backlight off when unplugged, restored on AC"),
+    ("profiles.logo_too", "Logo too"),
+    ("profiles.on_launch", "On launch apply"),
+    ("profiles.on_launch_hint", "Applied once at every start, on AC or battery"),
+    ("profiles.nothing", "Nothing"),
+    ("profiles.copy_to", "Copy to {}"),
+    ("profiles.copy_to_hint", "Use the current settings as the {} profile, then adjust what should differ"),
+    ("profiles.ac_edited", "AC profile*"),
+    ("profiles.ac_edited_hint", "Changed; applies the next time the laptop is plugged in"),
+    ("profiles.battery_edited", "Battery profile*"),
+    ("profiles.battery_edited_hint", "Changed; applies the next time the laptop is unplugged"),
+    ("profiles.power_plan", "Windows power plan"),
+    ("profiles.power_plan_enabled", "Apply a profile when the power plan changes"),
+    ("profiles.power_plan_hint", "Lets the Windows power slider drive the device, alongside AC/Battery"),
+    ("profiles.do_nothing", "Do nothing"),
+    ("profiles.plan_high_performance", "High performance"),
+    ("profiles.plan_balanced", "Balanced"),
+    ("profiles.plan_power_saver", "Power saver"),
+    ("settings.fan_percent_hint", "Show fan speed as a share of the maximum RPM and set it in percent"),
+    ("settings.fade_brightness_hint", "Ramp keyboard brightness smoothly; sends a few extra commands per change"),
+    ("settings.notify_switch_hint", "Show a notification when the AC/Battery profile switches automatically"),
+    ("settings.messages_shown_hint", "Stack several recent messages in the header; errors stay on top"),
+    ("settings.confirm_mode_hint", "Ask before switching modes while the fans are in manual mode"),
+    ("settings.throttle_warning_hint", "CPU temperature at which Performance/Hyperboost are marked"),
+    ("settings.reset_home_hint", "Write the home state to the device"),
+    ("settings.home_to_current_hint", "Use the current settings as home"),
+    ("settings.home_defaults_hint", "Home is Balanced, fans Auto, logo off, 50% brightness, battery care on"),
+    ("settings.close_to_tray_hint", "Closing the window hides R-Helper to the system tray"),
+    ("settings.check_updates_hint", "Ask GitHub for the latest release once per launch"),
+    ("settings.compact_view_hint", "Small always-on-top readout of mode and fan speed"),
+    ("settings.reset_window_hint", "Restore the default size and position"),
+    ("settings.copy_diagnostics_hint", "Copy system and device details for a bug report"),
+    ("settings.system_scale_hint", "Follow the display scaling set in Windows"),
+    ("settings.show_messages_hint", "Seconds status and error messages stay visible before fading"),
+    ("settings.status_messages", "Status messages"),
+    ("settings.errors", "Errors"),
+    ("settings.api_token_hint", "Clients send Authorization: Bearer <token>; empty rejects all"),
+    ("settings.autostart_repair_hint", "Point autostart at this executable"),
+    ("settings.monitor_only_hint", "Read-only: keep the readouts updating but never write to the device (also available as --monitor)"),
+    ("settings.manual_only_hint", "Only change the device when you ask: no fan RPM/curve enforcement and no automatic profile switching"),
+    ("settings.reset_home", "Reset to home…"),
+    ("settings.home_to_current", "Set to current"),
+    ("settings.home_defaults", "Defaults"),
+    ("settings.start_minimized_tray_hint", "Launch hidden to the system tray; open the window from the tray icon"),
+    ("settings.start_minimized_hint", "Launch minimized to the taskbar"),
+    ("settings.telemetry_pipe", "Telemetry pipe"),
+    ("settings.telemetry_pipe_hint", "Stream mode, fan RPM, battery and AC state as JSON lines on \\\\.\\pipe\\r-helper for desktop widgets"),
+    ("settings.system_scale", "System"),
+    ("settings.api", "Automation API"),
+    ("settings.api_hint", "Local HTTP endpoint on 127.0.0.1: GET /state, POST /perf {\"mode\":…}, POST /profile {\"name\":…}"),
+    ("settings.api_port", "Port"),
+    ("settings.api_token", "Token"),
+    ("settings.autostart_stale", "Points to a missing file"),
+    ("settings.autostart_repair", "Repair"),
+    ("header.refresh_hint", "Re-read all settings from the device"),
+    ("header.undo_hint", "Undo the last change (Ctrl+Z)"),
+    ("header.profile_matches", "Device matches this saved profile"),
+    ("header.profile_changed", "Settings changed since this profile was applied or saved"),
+    ("header.unsaved", "Custom/Unsaved"),
+    ("header.unsaved_hint", "No saved profile has been applied since these settings were made"),
+    ("header.save_profile_hint", "Save the current settings to this profile"),
+    ("header.no_unsaved_changes", "No unsaved changes"),
+    ("header.retry_hint", "Look for the device again"),
+    ("header.monitor_only", "Monitor only"),
+    ("header.monitor_only_hint", "Read-only: nothing is written to the device"),
+    ("header.manual_only", "Manual only"),
+    ("header.manual_only_hint", "Enforcement is off: the fan RPM may drift and profiles do not switch automatically"),
+    ("header.unmatched", "librazer not matched"),
+    ("header.unmatched_hint", "This laptop is not in librazer's supported device list"),
+    ("header.firmware", "Firmware {}"),
+    ("header.integrated_gpu_only", "Only the integrated GPU is visible (dGPU disabled or MUX set to iGPU)"),
+    ("profiles.row_hint", "{} • Fan {}"),
+    ("tray.show", "Show"),
+    ("tray.reset_window", "Reset window"),
+    ("tray.quit", "Quit"),
+    ("fan.unavailable", "N/A"),
+    ("messages.config_invalid_moved", "Settings could not be read and were reset; the old file was kept as {}"),
+    ("messages.config_invalid", "Settings could not be read and were reset"),    ("messages.monitor_only", "Monitor only: changes are disabled"),
+    ("messages.no_device", "No device connected"),
+    ("messages.device_disconnected", "Device disconnected"),
+    ("messages.device_reconnected", "Device reconnected"),
+    ("messages.initializing", "Initializing..."),
+    ("messages.initialized", "Initialization complete"),
+    ("messages.specs_loaded", "System specifications loaded"),
+    ("messages.read_status_failed", "Failed to read device status: {}"),
+    ("messages.failed_with", "{}: {}"),
+    ("messages.mode_updated", "Mode updated"),
+    ("messages.updated_externally", "Device state updated externally"),
+    ("messages.interference", "Another app appears to be controlling this device"),
+    ("messages.undid", "Undid last change"),
+    ("messages.undid_settings", "Undid {} change"),
+    ("messages.undo_failed", "Failed to undo"),
+    ("messages.save_failed", "Failed to save settings: {}"),
+    ("messages.window_reset", "Window position reset"),
+    ("messages.refreshed", "Device state refreshed"),
+    ("messages.refresh_failed", "Failed to refresh device state: {}"),
+    ("messages.diagnostics_copied", "Diagnostics copied to clipboard"),
+    ("messages.diagnostics_failed", "Failed to build diagnostics: {}"),
+    ("messages.autostart_enabled", "Start with Windows enabled"),
+    ("messages.autostart_disabled", "Start with Windows disabled"),
+    ("messages.autostart_failed", "Failed to update autostart: {}"),
+    ("messages.performance_mode", "Performance mode: {}"),
+    ("messages.pipe_open", "Telemetry pipe open at {}"),
+    ("messages.pipe_failed", "Telemetry pipe: {}"),
+    ("messages.api_listening", "Automation API listening on 127.0.0.1:{}"),
+    ("messages.api_failed", "Automation API: {}"),
+    ("messages.api_unknown_mode", "Automation: unknown performance mode '{}'"),
+    ("messages.api_unknown_profile", "Automation: no profile named '{}'"),
+    ("messages.profile_read_failed", "Failed to read device state for profile"),
+    ("messages.copied_to_power_profile", "Copied the current settings to the {} profile"),
+    ("messages.auto_switched", "⚡ Auto-switched to {} profile"),
+    ("messages.auto_switch_failed", "Failed to switch to {} profile"),
+    ("messages.switched_notification", "Switched to {} profile"),
+    ("messages.applying_profile", "Applying profile…"),
+    ("messages.profile_saved", "Profile '{}' saved"),
+    ("messages.profile_missing", "Profile '{}' no longer exists"),
+    ("messages.home_reset", "Reset to home state"),
+    ("messages.home_reset_failed", "Failed to reset to home state"),
+    ("messages.home_set", "Home state set to the current settings"),
+    ("messages.unknown_mode", "Unknown performance mode '{}'"),
+    ("messages.set_mode_failed", "Failed to set performance mode: {}"),
+    ("messages.mode_restore_failed", "Could not restore {} after performance mode change"),
+    ("messages.mode_changed", "Mode changed"),
+    ("messages.cpu_boost_not_allowed", "CPU {} is not supported together with GPU {}"),
+    ("messages.gpu_boost_not_allowed", "GPU {} is not supported together with CPU {}"),
+    ("messages.cpu_boost_failed", "Failed CPU boost"),
+    ("messages.gpu_boost_failed", "Failed GPU boost"),
+    ("messages.cpu_boost_set", "CPU {}"),
+    ("messages.gpu_boost_set", "GPU {}"),
+    ("messages.set_fan_failed", "Failed to set fan: {}"),
+    ("messages.fan_auto", "Fan set to auto mode"),
+    ("messages.fan_manual", "Fan set to manual mode"),
+    ("messages.set_rpm_failed", "Failed to set fan RPM"),
+    ("messages.rpm_set", "Fans RPM set to: {}"),
+    ("messages.zone_rpm_set", "Fan {} RPM set to: {}"),
+    ("messages.kept_manual_mode", "Kept the current mode on {}: it was just changed manually"),
+    ("messages.power_plan_applied", "🖥 {} power plan: applied '{}'"),
+    ("messages.power_plan_missing", "Profile '{}' for the {} power plan no longer exists"),
+    ("messages.fan", "Fan"),
+    ("messages.fan2", "Fan 2"),
+    ("messages.fan_stopped", "{} not spinning (set to {} RPM)"),
+    ("messages.fan_below_target", "{} below target: {} of {} RPM"),
+    ("messages.fan_auto_externally", "Fans switched to Auto externally; manual RPM released"),
+    ("messages.max_fan_enabled", "Max fan enabled"),
+    ("messages.max_fan_disabled", "Max fan disabled"),
+    ("messages.max_fan_enable_failed", "Failed to enable max fan: {}"),
+    ("messages.max_fan_disable_failed", "Failed to disable max fan: {}"),
+    ("messages.logo_failed", "Failed to set logo mode"),
+    ("messages.logo_set", "Logo mode set to {}"),
+    ("messages.color_failed", "Failed to set keyboard color"),
+    ("messages.color_set", "Keyboard color set to {}"),
+    ("messages.backlight_failed", "Failed to set keyboard backlight"),
+    ("messages.backlight_on", "Keyboard backlight on"),
+    ("messages.backlight_off", "Keyboard backlight off"),
+    ("messages.brightness_failed", "Failed to set brightness"),
+    ("messages.brightness_set", "Brightness set to {}%"),
+    ("messages.always_on_enabled", "Keyboard Backlight Always On enabled"),
+    ("messages.always_on_disabled", "Keyboard Backlight Always On disabled"),
+    ("messages.always_on_failed", "Failed to set lights always on: {}"),
+    ("messages.battery_care_enabled", "Battery care enabled"),
+    ("messages.battery_care_disabled", "Battery care disabled"),
+    ("messages.battery_care_failed", "Failed to set battery care: {}"),
+    ("messages.profile_applied", "Applied profile '{}'"),
+    ("messages.profile_apply_failed", "Failed to apply profile '{}'"),
+    ("messages.low_battery_applied", "🪫 Battery at {}%: applied '{}' profile"),
+    ("messages.low_battery_missing", "Low battery profile '{}' no longer exists"),
+    ("messages.startup_monitor_only", "Startup profile '{}' not applied in monitor-only mode"),
+    ("messages.startup_applied", "🚀 Applied startup profile '{}'"),
+    ("messages.startup_missing", "Startup profile '{}' no longer exists"),
+    ("messages.exported", "Exported {} profile(s) to {}"),
+    ("messages.export_failed", "Failed to export profiles: {}"),
+    ("messages.import_failed", "Failed to import profiles: {}"),
+    ("messages.imported", "Imported {} profile(s)"),
+    ("messages.imported_skipped", "Imported {} profile(s); skipped {} not supported by this device: {}"),
+    ("messages.relaunch_failed", "Failed to relaunch: {}"),
+    ("messages.invalid_profile_name", "Invalid profile name '{}'"),
+    ("messages.profile_deleted", "Profile '{}' deleted"),
+    ("messages.app_focused", "{} focused: {}"),
+    ("messages.home_defaults", "Home state restored to defaults"),
+    ("confirm.home_summary", "{}, fans {}, logo {}, {}% brightness, battery care {}"),
+    ("confirm.on", "on"),
+    ("confirm.off", "off"),
+    ("messages.profile_file_type", "R-Helper profiles"),
+    ("messages.export_title", "Export profiles"),
+    ("messages.import_title", "Import profiles"),
+];
+
+const GERMAN: &[(&str, &str)] = &[
+    ("theme.system", "System"),
+    ("theme.dark", "Dunkel"),
+    ("theme.light", "Hell"),
+    ("header.connected_device", "Verbundenes Gerät"),
+    ("header.no_razer_device", "Kein Razer-Gerät"),
+    ("header.detecting", "Gerät wird erkannt…"),
+    ("header.no_device", "Kein Gerät erkannt"),
+    ("header.retry", "Erneut suchen"),
+    ("performance.title", "Leistungsmodus"),
+    ("performance.ac_power", "Netzbetrieb"),
+    ("performance.battery", "Akku"),
+    ("fan.title", "Lüftersteuerung"),
+    ("lighting.title", "Beleuchtung"),
+    ("battery.title", "Akku"),
+    ("battery.charging", "Lädt"),
+    ("battery.not_charging", "Lädt nicht"),
+    ("battery.full", "Voll"),
+    ("battery.discharging", "Akkubetrieb"),
+    ("battery.low", "Niedrig"),
+    ("battery.critical", "Kritisch"),
+    ("profiles.title", "Profile"),
+    ("profiles.export", "Profile exportieren…"),
+    ("profiles.import", "Profile importieren…"),
+    ("profiles.save_current", "Aktuelle speichern"),
+    ("app_rules.title", "App-Regeln"),
+    ("history.title", "Meldungsverlauf"),
+    ("footer.settings", "Einstellungen"),
+    ("footer.debug", "Debug"),
+    ("settings.appearance", "Darstellung"),
+    ("settings.language", "Sprache"),
+    ("settings.theme", "Design"),
+    ("settings.ui_scale", "UI-Skalierung"),
+    ("settings.temperature", "Temperatur"),
+    ("settings.fan_percent", "Lüfterdrehzahl in %"),
+    ("settings.fade_brightness", "Helligkeit überblenden"),
+    ("settings.messages", "Meldungen"),
+    ("settings.notify_switch", "Bei Profilwechsel benachrichtigen"),
+    ("settings.show_messages", "Meldungen anzeigen"),
+    ("settings.messages_shown", "Angezeigte Meldungen"),
+    ("settings.control", "Steuerung"),
+    ("settings.monitor_only", "Nur überwachen"),
+    ("settings.manual_only", "Nur manuelle Steuerung"),
+    ("settings.confirm_mode", "Moduswechsel bestätigen"),
+    ("settings.throttle_warning", "Drosselungswarnung"),
+    ("settings.integration", "Integration"),
+    ("settings.close_to_tray", "In den Infobereich schließen"),
+    ("settings.start_minimized", "Minimiert starten"),
+    ("settings.autostart", "Mit Windows starten"),
+    ("settings.check_updates", "Nach Updates suchen"),
+    ("settings.compact_view", "Kompaktansicht"),
+    ("settings.reset_window", "Fenster zurücksetzen"),
+    ("settings.copy_diagnostics", "Diagnose kopieren"),
+    ("confirm.discard_title", "Änderungen an „{}“ verwerfen?"),
+    ("confirm.discard_body", "Die aktuellen Einstellungen weichen vom gespeicherten Profil ab. Ein Wechsel ersetzt sie; mit 💾 in der Kopfzeile lassen sie sich vorher sichern."),
+    ("confirm.discard_switch", "Verwerfen und wechseln"),
+    ("confirm.cancel", "Abbrechen"),
+    ("confirm.reset_home_title", "Auf Ausgangszustand zurücksetzen?"),
+    ("confirm.reset_home_body", "Dies ersetzt die aktuellen Geräteeinstellungen durch: {}."),
+    ("confirm.reset", "Zurücksetzen"),
+    ("confirm.perf_title", "Zu {} wechseln?"),
+    ("confirm.perf_body", "Ein Wechsel des Leistungsmodus setzt die Lüfter kurz auf Auto. Danach werden sie wieder auf {} U/min gestellt."),
+    ("confirm.switch", "Wechseln"),
+    ("confirm.switch_dont_ask", "Wechseln, nicht mehr fragen"),
+    ("elevation.title", "Nicht als Administrator gestartet"),
+    ("elevation.body", "Ohne erhöhte Rechte können einige Steuerungen auf diesem Gerät fehlschlagen. Als Administrator neu starten, wenn Einstellungen nicht übernommen werden."),
+    ("elevation.relaunch", "Als Administrator neu starten"),
+    ("elevation.dont_show_again", "Nicht mehr anzeigen"),
+    ("elevation.dismiss", "Schließen"),
+    ("app_rules.enabled", "Modus je nach aktiver App wechseln"),
+    ("app_rules.enabled_hint", "Manuelle Moduswechsel bleiben einige Sekunden bestehen"),
+    ("app_rules.otherwise", "Sonst:"),
+    ("app_rules.no_rules", "Keine Regeln"),
+    ("app_rules.delete", "Regel löschen"),
+    ("app_rules.add", "Hinzufügen"),
+    ("app_rules.use_last_app", "Letzte App übernehmen"),
+    ("battery.care", "Akkuschonung"),
+    ("battery.care_unsupported", "Auf diesem Gerät nicht unterstützt"),
+    ("battery.remaining", "noch {}"),
+    ("battery.to_full", "{} bis voll"),
+    ("battery.held_at_limit", "Angeschlossen; die Akkuschonung hält die Ladung an ihrer Grenze"),
+    ("battery.not_taking_charge", "Angeschlossen, aber der Akku lädt nicht"),
+    ("battery.care_active", "Aktiv (Hardware-Standard: 80 %)"),
+    ("battery.care_disabled", "Deaktiviert"),
+    ("battery.below", "Unter"),
+    ("battery.below_hint", "Bei niedrigem Akkustand einmalig ein gespeichertes Profil anwenden"),
+    ("battery.apply", "anwenden"),
+    ("battery.select_profile", "Profil wählen"),
+    ("profiles.none", "Keine gespeicherten Profile"),
+    ("footer.read_time", "Lesen: {} ms"),
+    ("footer.read_time_hint", "Dauer des letzten vollständigen Gerätelesens"),
+    ("footer.update_available", "Update verfügbar: {}"),
+    ("footer.open_release", "Release-Seite öffnen"),
+    ("footer.made_by", "Mit ♥ gemacht von Fatalution"),
+    ("compact.next_mode", "Zum nächsten Leistungsmodus wechseln"),
+    ("compact.full_view", "Vollansicht"),
+    ("history.empty", "Noch keine Meldungen"),
+    ("history.seconds_ago", "vor {} s"),
+    ("history.minutes_ago", "vor {} min"),
+    ("history.hours_ago", "vor {} h"),
+    ("fan.auto", "Auto"),
+    ("fan.manual", "Manuell"),
+    ("fan.curve", "Kurve"),
+    ("fan.max", "Max"),
+    ("fan.a11y_auto", "Lüftermodus Auto"),
+    ("fan.a11y_manual", "Lüftermodus Manuell"),
+    ("fan.a11y_curve", "Lüftermodus Kurve"),
+    ("fan.a11y_max", "Maximale Lüfterdrehzahl"),
+    ("fan.mode_shortcut", "Tastenkürzel: F wechselt Auto/Manuell"),
+    ("fan.curve_hint", "Lüfterdrehzahl nach CPU-Temperatur steuern"),
+    ("fan.max_hint", "Lüfter bis zum Ausschalten mit voller Drehzahl laufen lassen"),
+    ("fan.fan1", "Lüfter 1:"),
+    ("fan.fan2", "Lüfter 2:"),
+    ("fan.set_rpm", "Soll: {}"),
+    ("fan.set_auto", "Soll: Auto"),
+    ("fan.supported_range", "Unterstützter Bereich: {}–{} U/min"),
+    ("fan.default_range", "Standardbereich: {}–{} U/min (für dieses Modell sind keine Grenzen hinterlegt)"),
+    ("fan.cpu_temp_unavailable", "CPU-Temperatur nicht verfügbar"),
+    ("fan.apply_curve", "Kurve anwenden"),
+    ("fan.reset_curve", "Zurücksetzen"),
+    ("fan.reset_curve_hint", "Nicht angewendete Änderungen verwerfen"),
+    ("fan.history_range", "Letzte 60 s: {}–{} U/min"),
+    ("fan.current_mode", "Aktuell: {}"),
+    ("lighting.unsupported", "Auf diesem Gerät nicht unterstützt"),
+    ("lighting.logo_mode", "Logo-Modus:"),
+    ("lighting.logo_static", "Statisch"),
+    ("lighting.logo_breathing", "Atmend"),
+    ("lighting.logo_off", "Aus"),
+    ("lighting.a11y_logo_mode", "Logo-Modus {}"),
+    ("lighting.keyboard_brightness", "Tastaturhelligkeit:"),
+    ("lighting.backlight_off", "Aus"),
+    ("lighting.a11y_backlight_off", "Tastaturbeleuchtung aus"),
+    ("lighting.backlight_off_hint", "Tastaturbeleuchtung ausschalten"),
+    ("lighting.a11y_lights", "Tastaturbeleuchtung"),
+    ("lighting.dim_hint", "Helligkeit auf 0 setzen; erneut klicken stellt sie wieder her"),
+    ("lighting.restore_hint", "Vorherige Helligkeit wiederherstellen"),
+    ("lighting.brightness_shortcut", "Tastenkürzel: + / -"),
+    ("lighting.keyboard_color", "Tastaturfarbe:"),
+    ("lighting.always_on", "Tastaturbeleuchtung immer an"),
+    ("performance.describe_battery", "Battery: niedrigste Leistungsgrenzen für die längste Akkulaufzeit"),
+    ("performance.describe_silent", "Silent: wenig Leistung und leise Lüfter, ideal für leichte Arbeit im Akkubetrieb"),
+    ("performance.describe_balanced", "Balanced: alltägliche Leistungsgrenzen und mäßige Lüftergeräusche"),
+    ("performance.describe_performance", "Performance: mehr CPU-/GPU-Leistung, lautere Lüfter"),
+    ("performance.describe_hyperboost", "Hyperboost: maximale Leistungsgrenzen und Lüfter, am besten am Netz"),
+    ("performance.describe_custom", "Custom: CPU- und GPU-Boost selbst wählen"),
+    ("performance.a11y_cpu_boost", "CPU-Boost {}"),
+    ("performance.a11y_gpu_boost", "GPU-Boost {}"),
+    ("performance.a11y_mode", "Leistungsmodus {}"),
+    ("performance.a11y_show_hidden", "Versteckte Modi und Boosts anzeigen"),
+    ("performance.show_hidden_hint", "Versteckte Modi und Boosts ein-/ausblenden"),
+    ("performance.activate_custom", "Zum Anwenden den Custom-Modus aktivieren"),
+    ("performance.combination_not_allowed", "Kombination laut Firmware-Beschreibung nicht erlaubt"),
+    ("performance.unconfirmed_pairs", "Erlaubte Kombinationen für dieses Modell nicht bestätigt; die Firmware lehnt eventuell einige ab"),
+    ("performance.undervolt_inactive", "Verstecktes Preset (Undervolt). Zum Anwenden den Custom-Modus aktivieren."),
+    ("performance.undervolt_unconfirmed", "Verstecktes Preset (Undervolt). Verhalten nicht vollständig bestätigt."),
+    ("performance.mode_shortcut", "Tastenkürzel: {}"),
+    ("performance.hidden_mode", "Versteckt / laut Beschreibung nicht unterstützt"),
+    ("performance.custom_active", "Custom-Modus aktiv"),
+    ("performance.custom_switch", "Zum Custom-Modus wechseln"),
+    ("performance.thermal_warning", "CPU bei {}, drosselt wahrscheinlich thermisch; ein höherer Modus hilft eventuell nicht"),
+    ("profiles.export_hint", "Profile zum Weitergeben in eine Datei speichern"),
+    ("profiles.import_hint", "Profile aus einer geteilten Datei hinzufügen"),
+    ("profiles.delete", "Profil löschen"),
+    ("profiles.rename", "Profil umbenennen"),
+    ("profiles.apply", "Anwenden"),
+    ("profiles.new_name", "Neuer Profilname"),
+    ("profiles.duplicate_name", "Ein Profil mit diesem Namen existiert bereits"),
+    ("profiles.save_current_hint", "Aktuelle Geräteeinstellungen als Profil speichern"),
+    ("profiles.scope_logo", "Logo"),
+    ("profiles.scope_keyboard", "Tastatur"),
+    ("profiles.scope_fan", "Lüfter"),
+    ("profiles.scope_lights_always_on", "Beleuchtung immer an"),
+    ("profiles.scope_battery_care", "Akkuschonung"),
+    ("profiles.auto_switch_scope", "Automatischer Wechsel übernimmt auch"),
+    ("profiles.auto_switch", "Bei Netz-/Akkuwechsel umschalten"),
+    ("profiles.auto_switch_hint", "Netz- oder Akkuprofil anwenden, wenn sich die Stromquelle ändert"),
+    ("profiles.mode_follows_power", "Der Leistungsmodus folgt immer Netz/Akku"),
+    ("profiles.ac", "Netz"),
+    ("profiles.battery", "Akku"),
+    ("profiles.battery_lights_off", "Beleuchtung im Akkubetrieb aus"),
+    ("profiles.battery_lights_off_hint", "Tastaturbeleuchtung ohne Netzteil aus, am Netz wiederhergestellt"),
+    ("profiles.logo_too", "Logo auch"),
+    ("profiles.on_launch", "Beim Start anwenden"),
+    ("profiles.on_launch_hint", "Bei jedem Start einmal angewendet, am Netz oder im Akkubetrieb"),
+    ("profiles.nothing", "Nichts"),
+    ("profiles.copy_to", "Nach {} kopieren"),
+    ("profiles.copy_to_hint", "Aktuelle Einstellungen als Profil „{}“ übernehmen und danach anpassen, was abweichen soll"),
+    ("profiles.ac_edited", "Netzprofil*"),
+    ("profiles.ac_edited_hint", "Geändert; gilt, sobald der Laptop wieder am Netz ist"),
+    ("profiles.battery_edited", "Akkuprofil*"),
+    ("profiles.battery_edited_hint", "Geändert; gilt, sobald der Laptop wieder im Akkubetrieb ist"),
+    ("profiles.power_plan", "Windows-Energiesparplan"),
+    ("profiles.power_plan_enabled", "Profil anwenden, wenn sich der Energiesparplan ändert"),
+    ("profiles.power_plan_hint", "Lässt den Windows-Energieregler das Gerät steuern, zusätzlich zu Netz/Akku"),
+    ("profiles.do_nothing", "Nichts tun"),
+    ("profiles.plan_high_performance", "Höchstleistung"),
+    ("profiles.plan_balanced", "Ausbalanciert"),
+    ("profiles.plan_power_saver", "Energiesparmodus"),
+    ("settings.fan_percent_hint", "Lüfterdrehzahl als Anteil der Höchstdrehzahl anzeigen und in Prozent einstellen"),
+    ("settings.fade_brightness_hint", "Tastaturhelligkeit sanft überblenden; sendet pro Änderung einige zusätzliche Befehle"),
+    ("settings.notify_switch_hint", "Benachrichtigen, wenn das Netz-/Akkuprofil automatisch wechselt"),
+    ("settings.messages_shown_hint", "Mehrere aktuelle Meldungen in der Kopfzeile stapeln; Fehler bleiben oben"),
+    ("settings.confirm_mode_hint", "Vor einem Moduswechsel fragen, während die Lüfter manuell laufen"),
+    ("settings.throttle_warning_hint", "CPU-Temperatur, ab der Performance/Hyperboost markiert werden"),
+    ("settings.reset_home_hint", "Ausgangszustand auf das Gerät schreiben"),
+    ("settings.home_to_current_hint", "Aktuelle Einstellungen als Ausgangszustand verwenden"),
+    ("settings.home_defaults_hint", "Ausgangszustand: Balanced, Lüfter Auto, Logo aus, 50 % Helligkeit, Akkuschonung an"),
+    ("settings.close_to_tray_hint", "Beim Schließen des Fensters wird R-Helper im Infobereich ausgeblendet"),
+    ("settings.check_updates_hint", "Einmal pro Start bei GitHub nach dem neuesten Release fragen"),
+    ("settings.compact_view_hint", "Kleine, immer sichtbare Anzeige von Modus und Lüfterdrehzahl"),
+    ("settings.reset_window_hint", "Standardgröße und -position wiederherstellen"),
+    ("settings.copy_diagnostics_hint", "System- und Gerätedetails für einen Fehlerbericht kopieren"),
+    ("settings.system_scale_hint", "Der in Windows eingestellten Anzeigeskalierung folgen"),
+    ("settings.show_messages_hint", "Sekunden, die Status- und Fehlermeldungen sichtbar bleiben, bevor sie ausblenden"),
+    ("settings.status_messages", "Statusmeldungen"),
+    ("settings.errors", "Fehler"),
+    ("settings.api_token_hint", "Clients senden Authorization: Bearer <token>; leer lehnt alle ab"),
+    ("settings.autostart_repair_hint", "Autostart auf diese Programmdatei verweisen"),
+    ("settings.monitor_only_hint", "Nur lesen: Anzeigen aktualisieren, aber nie auf das Gerät schreiben (auch per --monitor)"),
+    ("settings.manual_only_hint", "Gerät nur auf Anforderung ändern: keine Durchsetzung von Lüfterdrehzahl/-kurve und kein automatischer Profilwechsel"),
+    ("settings.reset_home", "Auf Ausgangszustand zurücksetzen…"),
+    ("settings.home_to_current", "Aktuellen übernehmen"),
+    ("settings.home_defaults", "Standardwerte"),
+    ("settings.start_minimized_tray_hint", "Im Infobereich ausgeblendet starten; das Fenster über das Symbol öffnen"),
+    ("settings.start_minimized_hint", "Minimiert in der Taskleiste starten"),
+    ("settings.telemetry_pipe", "Telemetrie-Pipe"),
+    ("settings.telemetry_pipe_hint", "Modus, Lüfterdrehzahl, Akku- und Netzstatus als JSON-Zeilen über \\\\.\\pipe\\r-helper für Desktop-Widgets senden"),
+    ("settings.system_scale", "System"),
+    ("settings.api", "Automatisierungs-API"),
+    ("settings.api_hint", "Lokaler HTTP-Endpunkt auf 127.0.0.1: GET /state, POST /perf {\"mode\":…}, POST /profile {\"name\":…}"),
+    ("settings.api_port", "Port"),
+    ("settings.api_token", "Token"),
+    ("settings.autostart_stale", "Verweist auf eine fehlende Datei"),
+    ("settings.autostart_repair", "Reparieren"),
+    ("header.refresh_hint", "Alle Einstellungen erneut vom Gerät lesen"),
+    ("header.undo_hint", "Letzte Änderung rückgängig machen (Strg+Z)"),
+    ("header.profile_matches", "Gerät entspricht diesem gespeicherten Profil"),
+    ("header.profile_changed", "Einstellungen seit dem Anwenden oder Speichern dieses Profils geändert"),
+    ("header.unsaved", "Benutzerdefiniert/Ungespeichert"),
+    ("header.unsaved_hint", "Seit diesen Einstellungen wurde kein gespeichertes Profil angewendet"),
+    ("header.save_profile_hint", "Aktuelle Einstellungen in diesem Profil speichern"),
+    ("header.no_unsaved_changes", "Keine ungespeicherten Änderungen"),
+    ("header.retry_hint", "Erneut nach dem Gerät suchen"),
+    ("header.monitor_only", "Nur überwachen"),
+    ("header.monitor_only_hint", "Nur lesen: Es wird nichts auf das Gerät geschrieben"),
+    ("header.manual_only", "Nur manuell"),
+    ("header.manual_only_hint", "Durchsetzung aus: Die Lüfterdrehzahl kann abweichen und Profile wechseln nicht automatisch"),
+    ("header.unmatched", "von librazer nicht erkannt"),
+    ("header.unmatched_hint", "Dieser Laptop steht nicht in der Geräteliste von librazer"),
+    ("header.firmware", "Firmware {}"),
+    ("header.integrated_gpu_only", "Nur die integrierte GPU ist sichtbar (dGPU deaktiviert oder MUX auf iGPU)"),
+    ("profiles.row_hint", "{} • Lüfter {}"),
+    ("tray.show", "Anzeigen"),
+    ("tray.reset_window", "Fenster zurücksetzen"),
+    ("tray.quit", "Beenden"),
+    ("fan.unavailable", "k. A."),
+    ("messages.config_invalid_moved", "Einstellungen konnten nicht gelesen werden und wurden zurückgesetzt; die alte Datei liegt unter {}"),
+    ("messages.config_invalid", "Einstellungen konnten nicht gelesen werden und wurden zurückgesetzt"),    ("messages.monitor_only", "Nur Überwachung: Änderungen sind deaktiviert"),
+    ("messages.no_device", "Kein Gerät verbunden"),
+    ("messages.device_disconnected", "Gerät getrennt"),
+    ("messages.device_reconnected", "Gerät wieder verbunden"),
+    ("messages.initializing", "Initialisierung..."),
+    ("messages.initialized", "Initialisierung abgeschlossen"),
+    ("messages.specs_loaded", "Systeminformationen geladen"),
+    ("messages.read_status_failed", "Gerätestatus konnte nicht gelesen werden: {}"),
+    ("messages.failed_with", "{}: {}"),
+    ("messages.mode_updated", "Modus aktualisiert"),
+    ("messages.updated_externally", "Gerätezustand wurde extern geändert"),
+    ("messages.interference", "Eine andere App scheint dieses Gerät zu steuern"),
+    ("messages.undid", "Letzte Änderung rückgängig gemacht"),
+    ("messages.undid_settings", "Änderung rückgängig gemacht: {}"),
+    ("messages.undo_failed", "Rückgängig machen fehlgeschlagen"),
+    ("messages.save_failed", "Einstellungen konnten nicht gespeichert werden: {}"),
+    ("messages.window_reset", "Fensterposition zurückgesetzt"),
+    ("messages.refreshed", "Gerätezustand aktualisiert"),
+    ("messages.refresh_failed", "Gerätezustand konnte nicht aktualisiert werden: {}"),
+    ("messages.diagnostics_copied", "Diagnose in die Zwischenablage kopiert"),
+    ("messages.diagnostics_failed", "Diagnose konnte nicht erstellt werden: {}"),
+    ("messages.autostart_enabled", "Mit Windows starten aktiviert"),
+    ("messages.autostart_disabled", "Mit Windows starten deaktiviert"),
+    ("messages.autostart_failed", "Autostart konnte nicht geändert werden: {}"),
+    ("messages.performance_mode", "Leistungsmodus: {}"),
+    ("messages.pipe_open", "Telemetrie-Pipe geöffnet unter {}"),
+    ("messages.pipe_failed", "Telemetrie-Pipe: {}"),
+    ("messages.api_listening", "Automatisierungs-API lauscht auf 127.0.0.1:{}"),
+    ("messages.api_failed", "Automatisierungs-API: {}"),
+    ("messages.api_unknown_mode", "Automatisierung: unbekannter Leistungsmodus '{}'"),
+    ("messages.api_unknown_profile", "Automatisierung: kein Profil namens '{}'"),
+    ("messages.profile_read_failed", "Gerätezustand für das Profil konnte nicht gelesen werden"),
+    ("messages.copied_to_power_profile", "Aktuelle Einstellungen in das Profil {} kopiert"),
+    ("messages.auto_switched", "⚡ Automatisch zum Profil {} gewechselt"),
+    ("messages.auto_switch_failed", "Wechsel zum Profil {} fehlgeschlagen"),
+    ("messages.switched_notification", "Zum Profil {} gewechselt"),
+    ("messages.applying_profile", "Profil wird angewendet…"),
+    ("messages.profile_saved", "Profil '{}' gespeichert"),
+    ("messages.profile_missing", "Profil '{}' existiert nicht mehr"),
+    ("messages.home_reset", "Auf Ausgangszustand zurückgesetzt"),
+    ("messages.home_reset_failed", "Zurücksetzen auf den Ausgangszustand fehlgeschlagen"),
+    ("messages.home_set", "Ausgangszustand auf die aktuellen Einstellungen gesetzt"),
+    ("messages.unknown_mode", "Unbekannter Leistungsmodus '{}'"),
+    ("messages.set_mode_failed", "Leistungsmodus konnte nicht gesetzt werden: {}"),
+    ("messages.mode_restore_failed", "{} konnte nach dem Wechsel des Leistungsmodus nicht wiederhergestellt werden"),
+    ("messages.mode_changed", "Modus geändert"),
+    ("messages.cpu_boost_not_allowed", "CPU {} wird zusammen mit GPU {} nicht unterstützt"),
+    ("messages.gpu_boost_not_allowed", "GPU {} wird zusammen mit CPU {} nicht unterstützt"),
+    ("messages.cpu_boost_failed", "CPU-Boost fehlgeschlagen"),
+    ("messages.gpu_boost_failed", "GPU-Boost fehlgeschlagen"),
+    ("messages.cpu_boost_set", "CPU {}"),
+    ("messages.gpu_boost_set", "GPU {}"),
+    ("messages.set_fan_failed", "Lüfter konnte nicht gesetzt werden: {}"),
+    ("messages.fan_auto", "Lüfter auf Automatik gestellt"),
+    ("messages.fan_manual", "Lüfter auf manuell gestellt"),
+    ("messages.set_rpm_failed", "Lüfterdrehzahl konnte nicht gesetzt werden"),
+    ("messages.rpm_set", "Lüfterdrehzahl gesetzt auf: {}"),
+    ("messages.zone_rpm_set", "Drehzahl von Lüfter {} gesetzt auf: {}"),
+    ("messages.kept_manual_mode", "Aktueller Modus bei {} beibehalten: er wurde gerade manuell geändert"),
+    ("messages.power_plan_applied", "🖥 Energiesparplan {}: '{}' angewendet"),
+    ("messages.power_plan_missing", "Profil '{}' für den Energiesparplan {} existiert nicht mehr"),
+    ("messages.fan", "Lüfter"),
+    ("messages.fan2", "Lüfter 2"),
+    ("messages.fan_stopped", "{} dreht sich nicht (eingestellt auf {} RPM)"),
+    ("messages.fan_below_target", "{} unter dem Ziel: {} von {} RPM"),
+    ("messages.fan_auto_externally", "Lüfter wurden extern auf Automatik gestellt; manuelle Drehzahl freigegeben"),
+    ("messages.max_fan_enabled", "Maximale Lüfterdrehzahl aktiviert"),
+    ("messages.max_fan_disabled", "Maximale Lüfterdrehzahl deaktiviert"),
+    ("messages.max_fan_enable_failed", "Maximale Lüfterdrehzahl konnte nicht aktiviert werden: {}"),
+    ("messages.max_fan_disable_failed", "Maximale Lüfterdrehzahl konnte nicht deaktiviert werden: {}"),
+    ("messages.logo_failed", "Logo-Modus konnte nicht gesetzt werden"),
+    ("messages.logo_set", "Logo-Modus gesetzt auf {}"),
+    ("messages.color_failed", "Tastaturfarbe konnte nicht gesetzt werden"),
+    ("messages.color_set", "Tastaturfarbe gesetzt auf {}"),
+    ("messages.backlight_failed", "Tastaturbeleuchtung konnte nicht gesetzt werden"),
+    ("messages.backlight_on", "Tastaturbeleuchtung an"),
+    ("messages.backlight_off", "Tastaturbeleuchtung aus"),
+    ("messages.brightness_failed", "Helligkeit konnte nicht gesetzt werden"),
+    ("messages.brightness_set", "Helligkeit gesetzt auf {} %"),
+    ("messages.always_on_enabled", "Tastaturbeleuchtung immer an aktiviert"),
+    ("messages.always_on_disabled", "Tastaturbeleuchtung immer an deaktiviert"),
+    ("messages.always_on_failed", "Beleuchtung immer an konnte nicht gesetzt werden: {}"),
+    ("messages.battery_care_enabled", "Akkuschonung aktiviert"),
+    ("messages.battery_care_disabled", "Akkuschonung deaktiviert"),
+    ("messages.battery_care_failed", "Akkuschonung konnte nicht gesetzt werden: {}"),
+    ("messages.profile_applied", "Profil '{}' angewendet"),
+    ("messages.profile_apply_failed", "Profil '{}' konnte nicht angewendet werden"),
+    ("messages.low_battery_applied", "🪫 Akku bei {} %: Profil '{}' angewendet"),
+    ("messages.low_battery_missing", "Profil '{}' für niedrigen Akkustand existiert nicht mehr"),
+    ("messages.startup_monitor_only", "Startprofil '{}' im Nur-Überwachungsmodus nicht angewendet"),
+    ("messages.startup_applied", "🚀 Startprofil '{}' angewendet"),
+    ("messages.startup_missing", "Startprofil '{}' existiert nicht mehr"),
+    ("messages.exported", "{} Profil(e) nach {} exportiert"),
+    ("messages.export_failed", "Profile konnten nicht exportiert werden: {}"),
+    ("messages.import_failed", "Profile konnten nicht importiert werden: {}"),
+    ("messages.imported", "{} Profil(e) importiert"),
+    ("messages.imported_skipped", "{} Profil(e) importiert; {} von diesem Gerät nicht unterstützte übersprungen: {}"),
+    ("messages.relaunch_failed", "Neustart fehlgeschlagen: {}"),
+    ("messages.invalid_profile_name", "Ungültiger Profilname '{}'"),
+    ("messages.profile_deleted", "Profil '{}' gelöscht"),
+    ("messages.app_focused", "{} im Vordergrund: {}"),
+    ("messages.home_defaults", "Ausgangszustand auf Standard zurückgesetzt"),
+    ("confirm.home_summary", "{}, Lüfter {}, Logo {}, {} % Helligkeit, Akkuschonung {}"),
+    ("confirm.on", "an"),
+    ("confirm.off", "aus"),
+    ("messages.profile_file_type", "R-Helper-Profile"),
+    ("messages.export_title", "Profile exportieren"),
+    ("messages.import_title", "Profile importieren"),
+];
@@ -0,0 +1,67 @@
+//! Device logic behind R-Helper, usable without the GUI.
+//!
+//! Covers detecting the laptop, reading its full state, applying a saved profile and the
+//! individual performance, fan and lighting writes. Power source and battery queries live in
+//! `power`. The `rhelper` binary is an egui shell over this crate; build with
+//! `default-features = false` to depend on it without egui.
+
+pub mod device;
+pub mod power;
+pub mod utils;
+
+pub use device::{
+    CompleteDeviceState, DevicePoller, DeviceSnapshot, FanCurve, MAX_MANUAL_RPM, MIN_MANUAL_RPM,
+};
+pub use librazer;
+pub use librazer::device::Device;
+
+use anyhow::{Context, Result};
+use librazer::command;
+use librazer::types::{FanMode, LogoMode, PerfMode};
+
+/// Find the supported Razer laptop this runs on
+pub fn detect() -> Result<Device> {
+    Device::detect().context("No supported Razer device found")
+}
+
+/// Read every setting the app manages from the device
+pub fn read_state(device: &Device) -> Result<CompleteDeviceState> {
+    CompleteDeviceState::read_from_device(device)
+}
+
+/// Write a complete profile (performance mode first, then fans, lighting and battery care);
+/// settings the device rejects are listed in the error while the rest still apply
+pub fn apply_profile(device: &Device, profile: &CompleteDeviceState) -> Result<()> {
+    profile.apply_to_device(device)
+}
+
+/// Switch the performance mode; the firmware puts the fans back to Auto
+pub fn set_perf_mode(device: &Device, mode: PerfMode) -> Result<()> {
+    command::set_perf_mode(device, mode)
+}
+
+/// Hand the fans back to the firmware
+pub fn set_fan_auto(device: &Device) -> Result<()> {
+    command::set_fan_mode(device, FanMode::Auto)
+}
+
+/// Pin both fans to `rpm`, which must lie in the model's manual range (its descriptor's
+/// `fan_rpm_range`, else `MIN_MANUAL_RPM..=MAX_MANUAL_RPM`)
+pub fn set_fan_rpm(device: &Device, rpm: u16) -> Result<()> {
+    let range = device::manual_rpm_range(device.info());
+    if !range.contains(&rpm) {
+        anyhow::bail!("RPM must be between {} and {}", range.start(), range.end());
+    }
+    command::set_fan_mode(device, FanMode::Manual)?;
+    command::set_fan_rpm(device, rpm, true)
+}
+
+/// Set the keyboard backlight brightness (0-255)
+pub fn set_keyboard_brightness(device: &Device, brightness: u8) -> Result<()> {
+    command::set_keyboard_brightness(device, brightness)
+}
+
+/// Set the lid logo mode
+pub fn set_logo_mode(device: &Device, mode: LogoMode) -> Result<()> {
+    command::set_logo_mode(device, mode)
+}
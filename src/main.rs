@@ -1,34 +1,110 @@
 #![windows_subsystem = "windows"]
 
-mod device;
+mod api;
+mod cli;
+mod config;
+mod diagnostics;
+mod hotkey;
+mod i18n;
 mod messaging;
-mod power;
+mod notify;
+mod pipe;
 mod system;
+mod tray;
 mod ui;
-mod utils;
+mod update;
 
 use eframe::egui;
 use egui::IconData;
+use r_helper::{device, power, utils};
 
 use anyhow::Result;
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
 use std::sync::mpsc;
 
 use librazer::types::{
-    BatteryCare, CpuBoost, FanMode, GpuBoost, LightsAlwaysOn, LogoMode, MaxFanSpeedMode, PerfMode,
+    BatteryCare, CpuBoost, FanMode, FanZone, GpuBoost, LightsAlwaysOn, LogoMode, MaxFanSpeedMode,
+    PerfMode,
 };
-use librazer::{command, device::Device};
+use librazer::{command, descriptor::Descriptor, device::Device};
 use strum::IntoEnumIterator;
 
-use device::CompleteDeviceState;
+use device::{CompleteDeviceState, FanCurve};
+use i18n::t;
 use messaging::{error_message, status_message, MessageManager};
-use power::get_power_state;
-use system::{get_system_specs, SystemSpecs};
-use utils::{execute_device_command_simple, DeviceStateReader};
+use power::{get_battery_status, get_power_state, BatteryStatus};
+use system::autostart::{self, AutostartState};
+use system::{
+    get_system_specs, load_cached_specs, update_specs_cache, SystemSpecs, TempMonitor,
+    Temperatures, Theme,
+};
+use utils::{with_default_retry, DeviceStateReader};
 
 // Dynamic app metadata from Cargo
 const APP_NAME: &str = "R-Helper";
+#[cfg_attr(not(windows), allow(dead_code))]
 const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Delay before the first re-detection attempt after losing the device; doubles up to the max
+const MIN_RECONNECT_BACKOFF: std::time::Duration = std::time::Duration::from_secs(2);
+const MAX_RECONNECT_BACKOFF: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Upper bound on retained fan RPM samples (60s at the 500ms refresh tick)
+const FAN_RPM_HISTORY_CAPACITY: usize = 120;
+
+/// Default inner window size (also what "Reset window" restores)
+const DEFAULT_WINDOW_SIZE: [f32; 2] = [450.0, 500.0];
+const MIN_WINDOW_SIZE: [f32; 2] = [380.0, 300.0];
+/// Inner size of the single-row compact view
+const COMPACT_WINDOW_SIZE: [f32; 2] = [300.0, 40.0];
+
+/// How often the full device state is compared to catch changes made by other apps
+const EXTERNAL_CHANGE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// More external state changes than this within the window means another app is in control
+const EXTERNAL_CHANGE_THRESHOLD: usize = 3;
+const EXTERNAL_CHANGE_WINDOW: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// How often the focused application is checked against the app rules
+const APP_RULE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+/// App rules leave the mode alone this long after the user picks one
+const APP_RULE_MANUAL_GRACE: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// A new power source must hold this long before its profile is written (charger flaps, docking)
+const POWER_SWITCH_SETTLE: std::time::Duration = std::time::Duration::from_secs(2);
+/// The AC/Battery switch leaves the mode alone this long after the user picks one
+const POWER_SWITCH_MANUAL_GRACE: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How often a sample is written to the telemetry pipe
+const TELEMETRY_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Brightness read back this soon after a write may predate it, so it is not shown
+const BRIGHTNESS_READBACK_SUPPRESS: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// Minimum time between manual refreshes from the header button
+const MANUAL_REFRESH_COOLDOWN: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Number of earlier device states kept for undo
+const UNDO_HISTORY_LIMIT: usize = 5;
+
+/// Quiet period after the last fan slider change before the value is written
+const FAN_RPM_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(150);
+/// Length of a brightness fade and the number of writes it is split into (the last one lands
+/// exactly on the target)
+const BRIGHTNESS_FADE_DURATION: std::time::Duration = std::time::Duration::from_millis(200);
+const BRIGHTNESS_FADE_STEPS: u32 = 5;
+/// Repaint cadence while the window is shown: the 500ms poll, on top of input and animations
+const VISIBLE_REPAINT_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+/// Faster cadence while minimized or hidden to the tray, where no input wakes the loop and
+/// background enforcement and switching must keep running
+const BACKGROUND_REPAINT_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+/// Full device reads slower than this are logged; USB round-trips are normally a few ms
+const SLOW_READ_THRESHOLD: std::time::Duration = std::time::Duration::from_millis(100);
+/// A manual fan reading below this share of its set RPM for `STUCK_FAN_WINDOW` is reported
+const STUCK_FAN_MIN_RATIO: f32 = 0.5;
+const STUCK_FAN_WINDOW: std::time::Duration = std::time::Duration::from_secs(8);
+
 #[derive(Debug, Clone)]
 enum InitMessage {
     SystemSpecsComplete(SystemSpecs),
@@ -37,16 +113,283 @@ enum InitMessage {
     DeviceDetectionComplete(bool),
 }
 
+/// Fan state from before Max fan was turned on, restored when it is turned off
+#[derive(Debug, Clone, Copy)]
+struct MaxFanRestore {
+    /// Max uses the firmware's max fan speed mode (Custom only) instead of a manual RPM
+    firmware_max: bool,
+    fan_mode: FanMode,
+    rpm: u16,
+    rpm2: Option<u16>,
+}
+
+/// Custom mode boost levels and CPU+GPU combinations the connected model accepts
+#[derive(Debug, Clone, PartialEq)]
+struct BoostConstraints {
+    cpu: Vec<CpuBoost>,
+    gpu: Vec<GpuBoost>,
+    disallowed_pairs: Vec<(CpuBoost, GpuBoost)>,
+    /// The descriptor lists the model's disallowed combinations. Otherwise every pair is
+    /// offered, though the firmware may still reject some.
+    pairs_confirmed: bool,
+}
+
+impl BoostConstraints {
+    /// Take the levels and combinations from the descriptor, allowing all where it is silent
+    fn from_descriptor(descriptor: Option<&Descriptor>) -> Self {
+        let cpu = descriptor.and_then(|d| d.cpu_boosts).map(|s| s.to_vec()).unwrap_or_else(|| {
+            vec![CpuBoost::Low, CpuBoost::Medium, CpuBoost::High, CpuBoost::Boost]
+        });
+        let gpu = descriptor
+            .and_then(|d| d.gpu_boosts)
+            .map(|s| s.to_vec())
+            .unwrap_or_else(|| vec![GpuBoost::Low, GpuBoost::Medium, GpuBoost::High]);
+        let pairs = descriptor.and_then(|d| d.disallowed_boost_pairs);
+        Self {
+            cpu,
+            gpu,
+            disallowed_pairs: pairs.map(|p| p.to_vec()).unwrap_or_default(),
+            pairs_confirmed: pairs.is_some(),
+        }
+    }
+}
+
+/// Keyboard brightness ramp in progress, see `Settings::brightness_fade`
+#[derive(Debug, Clone, Copy)]
+struct BrightnessFade {
+    from: u8,
+    to: u8,
+    started: std::time::Instant,
+    /// Intermediate writes issued so far
+    steps_done: u32,
+}
+
+/// Profile write handed to a worker thread
+#[derive(Debug, Clone)]
+struct ProfileJob {
+    profile: CompleteDeviceState,
+    /// AC/Battery auto-switch: which settings to take from the profile (`None` writes all)
+    scope: Option<config::AutoSwitchScope>,
+    success_message: String,
+    error_prefix: String,
+    /// Desktop notification title shown on success
+    notification: Option<String>,
+    /// Reverting to an undo snapshot; the state left behind is not recorded again
+    undo: bool,
+    /// Lights off on battery (or their restore on AC), written over the profile
+    lights: Option<config::LightsState>,
+    /// `lights` are the remembered pre-battery lights; they are forgotten once written
+    restores_lights: bool,
+    /// Shown as the active profile once written; `None` leaves the state unsaved
+    profile_name: Option<String>,
+}
+
+impl ProfileJob {
+    fn run(&self, device: &Device) -> Result<()> {
+        // Unselected settings (including a manual fan RPM) keep their current values;
+        // fall back to writing the whole profile when the state can't be read
+        let mut target = match (self.scope, CompleteDeviceState::read_from_device(device)) {
+            (Some(scope), Ok(current)) => scope.merge(&current, &self.profile),
+            _ => self.profile.clone(),
+        };
+        if let Some(lights) = self.lights {
+            lights.apply_to(&mut target);
+        }
+        target.apply_to_device(device)
+    }
+}
+
+/// A finished profile write and the device read taken right after it
+struct ProfileOutcome {
+    result: Result<()>,
+    readout: DeviceReadout,
+}
+
+/// Device job waiting for the worker; returns true once its result has been applied
+type PendingDeviceJob = Box<dyn FnMut(&mut RazerGuiApp) -> bool>;
+
+/// Queries a status read may make; devices that rejected them at startup are not asked again
+#[derive(Debug, Clone, Copy)]
+struct ReadPlan {
+    second_fan: bool,
+    keyboard_backlight: bool,
+}
+
+/// Status fields read on the worker. Only the performance mode query must succeed; the other
+/// fields keep their last shown value when their read fails.
+#[derive(Debug, Clone)]
+struct StatusRead {
+    perf_mode: PerfMode,
+    fan_mode: FanMode,
+    /// Set RPM, read in Manual only
+    fan_rpm: Option<u16>,
+    fan_actual_rpm: Option<u16>,
+    fan2_rpm: Option<u16>,
+    fan2_actual_rpm: Option<u16>,
+    logo_mode: Option<LogoMode>,
+    keyboard_brightness: Option<u8>,
+    keyboard_backlight: Option<bool>,
+    lights_always_on: Option<LightsAlwaysOn>,
+    battery_care: Option<BatteryCare>,
+}
+
+impl StatusRead {
+    fn read(device: &Device, plan: ReadPlan) -> Result<Self> {
+        // Core perf + fan query (single device round-trip).
+        let (perf_mode, fan_mode) = with_default_retry(|| command::get_perf_mode(device))?;
+        let manual = fan_mode == FanMode::Manual;
+        Ok(Self {
+            perf_mode,
+            fan_mode,
+            fan_rpm: manual.then(|| get_fan_rpm_set(device, FanZone::Zone1)).flatten(),
+            fan_actual_rpm: get_fan_rpm_actual(device, FanZone::Zone1),
+            fan2_rpm: (plan.second_fan && manual)
+                .then(|| get_fan_rpm_set(device, FanZone::Zone2))
+                .flatten(),
+            fan2_actual_rpm: plan
+                .second_fan
+                .then(|| get_fan_rpm_actual(device, FanZone::Zone2))
+                .flatten(),
+            logo_mode: with_default_retry(|| command::get_logo_mode(device)).ok(),
+            keyboard_brightness: with_default_retry(|| command::get_keyboard_brightness(device))
+                .ok(),
+            keyboard_backlight: plan
+                .keyboard_backlight
+                .then(|| command::get_keyboard_backlight(device).ok())
+                .flatten(),
+            lights_always_on: with_default_retry(|| command::get_lights_always_on(device)).ok(),
+            battery_care: with_default_retry(|| command::get_battery_care(device)).ok(),
+        })
+    }
+}
+
+/// Status and full state read together on the worker after connecting or writing,
+/// see `RazerGuiApp::apply_readout`
+struct DeviceReadout {
+    status: Result<StatusRead>,
+    state: Result<CompleteDeviceState>,
+    read_time: std::time::Duration,
+}
+
+impl DeviceReadout {
+    fn read(device: &Device, plan: ReadPlan) -> Self {
+        let started = std::time::Instant::now();
+        let status = StatusRead::read(device, plan);
+        let state = CompleteDeviceState::read_from_device(device);
+        Self { status, state, read_time: started.elapsed() }
+    }
+}
+
+/// Everything learned right after the device is opened, see `RazerGuiApp::apply_initial_read`
+struct InitialRead {
+    descriptor: Descriptor,
+    has_second_fan: bool,
+    /// `None` when the device rejects logo control
+    logo_mode: Option<LogoMode>,
+    keyboard_brightness: Option<u8>,
+    perf_mode: Option<PerfMode>,
+    fan_mode: Option<FanMode>,
+    /// Set RPM, read in Manual only
+    fan_rpm: Option<u16>,
+    /// Read in Custom only
+    cpu_boost: Option<CpuBoost>,
+    gpu_boost: Option<GpuBoost>,
+    firmware_version: Option<String>,
+    keyboard_color: Option<[u8; 3]>,
+    keyboard_backlight: Option<bool>,
+    lights_always_on: Option<LightsAlwaysOn>,
+    /// `None` when the device rejects battery care
+    battery_care: Option<BatteryCare>,
+}
+
+impl InitialRead {
+    fn read(device: &Device) -> Self {
+        // Use batched reader helper to gather as much as possible without early abort.
+        let mut reader = DeviceStateReader::new(device);
+
+        let perf = reader.read(command::get_perf_mode, "performance mode");
+        // Fallback: if the retried combined call failed, a later one may still fill fan info.
+        let fan_mode = perf
+            .map(|(_, fan_mode)| fan_mode)
+            .or_else(|| command::get_perf_mode(device).ok().map(|(_, fan_mode)| fan_mode));
+        let perf_mode = perf.map(|(perf_mode, _)| perf_mode);
+        let custom = perf_mode == Some(PerfMode::Custom);
+
+        let read = Self {
+            descriptor: device.info().clone(),
+            // Single-fan devices reject Zone2 queries; hide the second readout for them.
+            has_second_fan: command::get_fan_actual_rpm(device, FanZone::Zone2).is_ok(),
+            logo_mode: with_default_retry(|| command::get_logo_mode(device)).ok(),
+            keyboard_brightness: reader
+                .read(command::get_keyboard_brightness, "keyboard brightness"),
+            perf_mode,
+            fan_mode,
+            fan_rpm: (fan_mode == Some(FanMode::Manual))
+                .then(|| get_fan_rpm_set(device, FanZone::Zone1))
+                .flatten(),
+            cpu_boost: custom.then(|| command::get_cpu_boost(device).ok()).flatten(),
+            gpu_boost: custom.then(|| command::get_gpu_boost(device).ok()).flatten(),
+            firmware_version: command::get_firmware_version(device)
+                .ok()
+                .map(|(major, minor)| format!("v{}.{}", major, minor)),
+            // Devices without color control reject the query; the picker stays hidden for them.
+            keyboard_color: command::get_keyboard_color(device).ok(),
+            keyboard_backlight: command::get_keyboard_backlight(device).ok(),
+            lights_always_on: reader.read(command::get_lights_always_on, "lights always on"),
+            battery_care: reader.read(command::get_battery_care, "battery care"),
+        };
+
+        let errors = reader.finish();
+        if !errors.is_empty() && cfg!(debug_assertions) {
+            eprintln!("Device state reading errors: {:?}", errors);
+        }
+        for error in &errors {
+            utils::log_debug(error);
+        }
+        read
+    }
+}
+
+/// Result of `set_performance_mode`'s worker job
+struct PerfModeChange {
+    /// Manual RPM of each zone put back after the mode change reset the fans
+    restored_rpm: Option<(u16, Option<u16>)>,
+    /// Boosts or fan settings the mode change reset that could not be put back,
+    /// as `setting (error)`; the mode itself was set
+    restore_errors: Vec<String>,
+    /// Boost levels read back after entering Custom
+    cpu_boost: Option<CpuBoost>,
+    gpu_boost: Option<GpuBoost>,
+}
+
+/// What `enforce_manual_fan_rpm`'s worker job found and wrote
+enum FanEnforcement {
+    /// The fans are back on Auto; see `note_external_fan_auto`
+    ExternalAuto,
+    /// The fan curve's RPM was written to both zones
+    Curve(u16),
+    /// Each zone's set RPM was written back
+    Reapplied(u16, Option<u16>),
+}
+
 #[derive(Debug, Clone)]
 struct DeviceStatus {
     performance_mode: String,
     fan_speed: String,
     fan_rpm: Option<u16>,
     fan_actual_rpm: Option<u16>,
+    fan2_rpm: Option<u16>,
+    fan2_actual_rpm: Option<u16>,
     logo_mode: String,
     keyboard_brightness: u8,
+    /// `None` when the keyboard does not report a color
+    keyboard_color: Option<[u8; 3]>,
+    /// `None` when the backlight has no on/off state separate from brightness
+    keyboard_backlight: Option<bool>,
     lights_always_on: bool,
     battery_care: bool,
+    /// e.g. "v1.2"; `None` when the device does not report it
+    firmware_version: Option<String>,
 }
 
 impl Default for DeviceStatus {
@@ -56,52 +399,171 @@ impl Default for DeviceStatus {
             fan_speed: "Reading...".to_string(),
             fan_rpm: None,
             fan_actual_rpm: None,
+            fan2_rpm: None,
+            fan2_actual_rpm: None,
             logo_mode: "Reading...".to_string(),
             keyboard_brightness: 0,
+            keyboard_color: None,
+            keyboard_backlight: None,
             lights_always_on: false,
             battery_care: true,
+            firmware_version: None,
         }
     }
 }
 
 struct RazerGuiApp {
     status: DeviceStatus,
-    device: Option<Device>,
+    /// Connected model; all device I/O goes through `device_poller`
+    device: Option<Descriptor>,
     device_state: Option<CompleteDeviceState>,
+    /// Named profile last applied or saved and the device state that matched it; the profile has
+    /// unsaved edits while the device state differs
+    active_profile: Option<(String, CompleteDeviceState)>,
+    /// Earlier device states, most recent last
+    undo_history: VecDeque<CompleteDeviceState>,
     system_specs: SystemSpecs,
     available_performance_modes: Vec<PerfMode>,
     base_performance_modes: Vec<PerfMode>,
+    /// Modes the device reported that its descriptor does not list
+    reported_performance_modes: Vec<PerfMode>,
 
     ac_power: bool,
+    battery_status: Option<BatteryStatus>,
+    low_battery_applied: bool,
+    /// The startup profile was applied (or skipped) for this launch
+    startup_profile_done: bool,
     ac_profile: CompleteDeviceState,
+    /// AC (`true`) or Battery profile changed from the other source and not yet applied
+    edited_power_profile: Option<bool>,
     battery_profile: CompleteDeviceState,
+    profiles: Vec<(String, CompleteDeviceState)>,
+    profile_name_input: String,
+    profile_rename: Option<(usize, String)>,
+    /// Profile write in progress on a worker thread
+    profile_apply: Option<(ProfileJob, mpsc::Receiver<Result<ProfileOutcome>>)>,
+    /// Latest profile requested while another was being written
+    queued_profile_job: Option<ProfileJob>,
+    settings: config::Settings,
+    app_rule_input: String,
+    /// Last focused executable other than R-Helper itself
+    last_foreground_exe: Option<String>,
+    last_app_rule_check: std::time::Instant,
+    last_manual_perf_change: Option<std::time::Instant>,
+    /// Power source the device profile still reflects, and when the source first changed
+    pending_power_switch: Option<(bool, std::time::Instant)>,
+    /// Recent externally made device changes, for interference detection
+    external_changes: VecDeque<std::time::Instant>,
+    interference_warned: bool,
+    /// Started with `--monitor`; see `monitor_only`
+    monitor_only_launch: bool,
+    /// Mode awaiting confirmation because the fans are in manual mode
+    pending_perf_change: Option<String>,
+    /// "Reset to home" is waiting for confirmation
+    pending_home_reset: bool,
+    /// Named profile to apply once the user decides about unsaved edits to the active one
+    pending_profile_switch: Option<usize>,
+    /// Warn once per launch that some commands may need administrator rights
+    show_elevation_banner: bool,
+    tray: Option<tray::AppTray>,
+    hotkey: Option<hotkey::AppHotkey>,
+    power_watcher: Option<power::PowerWatcher>,
+    /// Last Windows power plan reported by the watcher
+    power_plan: Option<power::PowerPlan>,
+    autostart: AutostartState,
+    /// Pending GitHub release lookup; started at most once per launch
+    update_check: Option<mpsc::Receiver<Option<update::Release>>>,
+    update_check_started: bool,
+    available_update: Option<update::Release>,
+    /// Local automation endpoint while enabled in the settings
+    api_server: Option<api::ApiServer>,
+    /// Port that failed to bind, so it is not retried every frame
+    api_failed_port: Option<u16>,
+    telemetry_pipe: Option<pipe::TelemetryPipe>,
+    /// Creating the pipe failed; not retried until the setting is toggled
+    telemetry_pipe_failed: bool,
+    last_telemetry_sent: std::time::Instant,
 
     loading: bool,
     fully_initialized: bool,
+    /// The window opens topmost so it surfaces on launch; dropped once it has rendered
+    topmost_pending: bool,
     init_receiver: Option<mpsc::Receiver<InitMessage>>,
     message_manager: MessageManager,
     last_refresh_time: std::time::Instant,
-    last_state_check_time: std::time::Instant,
+    /// Worker that owns the device: it polls in the background and runs every read and write
+    device_poller: Option<device::DevicePoller>,
+    /// Jobs queued on the worker whose results have not been applied yet, oldest first
+    device_jobs: Vec<PendingDeviceJob>,
+    /// Start of the last finished device job; worker snapshots taken before it are stale
+    last_device_read: std::time::Instant,
     last_fan_enforce_time: std::time::Instant,
-    status_messages: bool,
+    last_manual_refresh: Option<std::time::Instant>,
 
     manual_fan_rpm: u16,
+    manual_fan2_rpm: u16,
+    /// Manual RPM range the device accepts; app defaults unless its descriptor says otherwise
+    fan_rpm_range: RangeInclusive<u16>,
+    /// The range comes from the model's descriptor rather than the app default
+    fan_rpm_range_listed: bool,
+    /// RPM span of the fan readout colors, from the descriptor when it reports a range
+    fan_color_range: RangeInclusive<u16>,
+    /// Slider value waiting for input to settle: (zone, rpm, last change)
+    pending_fan_rpm: Option<(FanZone, u16, std::time::Instant)>,
+    /// `Some` while Max fan is on
+    max_fan_restore: Option<MaxFanRestore>,
+    has_second_fan: bool,
+    /// When a manual fan first read far below its target, see `check_stuck_fan`
+    fan_below_target_since: Option<std::time::Instant>,
+    /// The stuck fan warning was shown; cleared once the fans reach their target again
+    stuck_fan_reported: bool,
+    /// Boost levels and combinations from the connected model's descriptor
+    boost_constraints: BoostConstraints,
+    /// Devices without battery care or logo control reject the query at startup; their
+    /// controls are disabled instead of reverting on every click
+    battery_care_supported: bool,
+    logo_supported: bool,
+    fan_rpm_history: VecDeque<(std::time::Instant, u16)>,
+    fan_curve_draft: Vec<(u8, u16)>,
+    temp_monitor: TempMonitor,
+    temperatures: Temperatures,
     temp_brightness_step: usize,
+    /// Step the brightness quick toggle restores after turning the keyboard dark
+    brightness_restore_step: Option<usize>,
     brightness_slider_active: bool,
+    /// When brightness was last written; see `BRIGHTNESS_READBACK_SUPPRESS`
+    last_brightness_write: Option<std::time::Instant>,
+    brightness_fade: Option<BrightnessFade>,
+    /// Duration of the last full device read, shown in the footer in Debug mode
+    last_read_latency: Option<std::time::Duration>,
+    /// Full-view inner size to restore when leaving compact mode
+    full_window_size: Option<egui::Vec2>,
+    /// Focus state last frame, to re-check the system theme when the window is activated
+    window_focused: bool,
+    /// Hidden to the tray by closing the window
+    window_hidden: bool,
     should_quit: bool,
 
     init_power_read: bool,
     init_specs_complete: bool,
-    last_perf_poll_time: std::time::Instant,
     cpu_boost: CpuBoost,
     gpu_boost: GpuBoost,
-    base_window_height: f32,
-    expanded_window_height: Option<f32>,
     custom_controls_visible_last: bool,
+    /// UI scale currently in effect; differs from the setting until the next frame applies it
+    applied_ui_scale: Option<f32>,
+    /// Window size in points to restore once a scale change has taken effect
+    scale_resize: Option<egui::Vec2>,
     // Device detection state
     detecting_device: bool,
     device_detection_done: bool,
     min_detecting_until: std::time::Instant,
+    /// Frame in which the manual retry was clicked; detection waits until a later frame
+    /// has shown "Detecting device…"
+    detect_requested: Option<u64>,
+    last_reconnect_attempt: std::time::Instant,
+    reconnect_backoff: std::time::Duration,
+    /// The worker is opening the device and reading its initial state
+    connecting: bool,
 }
 
 impl RazerGuiApp {
@@ -110,7 +572,8 @@ impl RazerGuiApp {
     }
 
     fn string_to_perf_mode(mode: &str) -> Option<PerfMode> {
-        PerfMode::iter().find(|m| format!("{:?}", m) == mode)
+        let mode = mode.trim();
+        PerfMode::iter().find(|m| Self::perf_mode_to_string(*m).eq_ignore_ascii_case(mode))
     }
 
     fn logo_mode_to_string(mode: LogoMode) -> &'static str {
@@ -130,36 +593,62 @@ impl RazerGuiApp {
         }
     }
 
-    fn read_current_fan_state(device: &Device) -> (FanMode, Option<u16>) {
+    fn fan_mode_to_string(mode: FanMode) -> &'static str {
+        match mode {
+            FanMode::Auto => "Auto",
+            FanMode::Manual => "Manual",
+        }
+    }
+
+    /// Fan mode and the set RPM of each zone; zone 2 only when `second_fan`
+    fn read_current_fan_state(
+        device: &Device,
+        second_fan: bool,
+    ) -> (FanMode, Option<u16>, Option<u16>) {
         // Read the current fan mode from the combined perf/fan query.
-        // (We intentionally avoid a second immediate retry; caller logic tolerates fallback to Auto.)
-        let fan_mode = command::get_perf_mode(device).map(|(_, fm)| fm).unwrap_or_else(|_| {
-            eprintln!("Warning: Failed to read device fan mode, assuming Auto");
-            FanMode::Auto
-        });
-        let set_rpm = get_fan_rpm_set(device, librazer::types::FanZone::Zone1);
-        (fan_mode, set_rpm)
+        let fan_mode = with_default_retry(|| command::get_perf_mode(device))
+            .map(|(_, fm)| fm)
+            .unwrap_or_else(|_| {
+                eprintln!("Warning: Failed to read device fan mode, assuming Auto");
+                FanMode::Auto
+            });
+        let set_rpm = get_fan_rpm_set(device, FanZone::Zone1);
+        let set_rpm2 = if second_fan { get_fan_rpm_set(device, FanZone::Zone2) } else { None };
+        (fan_mode, set_rpm, set_rpm2)
     }
 
-    fn get_fan_status_from_mode(fan_mode: FanMode, device: &Device) -> (String, Option<u16>) {
-        match fan_mode {
-            FanMode::Auto => ("Auto".to_string(), None),
-            FanMode::Manual => {
-                let set_rpm = get_fan_rpm_set(device, librazer::types::FanZone::Zone1);
-                ("Manual".to_string(), set_rpm)
-            }
+    /// Read-only telemetry mode (`--monitor` or the setting): nothing is written to the device
+    fn monitor_only(&self) -> bool {
+        self.monitor_only_launch || self.settings.monitor_only
+    }
+
+    /// Whether the app may write on its own (fan enforcement, automatic profile switching)
+    fn automatic_writes_allowed(&self) -> bool {
+        !self.settings.manual_control_only && !self.monitor_only()
+    }
+
+    /// Refuse a device write in monitor mode, telling the user why
+    fn blocked_by_monitor_mode(&mut self) -> bool {
+        if self.monitor_only() {
+            self.set_status_message(t!("messages.monitor_only").to_string());
         }
+        self.monitor_only()
     }
 
     fn set_no_device_message(&mut self) {
-        self.set_status_message("No device connected".to_string());
+        self.set_status_message(t!("messages.no_device").to_string());
     }
 
-    fn new() -> Self {
-        // Profiles kept in-memory so we can auto-switch on AC/Battery changes.
-        let ac_profile = CompleteDeviceState::default();
-        let battery_profile =
-            CompleteDeviceState { perf_mode: PerfMode::Battery, ..CompleteDeviceState::default() };
+    fn new(config: config::AppConfig) -> Self {
+        // Profiles are restored from disk so we can auto-switch on AC/Battery changes.
+        let config::AppConfig {
+            settings,
+            profiles:
+                config::StoredProfiles { ac: ac_profile, battery: battery_profile, named: profiles },
+            load_error,
+            ..
+        } = config;
+        let fan_curve_draft = settings.fan_curve.clone().unwrap_or_default().points().to_vec();
 
         let (init_sender, init_receiver) = mpsc::channel();
 
@@ -168,40 +657,119 @@ impl RazerGuiApp {
             status: DeviceStatus::default(),
             device: None,
             device_state: None,
+            active_profile: None,
+            undo_history: VecDeque::new(),
             system_specs: SystemSpecs::default(),
             available_performance_modes: Vec::new(),
             base_performance_modes: Vec::new(),
+            reported_performance_modes: Vec::new(),
             ac_power: true,
+            battery_status: get_battery_status().ok(),
+            low_battery_applied: false,
+            startup_profile_done: false,
             ac_profile,
+            edited_power_profile: None,
             battery_profile,
+            profiles,
+            profile_name_input: String::new(),
+            profile_rename: None,
+            profile_apply: None,
+            queued_profile_job: None,
+            settings,
+            app_rule_input: String::new(),
+            last_foreground_exe: None,
+            last_app_rule_check: now,
+            last_manual_perf_change: None,
+            pending_power_switch: None,
+            external_changes: VecDeque::new(),
+            interference_warned: false,
+            monitor_only_launch: false,
+            pending_perf_change: None,
+            pending_home_reset: false,
+            pending_profile_switch: None,
+            show_elevation_banner: false,
+            tray: None,
+            hotkey: None,
+            power_watcher: None,
+            power_plan: None,
+            autostart: autostart::get_autostart_state(),
+            update_check: None,
+            update_check_started: false,
+            available_update: None,
+            api_server: None,
+            api_failed_port: None,
+            telemetry_pipe: None,
+            telemetry_pipe_failed: false,
+            last_telemetry_sent: now,
             loading: true,
             fully_initialized: false,
+            topmost_pending: true,
             init_receiver: Some(init_receiver),
             message_manager: MessageManager::new(),
             last_refresh_time: std::time::Instant::now(),
-            last_state_check_time: std::time::Instant::now(),
+            device_poller: None,
+            device_jobs: Vec::new(),
+            last_device_read: std::time::Instant::now(),
             last_fan_enforce_time: std::time::Instant::now(),
-            status_messages: false,
-
-            manual_fan_rpm: 2000,
+            last_manual_refresh: None,
+
+            // Replaced by the device's minimum once its range is known
+            manual_fan_rpm: device::MIN_MANUAL_RPM,
+            manual_fan2_rpm: device::MIN_MANUAL_RPM,
+            fan_rpm_range: device::MIN_MANUAL_RPM..=device::MAX_MANUAL_RPM,
+            fan_rpm_range_listed: false,
+            fan_color_range: ui::fan::DEFAULT_RPM_COLOR_RANGE,
+            pending_fan_rpm: None,
+            max_fan_restore: None,
+            has_second_fan: false,
+            fan_below_target_since: None,
+            stuck_fan_reported: false,
+            boost_constraints: BoostConstraints::from_descriptor(None),
+            battery_care_supported: true,
+            logo_supported: true,
+            fan_rpm_history: VecDeque::with_capacity(FAN_RPM_HISTORY_CAPACITY),
+            fan_curve_draft,
+            temp_monitor: TempMonitor::start(),
+            temperatures: Temperatures::default(),
             temp_brightness_step: 0,
+            brightness_restore_step: None,
             brightness_slider_active: false,
+            last_brightness_write: None,
+            brightness_fade: None,
+            last_read_latency: None,
+            full_window_size: None,
+            window_focused: false,
+            window_hidden: false,
 
             should_quit: false,
 
             init_power_read: false,
             init_specs_complete: false,
-            last_perf_poll_time: std::time::Instant::now(),
             cpu_boost: CpuBoost::Low,
             gpu_boost: GpuBoost::Low,
-            base_window_height: 0.0,
-            expanded_window_height: None,
             custom_controls_visible_last: false,
+            applied_ui_scale: None,
+            scale_resize: None,
             detecting_device: true,
             device_detection_done: false,
             min_detecting_until: now + std::time::Duration::from_secs(1),
+            detect_requested: None,
+            last_reconnect_attempt: now,
+            reconnect_backoff: MIN_RECONNECT_BACKOFF,
+            connecting: false,
         };
 
+        app.message_manager.set_durations(app.settings.message_durations);
+        app.show_elevation_banner =
+            !app.settings.hide_elevation_warning && !system::elevation::is_elevated();
+        app.message_manager.set_max_visible(app.settings.visible_messages);
+        if let Some(load_error) = load_error {
+            app.set_error_message(match load_error.backup {
+                Some(backup) => t!("messages.config_invalid_moved", backup.display()),
+                None => t!("messages.config_invalid").to_string(),
+            });
+        }
+
         // Kick off async device detection so the UI can show a clear “Detecting device…” state.
         app.start_device_detection(init_sender.clone());
 
@@ -225,111 +793,187 @@ impl RazerGuiApp {
         });
     }
 
-    fn detect_available_performance_modes(&mut self) {
-        // Prefer firmware-advertised list; fallback to full enum when unknown.
-        if let Some(ref device) = self.device {
-            if let Some(list) = device.info().perf_modes {
-                self.available_performance_modes = list.to_vec();
-                if self.base_performance_modes.is_empty() {
-                    self.base_performance_modes = self.available_performance_modes.clone();
-                }
-                return;
+    /// Drop a device that stopped answering so the reconnect loop can pick it up again
+    fn handle_device_lost(&mut self) {
+        self.device = None;
+        self.device_state = None;
+        self.active_profile = None;
+        self.undo_history.clear();
+        self.status = DeviceStatus::default();
+        self.reported_performance_modes.clear();
+        self.boost_constraints = BoostConstraints::from_descriptor(None);
+        self.reconnect_backoff = MIN_RECONNECT_BACKOFF;
+        self.last_reconnect_attempt = std::time::Instant::now();
+        self.set_error_message(t!("messages.device_disconnected").to_string());
+    }
+
+    /// Re-detect a missing device, backing off between failed attempts unless retried manually
+    fn try_reconnect_device(&mut self, ctx: &egui::Context) {
+        let requested =
+            self.detect_requested.is_some_and(|pass| ctx.cumulative_pass_nr() > pass + 1);
+        if self.device.is_some()
+            || self.connecting
+            || !self.device_detection_done
+            || (self.last_reconnect_attempt.elapsed() < self.reconnect_backoff && !requested)
+        {
+            return;
+        }
+        self.detect_requested = None;
+
+        let now = std::time::Instant::now();
+        self.last_reconnect_attempt = now;
+        // Show "Detecting device…" briefly for each retry
+        self.detecting_device = true;
+        self.min_detecting_until = now + std::time::Duration::from_secs(1);
+
+        self.connect_device(|app, result| match result {
+            Ok(()) => {
+                app.detecting_device = false;
+                app.reconnect_backoff = MIN_RECONNECT_BACKOFF;
+                app.set_status_message(t!("messages.device_reconnected").to_string());
             }
+            Err(e) => {
+                utils::log_debug(&format!("Device re-detection failed: {}", e));
+                app.reconnect_backoff = (app.reconnect_backoff * 2).min(MAX_RECONNECT_BACKOFF);
+            }
+        });
+    }
+
+    /// Open the device on the worker and take in its initial state, then call `done`.
+    /// Once initialization has finished the full status and state follow.
+    fn connect_device(&mut self, done: impl FnOnce(&mut Self, Result<()>) + 'static) {
+        self.connecting = true;
+        self.run_device_job(
+            |device| Ok(InitialRead::read(device)),
+            move |app, result| {
+                app.connecting = false;
+                let result = result.map(|read| {
+                    app.device = Some(read.descriptor.clone());
+                    app.detect_available_performance_modes();
+                    app.apply_initial_read(read);
+                    if app.fully_initialized {
+                        app.refresh_from_device(|_, _| {});
+                    }
+                });
+                done(app, result);
+            },
+        );
+    }
+
+    /// Header retry: show the detecting state, then re-detect once it is on screen
+    fn retry_device_detection(&mut self, ctx: &egui::Context) {
+        if self.device.is_some() || !self.device_detection_done {
+            return;
         }
-        self.available_performance_modes = PerfMode::iter().collect();
+        self.detecting_device = true;
+        self.min_detecting_until = std::time::Instant::now() + std::time::Duration::from_secs(1);
+        self.reconnect_backoff = MIN_RECONNECT_BACKOFF;
+        self.detect_requested = Some(ctx.cumulative_pass_nr());
+        ctx.request_repaint();
+    }
+
+    /// Detection never writes to the device: the firmware has no capability query, so the
+    /// list comes from the descriptor and grows with modes the device is seen to report.
+    fn detect_available_performance_modes(&mut self) {
+        // Prefer firmware-advertised list; fallback to full enum when unknown.
+        let descriptor_modes = self.device.as_ref().and_then(|descriptor| descriptor.perf_modes);
+        self.available_performance_modes = match descriptor_modes {
+            Some(list) => list.to_vec(),
+            None => PerfMode::iter().collect(),
+        };
         if self.base_performance_modes.is_empty() {
             self.base_performance_modes = self.available_performance_modes.clone();
         }
+        for mode in &self.reported_performance_modes {
+            if !self.available_performance_modes.contains(mode) {
+                self.available_performance_modes.push(*mode);
+            }
+        }
     }
 
-    fn get_descriptor_allowed_boosts(
-        &self,
-    ) -> (Vec<CpuBoost>, Vec<GpuBoost>, Vec<(CpuBoost, GpuBoost)>) {
-        if let Some(ref device) = self.device {
-            let d = device.info();
-            let cpus: Vec<CpuBoost> = d.cpu_boosts.map(|s| s.to_vec()).unwrap_or_else(|| {
-                vec![CpuBoost::Low, CpuBoost::Medium, CpuBoost::High, CpuBoost::Boost]
-            });
-            let gpus: Vec<GpuBoost> = d
-                .gpu_boosts
-                .map(|s| s.to_vec())
-                .unwrap_or_else(|| vec![GpuBoost::Low, GpuBoost::Medium, GpuBoost::High]);
-            let pairs: Vec<(CpuBoost, GpuBoost)> =
-                d.disallowed_boost_pairs.map(|p| p.to_vec()).unwrap_or_default();
-            (cpus, gpus, pairs)
-        } else {
-            (
-                vec![CpuBoost::Low, CpuBoost::Medium, CpuBoost::High, CpuBoost::Boost],
-                vec![GpuBoost::Low, GpuBoost::Medium, GpuBoost::High],
-                Vec::new(),
-            )
+    /// Keep a mode the device reports selectable even when its descriptor omits it
+    fn note_reported_perf_mode(&mut self) {
+        let Some(mode) = Self::string_to_perf_mode(&self.status.performance_mode) else {
+            return;
+        };
+        if self.available_performance_modes.contains(&mode)
+            || self.reported_performance_modes.contains(&mode)
+        {
+            return;
         }
+        utils::log_error(&format!(
+            "Device reported performance mode {:?}, which its descriptor does not list",
+            mode
+        ));
+        self.reported_performance_modes.push(mode);
+        self.available_performance_modes.push(mode);
     }
 
-    fn read_initial_device_state(&mut self) {
-        if let Some(ref device) = self.device {
-            let mut reader = DeviceStateReader::new(device);
-            // Use batched reader helper to gather as much as possible without early abort.
-
-            if let Some(brightness) =
-                reader.read(|d| command::get_keyboard_brightness(d), "keyboard brightness")
-            {
-                self.status.keyboard_brightness = brightness;
-                self.temp_brightness_step = ui::lighting::raw_brightness_to_step_index(brightness);
+    fn detect_fan_rpm_range(&mut self) {
+        let listed_range = self.device.as_ref().and_then(device::listed_rpm_range);
+        self.fan_rpm_range_listed = listed_range.is_some();
+        (self.fan_rpm_range, self.fan_color_range) = match listed_range {
+            Some(range) => (range.clone(), range),
+            None => {
+                (device::MIN_MANUAL_RPM..=device::MAX_MANUAL_RPM, ui::fan::DEFAULT_RPM_COLOR_RANGE)
             }
+        };
+        self.manual_fan_rpm = self.clamp_fan_rpm(self.manual_fan_rpm);
+        self.manual_fan2_rpm = self.clamp_fan_rpm(self.manual_fan2_rpm);
+    }
 
-            if let Some((perf_mode, fan_mode)) =
-                reader.read(|d| command::get_perf_mode(d), "performance mode")
-            {
-                self.status.performance_mode = Self::perf_mode_to_string(perf_mode).to_string();
+    /// RPM used when switching to manual without a value: the lowest the device accepts
+    fn default_manual_rpm(&self) -> u16 {
+        *self.fan_rpm_range.start()
+    }
 
-                let (fan_speed, fan_rpm) = Self::get_fan_status_from_mode(fan_mode, device);
-                self.status.fan_speed = fan_speed;
-                self.status.fan_rpm = fan_rpm;
+    fn clamp_fan_rpm(&self, rpm: u16) -> u16 {
+        rpm.clamp(*self.fan_rpm_range.start(), *self.fan_rpm_range.end())
+    }
 
-                if let Some(rpm) = fan_rpm {
-                    self.manual_fan_rpm = rpm;
-                }
+    fn apply_initial_read(&mut self, read: InitialRead) {
+        self.detect_fan_rpm_range();
+        self.boost_constraints = BoostConstraints::from_descriptor(self.device.as_ref());
+        self.has_second_fan = read.has_second_fan;
 
-                if matches!(perf_mode, PerfMode::Custom) {
-                    if let Ok(v) = command::get_cpu_boost(device) {
-                        self.cpu_boost = v;
-                    }
-                    if let Ok(v) = command::get_gpu_boost(device) {
-                        self.gpu_boost = v;
-                    }
-                }
-            }
+        self.logo_supported = read.logo_mode.is_some();
+        if let Some(logo_mode) = read.logo_mode {
+            self.status.logo_mode = Self::logo_mode_to_string(logo_mode).to_string();
+        }
 
-            if self.status.fan_speed == "Reading..." {
-                // Fallback: if earlier combined call failed but later succeeds, fill fan info.
-                if let Ok((_, fan_mode)) = command::get_perf_mode(device) {
-                    let (fan_speed, fan_rpm) = Self::get_fan_status_from_mode(fan_mode, device);
-                    self.status.fan_speed = fan_speed;
-                    self.status.fan_rpm = fan_rpm;
+        if let Some(brightness) = read.keyboard_brightness {
+            self.status.keyboard_brightness = brightness;
+            self.temp_brightness_step = ui::lighting::raw_brightness_to_step_index(brightness);
+        }
 
-                    if let Some(rpm) = fan_rpm {
-                        self.manual_fan_rpm = rpm;
-                    }
-                }
+        if let Some(perf_mode) = read.perf_mode {
+            self.status.performance_mode = Self::perf_mode_to_string(perf_mode);
+        }
+        if let Some(fan_mode) = read.fan_mode {
+            self.status.fan_speed = Self::fan_mode_to_string(fan_mode).to_string();
+            self.status.fan_rpm = read.fan_rpm;
+            if let Some(rpm) = read.fan_rpm {
+                self.manual_fan_rpm = rpm;
             }
+        }
+        if let Some(v) = read.cpu_boost {
+            self.cpu_boost = v;
+        }
+        if let Some(v) = read.gpu_boost {
+            self.gpu_boost = v;
+        }
 
-            if let Some(lights_always_on) =
-                reader.read(|d| command::get_lights_always_on(d), "lights always on")
-            {
-                self.status.lights_always_on = matches!(lights_always_on, LightsAlwaysOn::Enable);
-            }
+        self.status.firmware_version = read.firmware_version;
+        self.status.keyboard_color = read.keyboard_color;
+        self.status.keyboard_backlight = read.keyboard_backlight;
 
-            if let Some(battery_care) =
-                reader.read(|d| command::get_battery_care(d), "battery care")
-            {
-                self.status.battery_care = matches!(battery_care, BatteryCare::Enable);
-            }
+        if let Some(lights_always_on) = read.lights_always_on {
+            self.status.lights_always_on = matches!(lights_always_on, LightsAlwaysOn::Enable);
+        }
 
-            let errors = reader.finish();
-            if !errors.is_empty() && cfg!(debug_assertions) {
-                eprintln!("Device state reading errors: {:?}", errors);
-            }
+        self.battery_care_supported = read.battery_care.is_some();
+        if let Some(battery_care) = read.battery_care {
+            self.status.battery_care = matches!(battery_care, BatteryCare::Enable);
         }
     }
 
@@ -337,6 +981,11 @@ impl RazerGuiApp {
         // Device may not be known yet; pass None here. We'll still display specs we can read.
         let device_name: Option<String> = None;
 
+        // Show last launch's specs right away; the fresh query below replaces them
+        if let Some(specs) = load_cached_specs() {
+            self.system_specs = specs;
+        }
+
         std::thread::spawn(move || {
             if let Ok(ac_power) = get_power_state() {
                 let _ = sender.send(InitMessage::PowerStateRead(ac_power));
@@ -346,6 +995,7 @@ impl RazerGuiApp {
 
             let device_name_ref = device_name.as_deref();
             let system_specs = get_system_specs(device_name_ref);
+            update_specs_cache(&system_specs);
             let _ = sender.send(InitMessage::SystemSpecsComplete(system_specs));
         });
 
@@ -366,21 +1016,18 @@ impl RazerGuiApp {
             match message {
                 InitMessage::DeviceDetectionComplete(present) => {
                     self.device_detection_done = true;
-                    // If a device is found, switch immediately. If not, keep detecting until grace expires.
-                    if present {
-                        self.detecting_device = false;
-                    }
-                    if present {
-                        // Acquire the device on the UI thread.
-                        if let Ok(dev) = Device::detect() {
-                            self.device = Some(dev);
-                        }
-                    }
                     self.detect_available_performance_modes();
-                    if self.device.is_some() {
-                        self.read_initial_device_state();
-                        // Now that the device is known, we can show a brief init message.
-                        self.set_status_message("Initializing...".to_string());
+                    // If a device is found, switch once the worker has opened it. If not, keep
+                    // detecting until grace expires.
+                    if present {
+                        self.connect_device(|app, result| match result {
+                            Ok(()) => {
+                                app.detecting_device = false;
+                                // Now that the device is known, we can show a brief init message.
+                                app.set_status_message(t!("messages.initializing").to_string());
+                            }
+                            Err(e) => eprintln!("Failed to connect to Razer device: {}", e),
+                        });
                     }
                 }
                 InitMessage::SystemSpecsComplete(specs) => {
@@ -389,14 +1036,14 @@ impl RazerGuiApp {
                     if self.fully_initialized && self.init_power_read && self.init_specs_complete {
                         // Avoid flashing a transient message on unsupported devices.
                         if self.device.is_some() {
-                            self.set_status_message("Initialization complete".to_string());
+                            self.set_status_message(t!("messages.initialized").to_string());
                         } else {
-                            self.set_optional_status_message("Initialization complete".to_string());
+                            self.set_optional_status_message(
+                                t!("messages.initialized").to_string(),
+                            );
                         }
                     } else {
-                        self.set_optional_status_message(
-                            "System specifications loaded".to_string(),
-                        );
+                        self.set_optional_status_message(t!("messages.specs_loaded").to_string());
                     }
                 }
                 InitMessage::PowerStateRead(ac_power) => {
@@ -405,14 +1052,13 @@ impl RazerGuiApp {
                 }
                 InitMessage::InitializationComplete => {
                     self.fully_initialized = true;
+                    // A device still connecting is read once its initial state is in
                     if self.device.is_some() {
-                        if let Err(e) = self.read_device_status() {
-                            self.set_error_message(format!("Failed to read device status: {}", e));
-                        } else {
-                            self.update_stored_device_state();
-                            self.sync_ui_with_device_state();
-                            self.init_fan_slider_from_device();
-                        }
+                        self.refresh_from_device(|app, result| {
+                            if let Err(e) = result {
+                                app.set_error_message(t!("messages.read_status_failed", e));
+                            }
+                        });
                     }
                 }
             }
@@ -421,108 +1067,154 @@ impl RazerGuiApp {
 }
 
 fn get_fan_rpm_actual(device: &Device, zone: librazer::types::FanZone) -> Option<u16> {
-    match command::get_fan_actual_rpm(device, zone) {
-        Ok(rpm) => Some(rpm),
-        Err(_) => None,
-    }
+    command::get_fan_actual_rpm(device, zone).ok()
 }
 
 fn get_fan_rpm_set(device: &Device, zone: librazer::types::FanZone) -> Option<u16> {
-    match command::get_fan_rpm(device, zone) {
-        Ok(rpm) => Some(rpm),
-        Err(_) => None,
-    }
+    command::get_fan_rpm(device, zone).ok()
 }
 
 impl RazerGuiApp {
-    fn read_device_status(&mut self) -> Result<()> {
-        let device = self.device.as_ref().unwrap();
-        // Core perf + fan query (single device round-trip).
-        let (perf_mode, fan_mode) = command::get_perf_mode(device)?;
-        self.status.performance_mode = Self::perf_mode_to_string(perf_mode).to_string();
-        let (fan_speed, fan_rpm) = Self::get_fan_status_from_mode(fan_mode, device);
-        self.status.fan_speed = fan_speed;
-        self.status.fan_rpm = fan_rpm;
-        if let Some(rpm) = fan_rpm {
-            self.manual_fan_rpm = rpm;
-        }
-        self.status.fan_actual_rpm = get_fan_rpm_actual(device, librazer::types::FanZone::Zone1);
-        if let Ok(logo_mode) = command::get_logo_mode(device) {
-            self.status.logo_mode = Self::logo_mode_to_string(logo_mode).to_string();
-        }
+    /// Queue `job` on the device worker; `done` gets its result on the UI thread once it has
+    /// run. Worker snapshots taken before the job started are skipped from then on.
+    fn run_device_job<T: Send + 'static>(
+        &mut self,
+        job: impl FnOnce(&Device) -> Result<T> + Send + 'static,
+        done: impl FnOnce(&mut Self, Result<T>) + 'static,
+    ) {
+        let Some(poller) = &self.device_poller else {
+            done(self, Err(anyhow::anyhow!("Device worker not running")));
+            return;
+        };
+        let receiver = poller.run(move |device| Ok((std::time::Instant::now(), job(device))));
+        let mut done = Some(done);
+        self.device_jobs.push(Box::new(move |app| {
+            let result = match receiver.try_recv() {
+                Err(mpsc::TryRecvError::Empty) => return false,
+                Ok(Ok((started, result))) => {
+                    app.last_device_read = app.last_device_read.max(started);
+                    result
+                }
+                Ok(Err(e)) => Err(e),
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    Err(anyhow::anyhow!("Device worker stopped"))
+                }
+            };
+            if let Some(done) = done.take() {
+                done(app, result);
+            }
+            true
+        }));
+    }
 
-        if let Ok(brightness) = command::get_keyboard_brightness(device) {
-            self.status.keyboard_brightness = brightness;
-            self.temp_brightness_step = ui::lighting::raw_brightness_to_step_index(brightness);
+    /// Run a single write on the worker; `applied` updates the UI once it succeeded and a
+    /// failure is shown as `error_prefix: error`
+    fn run_device_command<T: Send + 'static>(
+        &mut self,
+        command: impl FnOnce(&Device) -> Result<T> + Send + 'static,
+        error_prefix: &'static str,
+        applied: impl FnOnce(&mut Self) + 'static,
+    ) {
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
         }
+        self.run_device_job(command, move |app, result| match result {
+            Ok(_) => applied(app),
+            Err(e) => app.set_error_message(t!("messages.failed_with", error_prefix, e)),
+        });
+    }
 
-        if let Ok(lights_always_on) = command::get_lights_always_on(device) {
-            self.status.lights_always_on = matches!(lights_always_on, LightsAlwaysOn::Enable);
-        }
+    /// Apply the results of finished device jobs in the order they were queued
+    fn process_device_jobs(&mut self) {
+        let mut jobs = std::mem::take(&mut self.device_jobs);
+        jobs.retain_mut(|job| !job(self));
+        // Jobs queued by the results above run after those still waiting
+        jobs.append(&mut self.device_jobs);
+        self.device_jobs = jobs;
+    }
 
-        if let Ok(battery_care) = command::get_battery_care(device) {
-            self.status.battery_care = matches!(battery_care, BatteryCare::Enable);
+    fn read_plan(&self) -> ReadPlan {
+        ReadPlan {
+            second_fan: self.has_second_fan,
+            keyboard_backlight: self.status.keyboard_backlight.is_some(),
         }
+    }
 
-        Ok(())
+    /// Re-read the status and the full state on the worker, recording the state for undo,
+    /// then call `then` with the status read's result
+    fn refresh_from_device(&mut self, then: impl FnOnce(&mut Self, Result<()>) + 'static) {
+        let plan = self.read_plan();
+        self.run_device_job(
+            move |device| Ok(DeviceReadout::read(device, plan)),
+            move |app, result| {
+                let result = result.and_then(|readout| app.apply_readout(readout));
+                then(app, result);
+            },
+        );
     }
 
-    fn sync_ui_with_device_state(&mut self) {
-        if let Some(ref device) = self.device {
-            // Refresh only fields that can drift externally (brightness skipped if user dragging slider).
-            if !self.brightness_slider_active {
-                if let Ok(brightness) = command::get_keyboard_brightness(device) {
-                    self.status.keyboard_brightness = brightness;
-                    self.temp_brightness_step =
-                        ui::lighting::raw_brightness_to_step_index(brightness);
-                }
-            }
-            let (fan_mode, set_rpm) = Self::read_current_fan_state(device);
-            let (fan_speed, fan_rpm) = Self::get_fan_status_from_mode(fan_mode, device);
-            self.status.fan_speed = fan_speed;
-            self.status.fan_rpm = fan_rpm;
-            if let Some(rpm) = set_rpm {
-                self.manual_fan_rpm = rpm;
-            }
-            if let Ok(lights_always_on) = command::get_lights_always_on(device) {
-                self.status.lights_always_on = matches!(lights_always_on, LightsAlwaysOn::Enable);
-            }
-            if let Ok(battery_care) = command::get_battery_care(device) {
-                self.status.battery_care = matches!(battery_care, BatteryCare::Enable);
-            }
+    /// Show a worker readout and record its full state; returns the status read's result
+    fn apply_readout(&mut self, readout: DeviceReadout) -> Result<()> {
+        Self::record_read_latency(&mut self.last_read_latency, "Status read", readout.read_time);
+        if let Ok(state) = readout.state {
+            self.store_device_state(state);
         }
+        readout.status.map(|status| self.apply_status_read(status))
     }
 
-    fn sync_other_dynamic_state(&mut self) {
-        if let Some(ref device) = self.device {
-            // Light-weight periodic poll for simple toggles.
-            if let Ok(lights_always_on) = command::get_lights_always_on(device) {
-                self.status.lights_always_on = matches!(lights_always_on, LightsAlwaysOn::Enable);
-            }
-            if let Ok(battery_care) = command::get_battery_care(device) {
-                self.status.battery_care = matches!(battery_care, BatteryCare::Enable);
+    fn apply_status_read(&mut self, read: StatusRead) {
+        self.status.performance_mode = Self::perf_mode_to_string(read.perf_mode);
+        self.status.fan_speed = Self::fan_mode_to_string(read.fan_mode).to_string();
+        self.status.fan_rpm = read.fan_rpm;
+        if let Some(rpm) = read.fan_rpm {
+            self.manual_fan_rpm = rpm;
+        }
+        self.status.fan_actual_rpm = read.fan_actual_rpm;
+        if self.has_second_fan {
+            self.status.fan2_actual_rpm = read.fan2_actual_rpm;
+            self.status.fan2_rpm = read.fan2_rpm;
+            if let Some(rpm) = read.fan2_rpm {
+                self.manual_fan2_rpm = rpm;
             }
         }
-    }
 
-    fn init_fan_slider_from_device(&mut self) {
-        if let Some(ref device) = self.device {
-            // Initializes manual fan slider to currently set RPM if in Manual.
-            let (fan_mode, set_rpm) = Self::read_current_fan_state(device);
-            let (fan_speed, fan_rpm) = Self::get_fan_status_from_mode(fan_mode, device);
-            self.status.fan_speed = fan_speed;
-            self.status.fan_rpm = fan_rpm;
-            if let Some(rpm) = set_rpm {
-                self.manual_fan_rpm = rpm;
-            }
+        if let Some(logo_mode) = read.logo_mode {
+            self.status.logo_mode = Self::logo_mode_to_string(logo_mode).to_string();
+        }
+        // Brightness skipped while the slider is dragged or was just written
+        if let Some(brightness) =
+            read.keyboard_brightness.filter(|_| self.brightness_readback_allowed())
+        {
+            self.status.keyboard_brightness = brightness;
+            self.temp_brightness_step = ui::lighting::raw_brightness_to_step_index(brightness);
+        }
+        if let Some(on) = read.keyboard_backlight {
+            self.status.keyboard_backlight = Some(on);
+        }
+        if let Some(lights_always_on) = read.lights_always_on {
+            self.status.lights_always_on = matches!(lights_always_on, LightsAlwaysOn::Enable);
+        }
+        if let Some(battery_care) = read.battery_care {
+            self.status.battery_care = matches!(battery_care, BatteryCare::Enable);
         }
     }
 
-    fn check_device_state_changes(&mut self) -> Result<()> {
-        if let Some(ref device) = self.device {
-            // Full snapshot comparison to detect external changes.
-            let current_state = CompleteDeviceState::read_from_device(device)?;
+    /// Remember how long a full device read took and log it when slow
+    fn record_read_latency(
+        last: &mut Option<std::time::Duration>,
+        what: &str,
+        elapsed: std::time::Duration,
+    ) {
+        *last = Some(elapsed);
+        if elapsed > SLOW_READ_THRESHOLD {
+            utils::log_warning(&format!("{} took {}ms", what, elapsed.as_millis()));
+        }
+    }
 
+    /// Full snapshot comparison against the last known state to detect external changes
+    fn check_device_state_changes(&mut self, current_state: CompleteDeviceState) {
+        if self.device.is_some() {
             if let Some(ref stored_state) = self.device_state {
                 if current_state != *stored_state {
                     let old_perf_mode = Self::perf_mode_to_string(stored_state.perf_mode);
@@ -534,32 +1226,37 @@ impl RazerGuiApp {
                     self.status.performance_mode =
                         Self::perf_mode_to_string(current_state.perf_mode).to_string();
 
-                    let (fan_speed, fan_rpm) =
-                        Self::get_fan_status_from_mode(current_state.fan_mode, device);
-                    self.status.fan_speed = fan_speed;
-                    self.status.fan_rpm = fan_rpm;
-                    if let Some(rpm) = fan_rpm {
+                    self.status.fan_speed =
+                        Self::fan_mode_to_string(current_state.fan_mode).to_string();
+                    self.status.fan_rpm = current_state.fan_rpm;
+                    if let Some(rpm) = current_state.fan_rpm {
                         self.manual_fan_rpm = rpm;
                     }
 
-                    self.status.logo_mode =
-                        Self::logo_mode_to_string(current_state.logo_mode).to_string();
+                    if let Some(logo_mode) = current_state.logo_mode {
+                        self.status.logo_mode = Self::logo_mode_to_string(logo_mode).to_string();
+                    }
 
                     self.status.keyboard_brightness = current_state.keyboard_brightness;
                     self.temp_brightness_step = ui::lighting::raw_brightness_to_step_index(
                         current_state.keyboard_brightness,
                     );
 
+                    self.status.keyboard_color = current_state.keyboard_color;
+                    self.status.keyboard_backlight = current_state.keyboard_backlight;
                     self.status.lights_always_on =
                         matches!(current_state.lights_always_on, LightsAlwaysOn::Enable);
-                    self.status.battery_care =
-                        matches!(current_state.battery_care, BatteryCare::Enable);
+                    if let Some(battery_care) = current_state.battery_care {
+                        self.status.battery_care = matches!(battery_care, BatteryCare::Enable);
+                    }
 
-                    if old_perf_mode != new_perf_mode {
-                        self.set_optional_status_message("Mode updated".to_string());
-                    } else if self.status_messages {
+                    // Frequent changes get one interference warning instead of a message each
+                    let interfering = self.record_external_change();
+                    if !interfering && old_perf_mode != new_perf_mode {
+                        self.set_optional_status_message(t!("messages.mode_updated").to_string());
+                    } else if !interfering && self.settings.status_messages {
                         self.set_optional_status_message(
-                            "Device state updated externally".to_string(),
+                            t!("messages.updated_externally").to_string(),
                         );
                     }
                 }
@@ -567,7 +1264,123 @@ impl RazerGuiApp {
                 self.device_state = Some(current_state);
             }
         }
-        Ok(())
+    }
+
+    /// Take in the worker's reads. Quick status reads follow the UI's 500ms refresh;
+    /// a full state read is compared against the last known state, and the device is
+    /// dropped when it no longer answers.
+    fn process_device_poll(&mut self, ctx: &egui::Context) {
+        let Some(poller) = &self.device_poller else {
+            return;
+        };
+        let ready = self.fully_initialized && self.device.is_some() && !self.loading;
+        let minimized = ctx.input(|i| i.viewport().minimized.unwrap_or(false));
+        // External changes (other apps, Fn keys) are picked up even while minimized
+        poller.set_polling(ready && !minimized, ready);
+        poller.set_second_fan(self.has_second_fan);
+
+        for snapshot in poller.snapshots() {
+            // Skip reads that began before a finished job or while jobs and profile writes
+            // are still changing the device
+            if !ready
+                || self.device.is_none()
+                || self.profile_apply.is_some()
+                || !self.device_jobs.is_empty()
+                || snapshot.taken_at < self.last_device_read
+            {
+                continue;
+            }
+            self.apply_status_snapshot(&snapshot);
+
+            let Some(state) = snapshot.state else {
+                continue;
+            };
+            if self.brightness_fade.is_some() {
+                continue;
+            }
+            Self::record_read_latency(
+                &mut self.last_read_latency,
+                "State read",
+                snapshot.read_time,
+            );
+            match state {
+                Ok(state) => self.check_device_state_changes(state),
+                Err(e) => {
+                    utils::log_debug(&format!("Device state check failed: {}", e));
+                    // Fallback: read full device status instead of minimal subset
+                    let plan = self.read_plan();
+                    self.run_device_job(
+                        move |device| StatusRead::read(device, plan),
+                        |app, result| match result {
+                            Ok(read) => app.apply_status_read(read),
+                            Err(e) => {
+                                utils::log_debug(&format!("Device status read failed: {}", e));
+                                // Both reads failing means the device is gone, not busy
+                                if app.device.is_some() {
+                                    app.handle_device_lost();
+                                }
+                            }
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// Fan speeds, fan mode and brightness from the worker's quick reads
+    fn apply_status_snapshot(&mut self, snapshot: &device::DeviceSnapshot) {
+        let Some(fan_mode) = snapshot.fan_mode else {
+            return;
+        };
+        self.status.fan_actual_rpm = snapshot.fan_actual_rpm;
+        if let Some(rpm) = snapshot.fan_actual_rpm {
+            Self::record_fan_rpm_sample(&mut self.fan_rpm_history, rpm);
+        }
+        if self.has_second_fan {
+            self.status.fan2_actual_rpm = snapshot.fan2_actual_rpm;
+        }
+
+        if self.status.fan_speed == "Manual" && fan_mode == FanMode::Auto {
+            self.note_external_fan_auto();
+        } else if fan_mode == FanMode::Auto {
+            self.status.fan_speed = "Auto".to_string();
+        } else {
+            self.status.fan_speed = "Manual".to_string();
+        }
+
+        if self.brightness_readback_allowed() && self.brightness_fade.is_none() {
+            if let Some(brightness) = snapshot.keyboard_brightness {
+                self.status.keyboard_brightness = brightness;
+                self.temp_brightness_step = ui::lighting::raw_brightness_to_step_index(brightness);
+            }
+        }
+    }
+
+    /// Count an external change; returns true when changes are frequent enough to blame
+    /// another app (warning about it once)
+    fn record_external_change(&mut self) -> bool {
+        let now = std::time::Instant::now();
+        self.external_changes.push_back(now);
+        while self
+            .external_changes
+            .front()
+            .is_some_and(|&at| now.duration_since(at) > EXTERNAL_CHANGE_WINDOW)
+        {
+            self.external_changes.pop_front();
+        }
+
+        if self.external_changes.len() <= EXTERNAL_CHANGE_THRESHOLD {
+            return false;
+        }
+        if !self.interference_warned {
+            self.interference_warned = true;
+            let mut warning = t!("messages.interference").to_string();
+            if !self.system_specs.control_apps.is_empty() {
+                warning.push_str(&format!(" ({})", self.system_specs.control_apps.join(", ")));
+            }
+            self.set_error_message(warning);
+        }
+        true
     }
 
     fn set_status_message(&mut self, message: String) {
@@ -575,171 +1388,1036 @@ impl RazerGuiApp {
     }
 
     fn set_optional_status_message(&mut self, message: String) {
-        if self.status_messages {
+        if self.settings.status_messages {
             self.message_manager.add_message(status_message(message));
         }
     }
 
     fn set_error_message(&mut self, message: String) {
+        utils::log_error(&message);
         self.message_manager.add_message(error_message(message));
     }
 
-    fn update_stored_device_state(&mut self) {
-        if let Some(ref device) = self.device {
-            if let Ok(current_state) = CompleteDeviceState::read_from_device(device) {
-                self.device_state = Some(current_state);
-            }
+    /// Read the full state on the worker and record it, then call `then`
+    /// (straight away without a device)
+    fn update_stored_device_state(&mut self, then: impl FnOnce(&mut Self) + 'static) {
+        if self.device.is_none() {
+            then(self);
+            return;
         }
+        self.run_device_job(
+            |device| {
+                let started = std::time::Instant::now();
+                let read = CompleteDeviceState::read_from_device(device);
+                Ok((read, started.elapsed()))
+            },
+            |app, result| {
+                if let Ok((read, read_time)) = result {
+                    Self::record_read_latency(&mut app.last_read_latency, "State read", read_time);
+                    if let Ok(current_state) = read {
+                        app.store_device_state(current_state);
+                    }
+                }
+                then(app);
+            },
+        );
     }
 
-    fn auto_switch_profile(&mut self) {
-        if let Some(ref device) = self.device {
-            // Choose which saved profile to apply based on current AC state.
-            let target_profile =
-                if self.ac_power { self.ac_profile.clone() } else { self.battery_profile.clone() };
+    /// Replace the stored device state, keeping the previous one for undo
+    fn store_device_state(&mut self, current_state: CompleteDeviceState) {
+        if let Some(previous) = self.device_state.replace(current_state) {
+            if self.device_state.as_ref() != Some(&previous) {
+                self.undo_history.push_back(previous);
+                if self.undo_history.len() > UNDO_HISTORY_LIMIT {
+                    self.undo_history.pop_front();
+                }
+            }
+        }
+    }
+
+    /// Re-apply the state from before the last change
+    fn undo_last_change(&mut self) {
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+        if self.blocked_by_monitor_mode() {
+            return;
+        }
+        let Some(previous) = self.undo_history.pop_back() else {
+            return;
+        };
 
-            let profile_name = if self.ac_power { "AC" } else { "Battery" };
+        let reverted = self
+            .device_state
+            .as_ref()
+            .map(|current| current.changed_settings(&previous))
+            .unwrap_or_default();
+        let success_message = if reverted.is_empty() {
+            t!("messages.undid").to_string()
+        } else {
+            t!("messages.undid_settings", reverted.join(", "))
+        };
 
-            if let Err(e) = command::set_perf_mode(device, target_profile.perf_mode) {
-                self.set_error_message(format!(
-                    "Failed to switch to {} profile: {}",
-                    profile_name, e
-                ));
-                return;
+        self.start_profile_job(ProfileJob {
+            profile: previous,
+            scope: None,
+            success_message,
+            error_prefix: t!("messages.undo_failed").to_string(),
+            notification: None,
+            undo: true,
+            lights: None,
+            restores_lights: false,
+            profile_name: None,
+        });
+    }
+
+    /// Write settings and profiles to disk; call after changing either
+    fn save_config(&mut self) {
+        let config = config::AppConfig {
+            version: config::CONFIG_VERSION,
+            settings: self.settings.clone(),
+            profiles: config::StoredProfiles {
+                ac: self.ac_profile.clone(),
+                battery: self.battery_profile.clone(),
+                named: self.profiles.clone(),
+            },
+            load_error: None,
+        };
+        if let Err(e) = config::save_config(&config) {
+            self.set_error_message(t!("messages.save_failed", e));
+        }
+    }
+
+    /// Height the custom CPU/GPU boost rows add while Custom mode is shown
+    fn custom_controls_height(ctx: &egui::Context) -> f32 {
+        3.0 * ctx.style().spacing.interact_size.y
+    }
+
+    /// Remember the current window rectangle; written to disk on exit
+    fn track_window_geometry(&mut self, ctx: &egui::Context) {
+        // Keep the full-view geometry while the compact readout is shown
+        if self.settings.compact_mode {
+            return;
+        }
+        let geometry = ctx.input(|i| {
+            let viewport = i.viewport();
+            if viewport.minimized.unwrap_or(false) {
+                return None;
+            }
+            let outer = viewport.outer_rect?;
+            let inner = viewport.inner_rect?;
+            Some(config::WindowGeometry {
+                x: outer.min.x,
+                y: outer.min.y,
+                width: inner.width(),
+                height: inner.height(),
+                scale: viewport.native_pixels_per_point.unwrap_or(1.0),
+            })
+        });
+
+        if let Some(mut geometry) = geometry {
+            // Store the non-Custom height; the custom rows are added back when shown
+            if self.custom_controls_visible_last {
+                geometry.height -= Self::custom_controls_height(ctx);
             }
+            self.settings.window = Some(geometry);
+        }
+    }
+
+    fn reset_window(&mut self, ctx: &egui::Context) {
+        self.settings.window = None;
+        self.save_config();
+
+        let [width, mut height] = DEFAULT_WINDOW_SIZE;
+        if self.custom_controls_visible_last {
+            height += Self::custom_controls_height(ctx);
+        }
+        ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(width, height)));
+        if let Some(center) = egui::ViewportCommand::center_on_screen(ctx) {
+            ctx.send_viewport_cmd(center);
+        }
+        tray::show_window(ctx);
+        self.window_hidden = false;
+        self.set_optional_status_message(t!("messages.window_reset").to_string());
+    }
+
+    /// Apply the UI scale setting and keep the window's layout size across scale changes,
+    /// so sections are not clipped when everything grows
+    fn apply_ui_scale(&mut self, ctx: &egui::Context) {
+        if let Some(size) = self.scale_resize.take() {
+            let min_size =
+                if self.settings.compact_mode { COMPACT_WINDOW_SIZE } else { MIN_WINDOW_SIZE };
+            // Never grow past the monitor; the sections scroll instead
+            let size = match ctx.input(|i| i.viewport().monitor_size) {
+                Some(monitor) => size.min(monitor * 0.9).max(min_size.into()),
+                None => size,
+            };
+            ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(min_size.into()));
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(size));
+        }
+
+        let scale = self
+            .settings
+            .ui_scale
+            .map(|scale| scale.clamp(config::UI_SCALE_MIN, config::UI_SCALE_MAX));
+        // The system DPI is only known once the window reports it
+        if scale == self.applied_ui_scale || ctx.native_pixels_per_point().is_none() {
+            return;
+        }
+        // Sizes are in points, so re-requesting the current size rescales the window with the UI
+        self.scale_resize = ctx.input(|i| i.viewport().inner_rect).map(|rect| rect.size());
+        match scale {
+            Some(scale) => ctx.set_pixels_per_point(scale),
+            None => ctx.set_zoom_factor(1.0),
+        }
+        self.applied_ui_scale = scale;
+    }
+
+    /// Launch without showing the window (`--minimized` or the setting). Initialization, the
+    /// first device read and profile automation keep running from the background repaints.
+    fn start_hidden(&mut self, ctx: &egui::Context) {
+        if self.tray.is_some() {
+            tray::hide_window(ctx);
+            self.window_hidden = true;
+        } else {
+            // Without a tray icon a hidden window could not be brought back
+            ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(true));
+        }
+    }
+
+    /// Swap between the full window and the single-row always-on-top readout
+    fn set_compact_mode(&mut self, ctx: &egui::Context, compact: bool) {
+        if compact {
+            self.full_window_size = ctx.input(|i| i.viewport().inner_rect).map(|rect| rect.size());
+            ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(COMPACT_WINDOW_SIZE.into()));
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(COMPACT_WINDOW_SIZE.into()));
+            ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(
+                egui::WindowLevel::AlwaysOnTop,
+            ));
+        } else {
+            // Launching in compact mode leaves only the saved geometry to go back to
+            let size = self
+                .full_window_size
+                .take()
+                .or_else(|| self.settings.window.map(|w| egui::vec2(w.width, w.height)))
+                .unwrap_or(DEFAULT_WINDOW_SIZE.into());
+            ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(MIN_WINDOW_SIZE.into()));
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(size));
+            ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(egui::WindowLevel::Normal));
+        }
+        self.settings.compact_mode = compact;
+        self.save_config();
+    }
+
+    /// Re-read everything from the device on request (header 🔄 button)
+    fn refresh_device_state(&mut self) {
+        self.last_manual_refresh = Some(std::time::Instant::now());
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+        self.refresh_from_device(|app, result| match result {
+            Ok(()) => app.set_optional_status_message(t!("messages.refreshed").to_string()),
+            Err(e) => app.set_error_message(t!("messages.refresh_failed", e)),
+        });
+    }
+
+    /// Build the report (on the worker when it reads the device) and copy it once done
+    fn copy_diagnostics(&mut self, ctx: &egui::Context) {
+        let specs = self.system_specs.clone();
+        let modes = self.available_performance_modes.clone();
+        let ctx = ctx.clone();
+        let copy = move |app: &mut Self, report: String| {
+            ctx.copy_text(report);
+            app.set_status_message(t!("messages.diagnostics_copied").to_string());
+        };
+        if self.device.is_none() {
+            copy(self, diagnostics::build_report(&specs, None, &modes));
+            return;
+        }
+        self.run_device_job(
+            move |device| Ok(diagnostics::build_report(&specs, Some(device), &modes)),
+            move |app, result| match result {
+                Ok(report) => copy(app, report),
+                Err(e) => app.set_error_message(t!("messages.diagnostics_failed", e)),
+            },
+        );
+    }
+
+    fn set_autostart(&mut self, enabled: bool) {
+        match autostart::set_autostart(enabled) {
+            Ok(()) => {
+                self.set_optional_status_message(if enabled {
+                    t!("messages.autostart_enabled").to_string()
+                } else {
+                    t!("messages.autostart_disabled").to_string()
+                });
+            }
+            Err(e) => self.set_error_message(t!("messages.autostart_failed", e)),
+        }
+        self.autostart = autostart::get_autostart_state();
+    }
 
-            self.status.performance_mode =
-                Self::perf_mode_to_string(target_profile.perf_mode).to_string();
+    fn process_hotkey(&mut self, ctx: &egui::Context) {
+        let pressed = self.hotkey.as_ref().is_some_and(|hotkey| hotkey.pressed());
+        // Typing the combination into a text field must not also switch modes
+        if !pressed || ctx.wants_keyboard_input() {
+            return;
+        }
+        self.cycle_performance_mode();
+    }
 
-            self.set_status_message(format!("⚡ Auto-switched to {} profile", profile_name));
+    /// In-window keys: F toggles fan Auto/Manual, +/- step brightness, 1-5 pick a mode
+    fn process_shortcuts(&mut self, ctx: &egui::Context) {
+        // Keys belong to a focused slider or text field
+        if ctx.memory(|memory| memory.focused().is_some()) || ctx.wants_keyboard_input() {
+            return;
+        }
+        if self.device.is_none() || self.profile_apply.is_some() || self.monitor_only() {
+            return;
         }
 
-        // Read current device state to preserve user settings
-        if let Err(_) = self.read_device_status() {
-            // If we can't read device status, try to apply minimal fallback
-            if let Some(ref device) = self.device {
-                let target_profile = if self.ac_power {
-                    self.ac_profile.clone()
+        let (toggle_fan, brightness_delta, mode_index) = ctx.input(|i| {
+            if i.modifiers.command || i.modifiers.alt {
+                return (false, 0, None);
+            }
+            let brightness_delta =
+                if i.key_pressed(egui::Key::Plus) || i.key_pressed(egui::Key::Equals) {
+                    1
+                } else if i.key_pressed(egui::Key::Minus) {
+                    -1
                 } else {
-                    self.battery_profile.clone()
+                    0
                 };
+            let mode_index = ui::performance::MODE_KEYS.iter().position(|&key| i.key_pressed(key));
+            (i.key_pressed(egui::Key::F), brightness_delta, mode_index)
+        });
+
+        if toggle_fan {
+            if self.max_fan_restore.is_some() {
+                self.end_max_fan();
+            }
+            if self.status.fan_speed.eq_ignore_ascii_case("auto") {
+                self.set_fan_mode("manual", Some(self.manual_fan_rpm));
+            } else {
+                self.set_fan_mode("auto", None);
+            }
+        }
+
+        if brightness_delta != 0 && self.status.keyboard_backlight != Some(false) {
+            let step = self
+                .temp_brightness_step
+                .saturating_add_signed(brightness_delta)
+                .min(ui::lighting::MAX_BRIGHTNESS_STEP);
+            if step != self.temp_brightness_step {
+                self.temp_brightness_step = step;
+                self.change_brightness(ui::lighting::step_index_to_raw_brightness(step));
+            }
+        }
+
+        // Same order as the buttons, so each key matches the number in its tooltip
+        let mode = mode_index.and_then(|index| {
+            ui::performance::ordered_modes(&self.available_performance_modes).get(index).copied()
+        });
+        if let Some(mode) = mode {
+            let mode_name = Self::perf_mode_to_string(mode);
+            if self.status.performance_mode != mode_name {
+                self.request_performance_mode(mode_name);
+            }
+        }
+    }
+
+    /// Switch to the next available performance mode
+    fn cycle_performance_mode(&mut self) {
+        if self.device.is_none() || self.available_performance_modes.is_empty() {
+            return;
+        }
+
+        let modes = &self.available_performance_modes;
+        let next_index = modes
+            .iter()
+            .position(|&mode| Self::perf_mode_to_string(mode) == self.status.performance_mode)
+            .map_or(0, |index| (index + 1) % modes.len());
+        let next_mode = Self::perf_mode_to_string(modes[next_index]);
+
+        self.last_manual_perf_change = Some(std::time::Instant::now());
+        let message = t!("messages.performance_mode", next_mode);
+        self.set_performance_mode_then(&next_mode, move |app| app.set_status_message(message));
+    }
+
+    fn process_tray_commands(&mut self, ctx: &egui::Context) {
+        let mut commands = Vec::new();
+        if let Some(ref tray) = self.tray {
+            while let Some(command) = tray.try_recv() {
+                commands.push(command);
+            }
+        }
+
+        for command in commands {
+            match command {
+                tray::TrayCommand::Show => {
+                    tray::show_window(ctx);
+                    self.window_hidden = false;
+                }
+                tray::TrayCommand::ResetWindow => self.reset_window(ctx),
+                tray::TrayCommand::SetPerformanceMode(mode) => {
+                    if self.device.is_none() {
+                        self.set_no_device_message();
+                    } else if self.profile_apply.is_none() {
+                        self.last_manual_perf_change = Some(std::time::Instant::now());
+                        self.set_performance_mode(&mode);
+                    }
+                }
+                tray::TrayCommand::Quit => self.should_quit = true,
+            }
+        }
+
+        self.sync_tray();
+    }
+
+    /// Start the release lookup once when enabled and pick up its result
+    fn process_update_check(&mut self) {
+        if self.settings.check_for_updates && !self.update_check_started {
+            self.update_check_started = true;
+            self.update_check = Some(update::spawn_check());
+        }
+
+        if let Some(ref receiver) = self.update_check {
+            match receiver.try_recv() {
+                Ok(release) => {
+                    self.available_update = release;
+                    self.update_check = None;
+                }
+                Err(mpsc::TryRecvError::Empty) => {}
+                Err(mpsc::TryRecvError::Disconnected) => self.update_check = None,
+            }
+        }
+    }
+
+    /// Start/stop the telemetry pipe to match the settings and feed it at the refresh cadence
+    fn process_telemetry_pipe(&mut self) {
+        if !self.settings.telemetry_pipe {
+            self.telemetry_pipe = None;
+            self.telemetry_pipe_failed = false;
+            return;
+        }
+
+        if self.telemetry_pipe.is_none() && !self.telemetry_pipe_failed {
+            match pipe::TelemetryPipe::start() {
+                Ok(telemetry_pipe) => {
+                    self.telemetry_pipe = Some(telemetry_pipe);
+                    self.set_optional_status_message(t!("messages.pipe_open", pipe::PIPE_NAME));
+                }
+                Err(e) => {
+                    self.telemetry_pipe_failed = true;
+                    self.set_error_message(t!("messages.pipe_failed", format!("{:#}", e)));
+                }
+            }
+        }
+
+        if let Some(ref telemetry_pipe) = self.telemetry_pipe {
+            if self.last_telemetry_sent.elapsed() >= TELEMETRY_INTERVAL {
+                self.last_telemetry_sent = std::time::Instant::now();
+                telemetry_pipe.publish(pipe::Telemetry {
+                    perf_mode: self.status.performance_mode.clone(),
+                    fan_rpm: self.status.fan_actual_rpm,
+                    battery_percent: self.battery_status.and_then(|status| status.percent),
+                    ac: self.ac_power,
+                });
+            }
+        }
+    }
+
+    /// Start/stop the automation endpoint to match the settings, publish the current state and
+    /// run the commands it received
+    fn process_api_commands(&mut self, ctx: &egui::Context) {
+        let api = &self.settings.api;
+        if !api.enabled {
+            self.api_server = None;
+            self.api_failed_port = None;
+            return;
+        }
+
+        if self.api_server.as_ref().is_some_and(|server| server.port() != api.port) {
+            self.api_server = None;
+        }
+        if self.api_server.is_none() && self.api_failed_port != Some(api.port) {
+            match api::ApiServer::start(ctx, api.port, &api.token) {
+                Ok(server) => {
+                    self.api_failed_port = None;
+                    self.api_server = Some(server);
+                    self.set_optional_status_message(t!("messages.api_listening", api.port));
+                }
+                Err(e) => {
+                    self.api_failed_port = Some(api.port);
+                    self.set_error_message(t!("messages.api_failed", format!("{:#}", e)));
+                }
+            }
+        }
+
+        let mut commands = Vec::new();
+        if let Some(ref server) = self.api_server {
+            server.set_token(&self.settings.api.token);
+            server.publish_state(self.device_state.as_ref());
+            while let Some(command) = server.try_recv() {
+                commands.push(command);
+            }
+        }
 
-                if let Err(e) = self.apply_profile(device, &target_profile) {
-                    self.set_error_message(format!("Failed to apply fallback profile: {}", e));
+        for command in commands {
+            if self.device.is_none() {
+                self.set_no_device_message();
+                continue;
+            }
+            match command {
+                api::ApiCommand::SetPerformanceMode(mode) => {
+                    let available = self
+                        .available_performance_modes
+                        .iter()
+                        .any(|&available| Self::perf_mode_to_string(available) == mode);
+                    if !available {
+                        self.set_error_message(t!("messages.api_unknown_mode", mode));
+                    } else if self.profile_apply.is_none() {
+                        self.last_manual_perf_change = Some(std::time::Instant::now());
+                        self.set_performance_mode(&mode);
+                    }
+                }
+                api::ApiCommand::ApplyProfile(name) => {
+                    match self.profiles.iter().position(|(profile, _)| *profile == name) {
+                        Some(index) => self.apply_named_profile(index, None),
+                        None => self.set_error_message(t!("messages.api_unknown_profile", name)),
+                    }
                 }
             }
         }
+    }
+
+    /// Mirror the available modes, current mode and fan speed into the tray menu and tooltip
+    fn sync_tray(&mut self) {
+        let Some(ref mut tray) = self.tray else {
+            return;
+        };
+
+        let modes: Vec<String> = if self.device.is_some() {
+            self.available_performance_modes
+                .iter()
+                .map(|&mode| Self::perf_mode_to_string(mode))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let tooltip = if self.device.is_none() {
+            APP_NAME.to_string()
+        } else {
+            match self.status.fan_actual_rpm.or(self.status.fan_rpm) {
+                Some(rpm) => {
+                    format!("{} - {}, {} RPM", APP_NAME, self.status.performance_mode, rpm)
+                }
+                None => format!("{} - {}", APP_NAME, self.status.performance_mode),
+            }
+        };
 
-        self.update_stored_device_state();
+        tray.sync(&modes, &self.status.performance_mode, &tooltip);
+    }
+
+    /// Record the current device state into the profile of the active power source.
+    fn capture_active_profile(&mut self) {
+        self.update_stored_device_state(Self::store_active_power_profile);
+    }
 
-        self.sync_ui_with_device_state();
+    /// Save the stored device state into the profile of the active power source
+    fn store_active_power_profile(&mut self) {
+        let ac = self.ac_power;
+        if let Some(current_state) = self.power_profile_from_device(ac) {
+            let profile = if ac { &mut self.ac_profile } else { &mut self.battery_profile };
+            if *profile != current_state {
+                *profile = current_state;
+                self.save_config();
+            }
+        }
+    }
+
+    /// The stored device state as it would be saved into the AC (`ac`) or Battery profile
+    fn power_profile_from_device(&self, ac: bool) -> Option<CompleteDeviceState> {
+        let mut current_state = self.device_state.clone()?;
+        // Max fan is a temporary burst; keep the fan settings it will return to
+        if let Some(restore) = self.max_fan_restore {
+            current_state.fan_mode = restore.fan_mode;
+            (current_state.fan_rpm, current_state.fan2_rpm) = match restore.fan_mode {
+                FanMode::Manual => (Some(restore.rpm), restore.rpm2),
+                FanMode::Auto => (None, None),
+            };
+        }
+        current_state.keep_unread_from(if ac { &self.ac_profile } else { &self.battery_profile });
+        Some(current_state)
     }
 
-    fn apply_profile(&self, device: &Device, profile: &CompleteDeviceState) -> Result<()> {
-        command::set_perf_mode(device, profile.perf_mode)?;
+    /// Clone the current settings into the profile of the other power source
+    fn copy_to_other_power_profile(&mut self) {
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+        self.update_stored_device_state(|app| {
+            let target_ac = !app.ac_power;
+            let Some(state) = app.power_profile_from_device(target_ac) else {
+                app.set_error_message(t!("messages.profile_read_failed").to_string());
+                return;
+            };
+            if target_ac {
+                app.ac_profile = state;
+            } else {
+                app.battery_profile = state;
+            }
+            app.edited_power_profile = Some(target_ac);
+            app.save_config();
+            let profile = if target_ac { t!("profiles.ac") } else { t!("profiles.battery") };
+            app.set_status_message(t!("messages.copied_to_power_profile", profile));
+        });
+    }
+
+    fn auto_switch_profile(&mut self) {
+        if self.device.is_none()
+            || !self.automatic_writes_allowed()
+            || !self.settings.auto_switch.enabled
+        {
+            return;
+        }
+        if self.edited_power_profile == Some(self.ac_power) {
+            self.edited_power_profile = None;
+        }
+        // Choose which saved profile to apply based on current AC state.
+        let profile =
+            if self.ac_power { self.ac_profile.clone() } else { self.battery_profile.clone() };
+        let profile_name = if self.ac_power { "AC" } else { "Battery" };
+        let label = if self.ac_power { t!("profiles.ac") } else { t!("profiles.battery") };
+        let scope = if self.ac_power {
+            self.settings.auto_switch.ac
+        } else {
+            self.settings.auto_switch.battery
+        };
+        let lights = self.battery_lights_override();
+
+        self.start_profile_job(ProfileJob {
+            profile,
+            scope: Some(scope),
+            success_message: t!("messages.auto_switched", label),
+            error_prefix: t!("messages.auto_switch_failed", label),
+            // No toast before the startup power read has settled (it is not a real transition)
+            notification: (self.settings.switch_notifications && self.init_power_read)
+                .then(|| t!("messages.switched_notification", label)),
+            undo: false,
+            restores_lights: self.ac_power && lights.is_some(),
+            lights,
+            profile_name: Some(profile_name.to_string()),
+        });
+    }
+
+    /// Lights to write with an AC/Battery switch: off when unplugging (remembering the
+    /// current lights), the remembered lights when AC returns. Those stay remembered until
+    /// `process_profile_apply` sees them written.
+    fn battery_lights_override(&mut self) -> Option<config::LightsState> {
+        let auto_switch = &mut self.settings.auto_switch;
+        if self.ac_power {
+            return auto_switch.saved_lights;
+        }
+        if !auto_switch.battery_lights_off {
+            return None;
+        }
+
+        // Already off from an earlier switch (e.g. a restart on battery); keep the original
+        if auto_switch.saved_lights.is_none() {
+            let current = self.device_state.as_ref()?;
+            auto_switch.saved_lights = Some(config::LightsState {
+                keyboard_brightness: current.keyboard_brightness,
+                logo_mode: current.logo_mode.filter(|_| auto_switch.battery_logo_off),
+            });
+            self.save_config();
+        }
+        Some(config::LightsState {
+            keyboard_brightness: ui::lighting::step_index_to_raw_brightness(0),
+            logo_mode: self.settings.auto_switch.battery_logo_off.then_some(LogoMode::Off),
+        })
+    }
+
+    /// Write a profile on a worker thread; a request made while one is running replaces
+    /// any earlier queued request and starts once the running one finishes
+    fn start_profile_job(&mut self, mut job: ProfileJob) {
+        if self.blocked_by_monitor_mode() {
+            return;
+        }
+        job.profile.clamp_fan_rpm(&self.fan_rpm_range);
+        if self.profile_apply.is_some() {
+            self.queued_profile_job = Some(job);
+            return;
+        }
+
+        // Runs between the worker's reads; UI polling pauses until it reports back
+        let worker_job = job.clone();
+        let plan = self.read_plan();
+        let receiver = match &self.device_poller {
+            Some(poller) => poller.run(move |device| {
+                let result = worker_job.run(device);
+                Ok(ProfileOutcome { result, readout: DeviceReadout::read(device, plan) })
+            }),
+            None => {
+                let (sender, receiver) = mpsc::channel();
+                let _ = sender.send(Err(anyhow::anyhow!("Device worker not running")));
+                receiver
+            }
+        };
+        self.profile_apply = Some((job, receiver));
+        self.set_status_message(t!("messages.applying_profile").to_string());
+    }
+
+    /// Handle a finished profile write, then start the queued one if any
+    fn process_profile_apply(&mut self) {
+        let Some((_, receiver)) = &self.profile_apply else {
+            return;
+        };
+        let (result, readout) = match receiver.try_recv() {
+            Ok(Ok(ProfileOutcome { result, readout })) => (result, Some(readout)),
+            Ok(Err(e)) => (Err(e), None),
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                (Err(anyhow::anyhow!("Profile worker stopped unexpectedly")), None)
+            }
+        };
+        let Some((job, _)) = self.profile_apply.take() else {
+            return;
+        };
+
+        // A newer request supersedes this one; only its outcome is reported
+        if let Some(next) = self.queued_profile_job.take() {
+            self.start_profile_job(next);
+            return;
+        }
+
+        if let (true, Some(readout)) = (self.device.is_some(), readout) {
+            if job.undo {
+                // Forget the undone state so it is not pushed back onto the history
+                self.device_state = None;
+            }
+            let _ = self.apply_readout(readout);
+            if job.undo {
+                self.store_active_power_profile();
+            }
+        }
+
+        if job.restores_lights && result.is_ok() {
+            self.settings.auto_switch.saved_lights = None;
+            self.save_config();
+        }
+
+        self.active_profile = match (&result, job.profile_name) {
+            (Ok(()), Some(name)) => self.device_state.clone().map(|state| (name, state)),
+            _ => None,
+        };
 
-        command::set_logo_mode(device, profile.logo_mode)?;
+        // The slider and the Auto→Manual switch continue from the RPM this profile stores
+        let wrote_fans = job.scope.is_none_or(|scope| scope.fan);
+        if let (Ok(()), true, Some(rpm)) = (&result, wrote_fans, job.profile.fan_rpm) {
+            self.manual_fan_rpm = self.clamp_fan_rpm(rpm);
+            if self.has_second_fan {
+                self.manual_fan2_rpm = self.clamp_fan_rpm(job.profile.fan2_rpm.unwrap_or(rpm));
+            }
+        }
 
-        if let Ok(current_brightness) = command::get_keyboard_brightness(device) {
-            if current_brightness != profile.keyboard_brightness {
-                command::set_keyboard_brightness(device, profile.keyboard_brightness)?;
+        match result {
+            Ok(()) => {
+                self.set_status_message(job.success_message);
+                if let Some(title) = job.notification {
+                    notify::show_toast(
+                        &title,
+                        &t!("messages.performance_mode", self.status.performance_mode),
+                    );
+                }
             }
+            Err(e) => self.set_error_message(t!("messages.failed_with", job.error_prefix, e)),
+        }
+    }
+
+    /// Mode change from the window; asks first when it would briefly reset manual fans
+    fn request_performance_mode(&mut self, mode: String) {
+        if self.settings.confirm_perf_change_manual_fan && self.status.fan_speed == "Manual" {
+            self.pending_perf_change = Some(mode);
         } else {
-            command::set_keyboard_brightness(device, profile.keyboard_brightness)?;
+            self.last_manual_perf_change = Some(std::time::Instant::now());
+            self.set_performance_mode(&mode);
         }
+    }
+
+    fn render_perf_change_confirm(&mut self, ctx: &egui::Context) {
+        use ui::confirm::{render_perf_change_confirm, ConfirmAction};
 
-        command::set_lights_always_on(device, profile.lights_always_on)?;
+        let Some(mode) = self.pending_perf_change.clone() else {
+            return;
+        };
+        let rpm = self.status.fan_rpm.unwrap_or(self.manual_fan_rpm);
+        let confirmed = match render_perf_change_confirm(ctx, &mode, rpm) {
+            ConfirmAction::None => return,
+            ConfirmAction::Confirm => true,
+            ConfirmAction::ConfirmDontAsk => {
+                self.settings.confirm_perf_change_manual_fan = false;
+                self.save_config();
+                true
+            }
+            ConfirmAction::Cancel => false,
+        };
 
-        command::set_battery_care(device, profile.battery_care)?;
+        self.pending_perf_change = None;
+        if confirmed && self.device.is_some() {
+            self.last_manual_perf_change = Some(std::time::Instant::now());
+            self.set_performance_mode(&mode);
+        }
+    }
 
-        Ok(())
+    fn request_reset_to_home(&mut self) {
+        if self.blocked_by_monitor_mode() {
+            return;
+        }
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+        self.pending_home_reset = true;
+    }
+
+    fn render_reset_home_confirm(&mut self, ctx: &egui::Context) {
+        use ui::confirm::{render_reset_home_confirm, ConfirmAction};
+
+        if !self.pending_home_reset {
+            return;
+        }
+        let home = &self.settings.home_state;
+        let summary = t!(
+            "confirm.home_summary",
+            Self::perf_mode_to_string(home.perf_mode),
+            match (home.fan_mode, home.fan_rpm) {
+                (FanMode::Manual, Some(rpm)) => format!("{} RPM", rpm),
+                _ => t!("fan.auto").to_string(),
+            },
+            home.logo_mode.map_or(t!("fan.unavailable"), |mode| match mode {
+                LogoMode::Static => t!("lighting.logo_static"),
+                LogoMode::Breathing => t!("lighting.logo_breathing"),
+                LogoMode::Off => t!("lighting.logo_off"),
+            }),
+            ui::lighting::step_index_to_percent(ui::lighting::raw_brightness_to_step_index(
+                home.keyboard_brightness
+            )),
+            match home.battery_care {
+                Some(BatteryCare::Enable) => t!("confirm.on"),
+                Some(BatteryCare::Disable) => t!("confirm.off"),
+                None => t!("fan.unavailable"),
+            },
+        );
+        match render_reset_home_confirm(ctx, &summary) {
+            ConfirmAction::None => {}
+            ConfirmAction::Confirm | ConfirmAction::ConfirmDontAsk => {
+                self.pending_home_reset = false;
+                self.reset_to_home();
+            }
+            ConfirmAction::Cancel => self.pending_home_reset = false,
+        }
+    }
+
+    /// Whether the device has drifted from the saved profile it was last set to
+    fn profile_dirty(&self) -> bool {
+        // AC/Battery profiles follow every change on their own; only named ones need saving
+        self.active_profile.as_ref().is_some_and(|(name, saved)| {
+            self.profiles.iter().any(|(profile_name, _)| profile_name == name)
+                && self.device_state.as_ref().is_some_and(|state| state != saved)
+        })
+    }
+
+    /// Overwrite the active named profile with the current device state
+    fn save_active_profile(&mut self) {
+        let Some((name, _)) = self.active_profile.clone() else {
+            return;
+        };
+        self.update_stored_device_state(move |app| {
+            let Some(state) = app.device_state.clone() else {
+                app.set_error_message(t!("messages.profile_read_failed").to_string());
+                return;
+            };
+            match app.profiles.iter_mut().find(|(profile_name, _)| *profile_name == name) {
+                Some((_, profile)) => {
+                    *profile = state.clone();
+                    app.active_profile = Some((name.clone(), state));
+                    app.save_config();
+                    app.set_status_message(t!("messages.profile_saved", name));
+                }
+                None => {
+                    app.active_profile = None;
+                    app.set_error_message(t!("messages.profile_missing", name));
+                }
+            }
+        });
+    }
+
+    fn render_profile_switch_confirm(&mut self, ctx: &egui::Context) {
+        use ui::confirm::{render_discard_profile_confirm, ConfirmAction};
+
+        let Some(index) = self.pending_profile_switch else {
+            return;
+        };
+        let Some((name, _)) = self.active_profile.as_ref() else {
+            self.pending_profile_switch = None;
+            self.apply_named_profile(index, None);
+            return;
+        };
+        match render_discard_profile_confirm(ctx, name) {
+            ConfirmAction::None => {}
+            ConfirmAction::Confirm | ConfirmAction::ConfirmDontAsk => {
+                self.pending_profile_switch = None;
+                self.apply_named_profile(index, None);
+            }
+            ConfirmAction::Cancel => self.pending_profile_switch = None,
+        }
+    }
+
+    /// Write the home state; the UI resyncs from the device once the job finishes
+    fn reset_to_home(&mut self) {
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+        let mut profile = self.settings.home_state.clone();
+        profile.clamp_fan_rpm(&self.fan_rpm_range);
+        self.start_profile_job(ProfileJob {
+            profile,
+            scope: None,
+            success_message: t!("messages.home_reset").to_string(),
+            error_prefix: t!("messages.home_reset_failed").to_string(),
+            notification: None,
+            undo: false,
+            lights: None,
+            restores_lights: false,
+            profile_name: None,
+        });
+    }
+
+    fn set_home_to_current(&mut self) {
+        self.update_stored_device_state(|app| match app.device_state.clone() {
+            Some(state) => {
+                app.settings.home_state = state;
+                app.save_config();
+                app.set_status_message(t!("messages.home_set").to_string());
+            }
+            None => app.set_no_device_message(),
+        });
     }
 
     fn set_performance_mode(&mut self, mode: &str) {
+        self.set_performance_mode_then(mode, |_| {});
+    }
+
+    /// Change the performance mode on the worker; `then` runs after a successful change
+    fn set_performance_mode_then(&mut self, mode: &str, then: impl FnOnce(&mut Self) + 'static) {
+        if self.blocked_by_monitor_mode() {
+            return;
+        }
         let perf_mode = match Self::string_to_perf_mode(mode) {
             Some(m) => m,
-            None => return,
+            None => {
+                self.set_error_message(t!("messages.unknown_mode", mode));
+                return;
+            }
         };
 
-        let mut restore_manual = None::<u16>;
-        let mut read_boosts = false;
-        let mut set_mode_ok = false;
-        let mut error_msg: Option<String> = None;
-
-        if let Some(ref device) = self.device {
-            let (current_fan_mode, set_rpm) = Self::read_current_fan_state(device);
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+        // Re-apply the last Custom tune remembered in the active power profile.
+        let profile = if self.ac_power { &self.ac_profile } else { &self.battery_profile };
+        let (profile_cpu_boost, profile_gpu_boost) = (profile.cpu_boost, profile.gpu_boost);
+        let second_fan = self.has_second_fan;
+
+        self.run_device_job(
+            move |device| {
+                let (current_fan_mode, set_rpm, set_rpm2) =
+                    Self::read_current_fan_state(device, second_fan);
+                // Preserve manual fan RPM if user had manual mode before switching; each zone
+                // gets its own RPM back, so independent speeds survive
+                let manual_fan = match (current_fan_mode, set_rpm) {
+                    (FanMode::Manual, Some(rpm)) => Some((rpm, set_rpm2)),
+                    _ => None,
+                };
+                let boosts = (profile_cpu_boost, profile_gpu_boost);
+                let mut failed =
+                    device::set_perf_mode_restoring(device, perf_mode, boosts, manual_fan);
+                if let Some(index) =
+                    failed.iter().position(|(setting, _)| *setting == "performance mode")
+                {
+                    return Err(failed.swap_remove(index).1);
+                }
 
-            match command::set_perf_mode(device, perf_mode) {
-                Ok(_) => {
-                    set_mode_ok = true;
-                    // Preserve manual fan RPM if user had manual mode before switching.
-                    if matches!(current_fan_mode, FanMode::Manual) {
-                        restore_manual = set_rpm;
-                    }
-                    // Only query boost states for Custom (other modes ignore those values).
-                    if mode == "Custom" {
-                        read_boosts = true;
+                let fan_restored = failed.iter().all(|(setting, _)| *setting != "fan");
+                let mut change = PerfModeChange {
+                    restored_rpm: manual_fan.filter(|_| fan_restored),
+                    restore_errors: failed
+                        .into_iter()
+                        .map(|(setting, e)| format!("{} ({})", setting, e))
+                        .collect(),
+                    cpu_boost: None,
+                    gpu_boost: None,
+                };
+                // Only query boost states for Custom (other modes ignore those values).
+                // Populate boost controls so UI reflects actual device values.
+                if perf_mode == PerfMode::Custom {
+                    change.cpu_boost = command::get_cpu_boost(device).ok();
+                    change.gpu_boost = command::get_gpu_boost(device).ok();
+                }
+                Ok(change)
+            },
+            move |app, result| {
+                let change = match result {
+                    Ok(change) => change,
+                    Err(e) => {
+                        app.set_error_message(t!("messages.set_mode_failed", e));
+                        return;
                     }
+                };
+                if !change.restore_errors.is_empty() {
+                    app.set_error_message(t!(
+                        "messages.mode_restore_failed",
+                        change.restore_errors.join("; ")
+                    ));
                 }
-                Err(e) => {
-                    error_msg = Some(format!("Failed to set performance mode: {}", e));
+                if let Some(v) = change.cpu_boost {
+                    app.cpu_boost = v;
                 }
-            }
-
-            if set_mode_ok {
-                if let Some(rpm) = restore_manual {
-                    // Short delays give firmware time to commit mode before restoring manual fan state.
-                    std::thread::sleep(std::time::Duration::from_millis(50));
-                    if command::set_fan_mode(device, FanMode::Manual).is_ok() {
-                        std::thread::sleep(std::time::Duration::from_millis(50));
-                        if command::set_fan_rpm(device, rpm, true).is_err() {
-                            error_msg = Some(
-                                "Failed to restore fan RPM after performance mode change".into(),
-                            );
-                        } else {
-                            restore_manual = Some(rpm);
-                        }
-                    } else {
-                        error_msg = Some(
-                            "Failed to restore manual fan mode after performance mode change"
-                                .into(),
-                        );
-                    }
+                if let Some(v) = change.gpu_boost {
+                    app.gpu_boost = v;
                 }
-                if read_boosts {
-                    // Populate boost controls so UI reflects actual device values.
-                    if let Ok(v) = command::get_cpu_boost(device) {
-                        self.cpu_boost = v;
-                    }
-                    if let Ok(v) = command::get_gpu_boost(device) {
-                        self.gpu_boost = v;
+                app.status.performance_mode = Self::perf_mode_to_string(perf_mode);
+                if let Some((rpm, rpm2)) = change.restored_rpm {
+                    app.status.fan_speed = "Manual".into();
+                    app.status.fan_rpm = Some(rpm);
+                    app.manual_fan_rpm = rpm;
+                    if let Some(rpm2) = rpm2 {
+                        app.status.fan2_rpm = Some(rpm2);
+                        app.manual_fan2_rpm = rpm2;
                     }
                 }
-            }
-        } else {
-            self.set_no_device_message();
-            return;
-        }
-
-        if let Some(msg) = error_msg {
-            self.set_error_message(msg);
-        }
-        if set_mode_ok {
-            self.status.performance_mode = mode.to_string();
-            if let Some(rpm) = restore_manual {
-                self.status.fan_speed = "Manual".into();
-                self.status.fan_rpm = Some(rpm);
-                self.manual_fan_rpm = rpm;
-            }
-            self.set_optional_status_message("Mode changed".into());
-            self.update_stored_device_state();
-        }
+                app.set_optional_status_message(t!("messages.mode_changed").to_string());
+                app.capture_active_profile();
+                then(app);
+            },
+        );
     }
 
     fn render_performance_section(&mut self, ui: &mut egui::Ui) {
         use ui::performance::{render_performance_section, PerformanceAction};
-        let (mut allowed_cpu, mut allowed_gpu, disallowed_pairs) =
-            self.get_descriptor_allowed_boosts();
+        let BoostConstraints {
+            cpu: mut allowed_cpu,
+            gpu: mut allowed_gpu,
+            disallowed_pairs,
+            pairs_confirmed,
+        } = self.boost_constraints.clone();
         let base_cpu = allowed_cpu.clone();
         let base_gpu = allowed_gpu.clone();
 
@@ -776,28 +2454,33 @@ impl RazerGuiApp {
             };
             allowed_gpu.sort_by_key(order_gpu);
         }
+        let thermal_warning = self
+            .temperatures
+            .cpu
+            .filter(|&temp| temp >= f32::from(self.settings.throttle_warning_temp))
+            .map(|temp| t!("performance.thermal_warning", self.settings.temp_unit.format(temp)));
         let action = render_performance_section(
             ui,
             &self.status.performance_mode,
             self.ac_power,
             &self.available_performance_modes,
             &self.base_performance_modes,
-            self.status_messages, // debug flag reuse
+            self.settings.status_messages, // debug flag reuse
             self.cpu_boost,
             self.gpu_boost,
             &allowed_cpu,
             &allowed_gpu,
             &disallowed_pairs,
+            pairs_confirmed,
             &base_cpu,
             &base_gpu,
             self.device.is_none(),
+            thermal_warning.as_deref(),
         );
 
         match action {
             PerformanceAction::None => {}
-            PerformanceAction::SetPerformanceMode(mode) => {
-                self.set_performance_mode(&mode);
-            }
+            PerformanceAction::SetPerformanceMode(mode) => self.request_performance_mode(mode),
             PerformanceAction::ToggleHidden => {
                 let current = ui
                     .ctx()
@@ -805,143 +2488,571 @@ impl RazerGuiApp {
                 ui.ctx().data_mut(|d| d.insert_temp("perf_hidden_show".into(), !current));
             }
             PerformanceAction::SetCpuBoost(boost) => {
-                if self.status.performance_mode == "Custom" {
-                    if let Some(ref device) = self.device {
-                        if let Err(e) = command::set_cpu_boost(device, boost) {
-                            self.set_error_message(format!("Failed CPU boost: {}", e));
-                        } else {
-                            self.cpu_boost = boost;
-                            self.set_optional_status_message(format!("CPU {:?}", boost));
-                        }
-                    }
-                }
+                self.set_cpu_boost(boost, &disallowed_pairs);
             }
             PerformanceAction::SetGpuBoost(boost) => {
-                if self.status.performance_mode == "Custom" {
-                    if let Some(ref device) = self.device {
-                        if let Err(e) = command::set_gpu_boost(device, boost) {
-                            self.set_error_message(format!("Failed GPU boost: {}", e));
-                        } else {
-                            self.gpu_boost = boost;
-                            self.set_optional_status_message(format!("GPU {:?}", boost));
-                        }
-                    }
-                }
+                self.set_gpu_boost(boost, &disallowed_pairs);
             }
         }
     }
 
+    fn set_cpu_boost(&mut self, boost: CpuBoost, disallowed_pairs: &[(CpuBoost, GpuBoost)]) {
+        if self.status.performance_mode != "Custom" {
+            return;
+        }
+        // The firmware rejects these combinations; report it instead of failing silently.
+        if disallowed_pairs.contains(&(boost, self.gpu_boost)) {
+            self.set_error_message(t!(
+                "messages.cpu_boost_not_allowed",
+                format!("{:?}", boost),
+                format!("{:?}", self.gpu_boost)
+            ));
+            return;
+        }
+        self.run_device_command(
+            move |device| command::set_cpu_boost(device, boost),
+            t!("messages.cpu_boost_failed"),
+            move |app| {
+                app.cpu_boost = boost;
+                app.set_optional_status_message(t!(
+                    "messages.cpu_boost_set",
+                    format!("{:?}", boost)
+                ));
+                app.capture_active_profile();
+            },
+        );
+    }
+
+    fn set_gpu_boost(&mut self, boost: GpuBoost, disallowed_pairs: &[(CpuBoost, GpuBoost)]) {
+        if self.status.performance_mode != "Custom" {
+            return;
+        }
+        if disallowed_pairs.contains(&(self.cpu_boost, boost)) {
+            self.set_error_message(t!(
+                "messages.gpu_boost_not_allowed",
+                format!("{:?}", boost),
+                format!("{:?}", self.cpu_boost)
+            ));
+            return;
+        }
+        self.run_device_command(
+            move |device| command::set_gpu_boost(device, boost),
+            t!("messages.gpu_boost_failed"),
+            move |app| {
+                app.gpu_boost = boost;
+                app.set_optional_status_message(t!(
+                    "messages.gpu_boost_set",
+                    format!("{:?}", boost)
+                ));
+                app.capture_active_profile();
+            },
+        );
+    }
+
     fn set_fan_mode(&mut self, mode: &str, rpm: Option<u16>) {
-        if let Some(ref device) = self.device {
-            let result = match mode {
-                "auto" => match command::set_fan_mode(device, FanMode::Auto) {
-                    Ok(_) => {
-                        self.status.fan_speed = "Auto".to_string();
-                        self.status.fan_rpm = None;
-                        Ok(())
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+        // `None` is Auto, otherwise the manual RPM to write
+        let manual_rpm = match mode {
+            "auto" => None,
+            "manual" => {
+                Some(rpm.map_or_else(|| self.default_manual_rpm(), |rpm| self.clamp_fan_rpm(rpm)))
+            }
+            _ => return,
+        };
+
+        self.run_device_job(
+            move |device| match manual_rpm {
+                None => command::set_fan_mode(device, FanMode::Auto),
+                Some(rpm) => command::set_fan_mode(device, FanMode::Manual)
+                    .and_then(|_| command::set_fan_rpm(device, rpm, true)),
+            },
+            move |app, result| {
+                if let Err(e) = result {
+                    app.set_status_message(t!("messages.set_fan_failed", e));
+                    return;
+                }
+                match manual_rpm {
+                    None => {
+                        app.status.fan_speed = "Auto".to_string();
+                        app.status.fan_rpm = None;
                     }
-                    Err(e) => Err(e),
-                },
-                "manual" => match command::set_fan_mode(device, FanMode::Manual) {
-                    Ok(_) => {
-                        let rpm_val = rpm.unwrap_or(2000);
-                        match command::set_fan_rpm(device, rpm_val, true) {
-                            Ok(_) => {
-                                self.status.fan_speed = "Manual".to_string();
-                                self.status.fan_rpm = Some(rpm_val);
-                                Ok(())
-                            }
-                            Err(e) => Err(e),
+                    Some(rpm) => {
+                        app.status.fan_speed = "Manual".to_string();
+                        app.status.fan_rpm = Some(rpm);
+                        if app.has_second_fan {
+                            app.status.fan2_rpm = Some(rpm);
+                            app.manual_fan2_rpm = rpm;
                         }
                     }
-                    Err(e) => Err(e),
-                },
-                _ => return,
-            };
+                }
+                app.set_optional_status_message(match manual_rpm {
+                    None => t!("messages.fan_auto").to_string(),
+                    Some(_) => t!("messages.fan_manual").to_string(),
+                });
+                app.capture_active_profile();
+            },
+        );
+    }
 
-            match result {
-                Ok(_) => {
-                    self.set_optional_status_message(format!("Fan set to {} mode", mode));
+    fn set_fan_rpm_only(&mut self, rpm: u16) {
+        self.run_device_command(
+            move |device| command::set_fan_rpm(device, rpm, true),
+            t!("messages.set_rpm_failed"),
+            move |app| {
+                app.status.fan_rpm = Some(rpm);
+                if app.has_second_fan {
+                    app.status.fan2_rpm = Some(rpm);
+                    app.manual_fan2_rpm = rpm;
                 }
-                Err(e) => {
-                    self.set_status_message(format!("Failed to set fan: {}", e));
+                app.set_optional_status_message(t!("messages.rpm_set", rpm));
+                app.capture_active_profile();
+            },
+        );
+    }
+
+    fn set_fan_zone_rpm_only(&mut self, zone: FanZone, rpm: u16) {
+        let zone_number = if zone == FanZone::Zone1 { 1 } else { 2 };
+        self.run_device_command(
+            move |device| command::set_fan_zone_rpm(device, zone, rpm, true),
+            t!("messages.set_rpm_failed"),
+            move |app| {
+                match zone {
+                    FanZone::Zone1 => app.status.fan_rpm = Some(rpm),
+                    FanZone::Zone2 => app.status.fan2_rpm = Some(rpm),
                 }
-            }
+                app.set_optional_status_message(t!("messages.zone_rpm_set", zone_number, rpm));
+                app.capture_active_profile();
+            },
+        );
+    }
+
+    /// Track a power source change. The indicator follows at once; the profile write waits for
+    /// `apply_settled_power_switch` so a flapping charger does not thrash the device.
+    fn handle_power_source(&mut self, ac_power: bool) {
+        if ac_power == self.ac_power {
+            return;
+        }
+        let applied = self.pending_power_switch.map_or(self.ac_power, |(applied, _)| applied);
+        self.ac_power = ac_power;
+        // Flapping back to the source the device already matches needs no write at all
+        self.pending_power_switch =
+            (ac_power != applied).then(|| (applied, std::time::Instant::now()));
+    }
+
+    /// Write the AC/Battery profile once the power source has been stable for a while
+    fn apply_settled_power_switch(&mut self, ctx: &egui::Context) {
+        let Some((_, since)) = self.pending_power_switch else {
+            return;
+        };
+        let elapsed = since.elapsed();
+        if elapsed < POWER_SWITCH_SETTLE {
+            ctx.request_repaint_after(POWER_SWITCH_SETTLE - elapsed);
+            return;
+        }
+        self.pending_power_switch = None;
+
+        if self.last_manual_perf_change.is_some_and(|at| at.elapsed() < POWER_SWITCH_MANUAL_GRACE) {
+            let power = if self.ac_power { t!("profiles.ac") } else { t!("profiles.battery") };
+            self.set_optional_status_message(t!("messages.kept_manual_mode", power));
+            return;
+        }
+        self.auto_switch_profile();
+    }
+
+    /// Apply the profile mapped to a Windows power plan switch
+    fn handle_power_plan(&mut self, plan: power::PowerPlan) {
+        // Windows reports the current plan on registration; only actual switches count
+        let previous = self.power_plan.replace(plan);
+        if previous.is_none_or(|previous| previous == plan)
+            || !self.settings.power_plan.enabled
+            || !self.automatic_writes_allowed()
+        {
+            return;
+        }
+        let Some(name) = self.settings.power_plan.profile_mut(plan).clone() else {
+            return;
+        };
+        match self.profiles.iter().position(|(profile_name, _)| *profile_name == name) {
+            Some(index) => self.apply_named_profile(
+                index,
+                Some(t!("messages.power_plan_applied", plan.label(), name)),
+            ),
+            None => self.set_error_message(t!("messages.power_plan_missing", name, plan.label())),
+        }
+    }
+
+    /// Append an RPM sample, dropping anything outside the sparkline window
+    fn record_fan_rpm_sample(history: &mut VecDeque<(std::time::Instant, u16)>, rpm: u16) {
+        let now = std::time::Instant::now();
+        history.push_back((now, rpm));
+        while history.len() > FAN_RPM_HISTORY_CAPACITY
+            || history
+                .front()
+                .is_some_and(|&(time, _)| now.duration_since(time) > ui::fan::RPM_HISTORY_WINDOW)
+        {
+            history.pop_front();
+        }
+    }
+
+    fn write_manual_fan_rpm(&mut self, zone: FanZone, rpm: u16) {
+        if self.has_second_fan {
+            self.set_fan_zone_rpm_only(zone, rpm);
         } else {
-            self.set_no_device_message();
+            self.set_fan_rpm_only(rpm);
         }
     }
 
-    fn set_fan_rpm_only(&mut self, rpm: u16) {
-        match execute_device_command_simple(
-            self.device.as_ref(),
-            |device| command::set_fan_rpm(device, rpm, true),
-            &format!("Fans RPM set to: {}", rpm),
-            "Failed to set fan RPM",
-        ) {
-            Ok(message) => {
-                self.status.fan_rpm = Some(rpm);
-                self.set_optional_status_message(message);
+    /// Coalesce slider/keyboard changes; only the latest value is written once input settles
+    fn queue_manual_fan_rpm(&mut self, zone: FanZone, rpm: u16) {
+        if let Some((pending_zone, pending_rpm, _)) = self.pending_fan_rpm {
+            if pending_zone == zone && pending_rpm == rpm {
+                return;
+            }
+            if pending_zone != zone {
+                self.write_manual_fan_rpm(pending_zone, pending_rpm);
+            }
+        } else {
+            let written = match zone {
+                FanZone::Zone1 => self.status.fan_rpm,
+                FanZone::Zone2 => self.status.fan2_rpm,
+            };
+            if written == Some(rpm) {
+                return;
             }
-            Err(message) => {
-                self.set_error_message(message);
+        }
+        self.pending_fan_rpm = Some((zone, rpm, std::time::Instant::now()));
+    }
+
+    fn flush_pending_fan_rpm(&mut self, ctx: &egui::Context) {
+        if let Some((zone, rpm, changed_at)) = self.pending_fan_rpm {
+            if changed_at.elapsed() >= FAN_RPM_DEBOUNCE {
+                self.pending_fan_rpm = None;
+                self.write_manual_fan_rpm(zone, rpm);
+            } else {
+                // Idle frames are sparse; come back when the debounce ends
+                ctx.request_repaint_after(FAN_RPM_DEBOUNCE.saturating_sub(changed_at.elapsed()));
             }
         }
     }
 
+    /// Warn once when a fan given a manual RPM stays far below it for `STUCK_FAN_WINDOW`,
+    /// which can mean a failing or blocked fan. Re-arms once the fans catch up.
+    fn check_stuck_fan(&mut self) {
+        let zones = if self.status.fan_speed == "Manual" {
+            vec![
+                (t!("messages.fan"), self.status.fan_rpm, self.status.fan_actual_rpm),
+                (t!("messages.fan2"), self.status.fan2_rpm, self.status.fan2_actual_rpm),
+            ]
+        } else {
+            Vec::new()
+        };
+        // Only a nonzero commanded RPM sets an expectation
+        let commanded: Vec<_> = zones
+            .into_iter()
+            .filter_map(|(name, target, actual)| {
+                Some((name, target.filter(|&rpm| rpm > 0)?, actual))
+            })
+            .collect();
+        if commanded.is_empty() {
+            self.fan_below_target_since = None;
+            self.stuck_fan_reported = false;
+            return;
+        }
+        // Skip a round with a failed read rather than treating it as a stopped fan
+        if commanded.iter().any(|(_, _, actual)| actual.is_none()) {
+            return;
+        }
+
+        let below = commanded.into_iter().find_map(|(name, target, actual)| {
+            let actual = actual.unwrap_or_default();
+            (f32::from(actual) < f32::from(target) * STUCK_FAN_MIN_RATIO)
+                .then_some((name, target, actual))
+        });
+        let Some((name, target, actual)) = below else {
+            self.fan_below_target_since = None;
+            self.stuck_fan_reported = false;
+            return;
+        };
+
+        // Fans need a few seconds to ramp up after a new target
+        let since = *self.fan_below_target_since.get_or_insert_with(std::time::Instant::now);
+        if since.elapsed() >= STUCK_FAN_WINDOW && !self.stuck_fan_reported {
+            self.stuck_fan_reported = true;
+            let message = if actual == 0 {
+                t!("messages.fan_stopped", name, target)
+            } else {
+                t!("messages.fan_below_target", name, actual, target)
+            };
+            utils::log_warning(&message);
+            self.set_error_message(message);
+        }
+    }
+
+    /// The device reports Auto while the app still holds a manual RPM: follow the device
+    fn note_external_fan_auto(&mut self) {
+        self.status.fan_speed = "Auto".to_string();
+        self.status.fan_rpm = None;
+        self.status.fan2_rpm = None;
+        self.set_status_message(t!("messages.fan_auto_externally").to_string());
+        // The power profile keeps its manual RPM; only the live state follows the device
+        self.update_stored_device_state(|_| {});
+    }
+
     fn enforce_manual_fan_rpm(&mut self) {
-        if self.status.fan_speed == "Manual" {
-            if let Some(ref device) = self.device {
+        // Re-reading the device mid-drag would snap the slider back to the old value, and a
+        // write still queued on the worker is newer than anything read back now
+        if self.pending_fan_rpm.is_some()
+            || self.max_fan_restore.is_some()
+            || self.device.is_none()
+            || !self.device_jobs.is_empty()
+            || self.status.fan_speed != "Manual"
+        {
+            return;
+        }
+        // An active fan curve drives the RPM from CPU temperature when a reading exists.
+        let curve_rpm = self
+            .settings
+            .fan_curve
+            .as_ref()
+            .zip(self.temperatures.cpu)
+            .map(|(curve, temp)| self.clamp_fan_rpm(curve.target_rpm(temp)));
+        let second_fan = self.has_second_fan;
+
+        self.run_device_job(
+            move |device| {
+                // Another app (e.g. Synapse) may have put the fans back on Auto since the last
+                // poll; writing an RPM now would fight it, and an unreadable mode is not worth
+                // forcing
+                if command::get_perf_mode(device)?.1 == FanMode::Auto {
+                    return Ok(FanEnforcement::ExternalAuto);
+                }
+                if let Some(rpm) = curve_rpm {
+                    command::set_fan_rpm(device, rpm, true)?;
+                    return Ok(FanEnforcement::Curve(rpm));
+                }
+
                 // Periodically re-set manual RPM (device may drift after perf mode changes).
-                if let Some(current_set_rpm) =
-                    get_fan_rpm_set(device, librazer::types::FanZone::Zone1)
-                {
-                    if let Ok(_) = command::set_fan_rpm(device, current_set_rpm, true) {
-                        self.manual_fan_rpm = current_set_rpm;
-                        self.status.fan_rpm = Some(current_set_rpm);
-                        self.last_fan_enforce_time = std::time::Instant::now();
+                // Each zone keeps its own value so independent settings are not flattened.
+                let zone2_rpm =
+                    if second_fan { get_fan_rpm_set(device, FanZone::Zone2) } else { None };
+                let current_set_rpm = command::get_fan_rpm(device, FanZone::Zone1)?;
+                match zone2_rpm {
+                    Some(rpm2) => {
+                        command::set_fan_zone_rpm(device, FanZone::Zone1, current_set_rpm, true)
+                            .and_then(|_| {
+                                command::set_fan_zone_rpm(device, FanZone::Zone2, rpm2, false)
+                            })
                     }
+                    None => command::set_fan_rpm(device, current_set_rpm, true),
+                }?;
+                Ok(FanEnforcement::Reapplied(current_set_rpm, zone2_rpm))
+            },
+            |app, result| {
+                // A failed read or write is retried on the next tick
+                let Ok(enforcement) = result else {
+                    return;
+                };
+                if app.status.fan_speed != "Manual" {
+                    return;
                 }
-            }
+                match enforcement {
+                    FanEnforcement::ExternalAuto => {
+                        app.note_external_fan_auto();
+                        return;
+                    }
+                    FanEnforcement::Curve(rpm) => {
+                        app.status.fan_rpm = Some(rpm);
+                        if app.has_second_fan {
+                            app.status.fan2_rpm = Some(rpm);
+                        }
+                    }
+                    FanEnforcement::Reapplied(rpm, zone2_rpm) => {
+                        app.manual_fan_rpm = rpm;
+                        app.status.fan_rpm = Some(rpm);
+                        if let Some(rpm2) = zone2_rpm {
+                            app.manual_fan2_rpm = rpm2;
+                            app.status.fan2_rpm = Some(rpm2);
+                        }
+                    }
+                }
+                app.last_fan_enforce_time = std::time::Instant::now();
+            },
+        );
+    }
+
+    fn apply_fan_curve(&mut self, curve: FanCurve) {
+        let initial_rpm = self
+            .temperatures
+            .cpu
+            .map_or(self.manual_fan_rpm, |temp| self.clamp_fan_rpm(curve.target_rpm(temp)));
+        self.fan_curve_draft = curve.points().to_vec();
+        self.settings.fan_curve = Some(curve);
+        self.save_config();
+
+        if self.status.fan_speed == "Manual" {
+            self.set_fan_rpm_only(initial_rpm);
+        } else {
+            self.set_fan_mode("manual", Some(initial_rpm));
+        }
+    }
+
+    fn clear_fan_curve(&mut self) {
+        self.settings.fan_curve = None;
+        self.save_config();
+
+        if self.status.fan_speed == "Manual" {
+            self.set_fan_rpm_only(self.manual_fan_rpm);
+        } else {
+            self.set_fan_mode("manual", Some(self.manual_fan_rpm));
+        }
+    }
+
+    /// Run the fans at full speed, remembering the current fan settings for later
+    fn enable_max_fan(&mut self) {
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+
+        let manual = self.status.fan_speed == "Manual";
+        let restore = MaxFanRestore {
+            firmware_max: self.status.performance_mode == "Custom",
+            fan_mode: if manual { FanMode::Manual } else { FanMode::Auto },
+            rpm: self.status.fan_rpm.unwrap_or(self.manual_fan_rpm),
+            rpm2: self.has_second_fan.then(|| self.status.fan2_rpm.unwrap_or(self.manual_fan2_rpm)),
+        };
+        self.pending_fan_rpm = None;
+
+        let max_rpm = *self.fan_rpm_range.end();
+        let plan = self.read_plan();
+        self.run_device_job(
+            move |device| {
+                if restore.firmware_max {
+                    command::set_max_fan_speed_mode(device, MaxFanSpeedMode::Enable)
+                } else {
+                    command::set_fan_mode(device, FanMode::Manual)
+                        .and_then(|_| command::set_fan_rpm(device, max_rpm, true))
+                }?;
+                Ok(DeviceReadout::read(device, plan))
+            },
+            move |app, result| match result {
+                Ok(readout) => {
+                    app.max_fan_restore = Some(restore);
+                    let _ = app.apply_readout(readout);
+                    app.set_optional_status_message(t!("messages.max_fan_enabled").to_string());
+                }
+                Err(e) => app.set_error_message(t!("messages.max_fan_enable_failed", e)),
+            },
+        );
+    }
+
+    /// Turn Max fan off and put back the fan settings from before it was enabled
+    fn disable_max_fan(&mut self) {
+        let Some(restore) = self.max_fan_restore else {
+            return;
+        };
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+
+        let clear_firmware_max = self.firmware_max_fan_active(restore);
+        let plan = self.read_plan();
+        self.run_device_job(
+            move |device| {
+                if clear_firmware_max {
+                    command::set_max_fan_speed_mode(device, MaxFanSpeedMode::Disable)?;
+                }
+                match restore.fan_mode {
+                    FanMode::Manual => {
+                        device::restore_manual_fan(device, restore.rpm, restore.rpm2)
+                    }
+                    FanMode::Auto => command::set_fan_mode(device, FanMode::Auto),
+                }?;
+                Ok(DeviceReadout::read(device, plan))
+            },
+            move |app, result| match result {
+                Ok(readout) => {
+                    app.max_fan_restore = None;
+                    if restore.fan_mode == FanMode::Manual {
+                        app.manual_fan_rpm = restore.rpm;
+                        if let Some(rpm2) = restore.rpm2 {
+                            app.manual_fan2_rpm = rpm2;
+                        }
+                    }
+                    let _ = app.apply_readout(readout);
+                    app.set_optional_status_message(t!("messages.max_fan_disabled").to_string());
+                }
+                Err(e) => app.set_error_message(t!("messages.max_fan_disable_failed", e)),
+            },
+        );
+    }
+
+    /// Drop Max fan without restoring the old settings (a new fan setting replaces them)
+    fn end_max_fan(&mut self) {
+        let Some(restore) = self.max_fan_restore.take() else {
+            return;
+        };
+        if self.device.is_some() && self.firmware_max_fan_active(restore) {
+            self.run_device_job(
+                |device| command::set_max_fan_speed_mode(device, MaxFanSpeedMode::Disable),
+                |app, result| {
+                    if let Err(e) = result {
+                        app.set_error_message(t!("messages.max_fan_disable_failed", e));
+                    }
+                },
+            );
         }
     }
 
+    /// Whether turning Max fan off must also leave the firmware's max fan speed mode
+    fn firmware_max_fan_active(&self, restore: MaxFanRestore) -> bool {
+        // The firmware mode only exists in Custom; leaving Custom already dropped it
+        restore.firmware_max && self.status.performance_mode == "Custom"
+    }
+
     fn render_fan_section(&mut self, ui: &mut egui::Ui) {
         use ui::fan::{render_fan_section, FanAction};
 
-        let key = egui::Id::new("max_fan_speed_enabled");
-        let mut max_enabled = ui.ctx().data(|d| d.get_temp::<bool>(key).unwrap_or(false));
+        let max_enabled = self.max_fan_restore.is_some();
+        let color_range = match self.settings.fan_color_range {
+            Some([min, max]) if min < max => min..=max,
+            _ => self.fan_color_range.clone(),
+        };
         let (action, new_toggle) = render_fan_section(
             ui,
             &self.status.fan_speed,
             self.status.fan_actual_rpm,
+            self.status.fan2_actual_rpm.filter(|_| self.has_second_fan),
             self.status.fan_rpm,
             &mut self.manual_fan_rpm,
-            self.status_messages,
-            self.status.performance_mode == "Custom",
+            self.has_second_fan.then_some(&mut self.manual_fan2_rpm),
+            self.fan_rpm_range.clone(),
+            self.fan_rpm_range_listed,
+            color_range,
+            self.settings.fan_speed_percent,
+            self.settings.status_messages,
             max_enabled,
+            self.settings.fan_curve.as_ref(),
+            &mut self.fan_curve_draft,
+            self.temperatures,
+            self.settings.temp_unit,
+            &self.fan_rpm_history,
         );
-        if new_toggle != max_enabled && self.status.performance_mode == "Custom" {
-            if let Some(ref device) = self.device {
-                let result = if new_toggle {
-                    command::set_max_fan_speed_mode(device, MaxFanSpeedMode::Enable)
-                } else {
-                    command::set_max_fan_speed_mode(device, MaxFanSpeedMode::Disable)
-                };
-                match result {
-                    Ok(_) => {
-                        max_enabled = new_toggle;
-                        self.set_optional_status_message(if new_toggle {
-                            "Max fan enabled".into()
-                        } else {
-                            "Max fan disabled".into()
-                        });
-                    }
-                    Err(e) => self.set_error_message(format!("Failed to toggle max fan: {}", e)),
-                }
+        if new_toggle != max_enabled {
+            if new_toggle {
+                self.enable_max_fan();
+            } else {
+                self.disable_max_fan();
             }
         }
-        ui.ctx().data_mut(|d| d.insert_temp(key, max_enabled));
+        // Picking a fan mode or speed by hand ends the burst
+        if action != FanAction::None && self.max_fan_restore.is_some() {
+            self.end_max_fan();
+        }
 
         match action {
             FanAction::None => {}
@@ -951,54 +3062,169 @@ impl RazerGuiApp {
             FanAction::SetManualMode(rpm) => {
                 self.set_fan_mode("manual", Some(rpm));
             }
-            FanAction::SetManualRpm(rpm) => {
-                self.set_fan_rpm_only(rpm);
+            FanAction::SetManualRpm(zone, rpm) => {
+                self.pending_fan_rpm = None;
+                self.write_manual_fan_rpm(zone, rpm);
             }
-            FanAction::SliderDragging(_) => {}
+            FanAction::SliderDragging(zone, rpm) => {
+                self.queue_manual_fan_rpm(zone, rpm);
+            }
+            FanAction::ApplyFanCurve(curve) => {
+                self.apply_fan_curve(curve);
+            }
+            FanAction::ClearFanCurve => {
+                self.clear_fan_curve();
+            }
+        }
+
+        self.flush_pending_fan_rpm(ui.ctx());
+    }
+
+    fn set_logo_mode(&mut self, mode: &str) {
+        let logo_mode = match Self::string_to_logo_mode(mode) {
+            Some(mode) => mode,
+            None => return,
+        };
+
+        let mode = mode.to_string();
+        self.run_device_command(
+            move |device| command::set_logo_mode(device, logo_mode),
+            t!("messages.logo_failed"),
+            move |app| {
+                app.set_optional_status_message(t!("messages.logo_set", mode));
+                app.status.logo_mode = mode;
+                app.capture_active_profile();
+            },
+        );
+    }
+
+    fn set_keyboard_color(&mut self, rgb: [u8; 3]) {
+        self.run_device_command(
+            move |device| command::set_keyboard_color(device, rgb),
+            t!("messages.color_failed"),
+            move |app| {
+                app.status.keyboard_color = Some(rgb);
+                app.set_optional_status_message(t!(
+                    "messages.color_set",
+                    format!("#{:02X}{:02X}{:02X}", rgb[0], rgb[1], rgb[2])
+                ));
+                app.capture_active_profile();
+            },
+        );
+    }
+
+    fn set_keyboard_backlight(&mut self, on: bool) {
+        self.run_device_command(
+            move |device| command::set_keyboard_backlight(device, on),
+            t!("messages.backlight_failed"),
+            move |app| {
+                app.status.keyboard_backlight = Some(on);
+                app.set_optional_status_message(
+                    if on { t!("messages.backlight_on") } else { t!("messages.backlight_off") }
+                        .to_string(),
+                );
+                app.capture_active_profile();
+            },
+        );
+    }
+
+    /// Whether a polled brightness may replace the slider value: not while it is being dragged,
+    /// nor right after a write whose value a stale read could flicker back
+    fn brightness_readback_allowed(&self) -> bool {
+        !self.brightness_slider_active
+            && self
+                .last_brightness_write
+                .is_none_or(|at| at.elapsed() >= BRIGHTNESS_READBACK_SUPPRESS)
+    }
+
+    fn set_brightness(&mut self, brightness: u8) {
+        self.last_brightness_write = Some(std::time::Instant::now());
+        // Skip intermediate slider values; the final one is captured on release.
+        let capture = !self.brightness_slider_active;
+        self.run_device_command(
+            move |device| command::set_keyboard_brightness(device, brightness),
+            t!("messages.brightness_failed"),
+            move |app| {
+                let step = ui::lighting::raw_brightness_to_step_index(brightness);
+                app.status.keyboard_brightness = brightness;
+                app.temp_brightness_step = step;
+                app.set_optional_status_message(t!(
+                    "messages.brightness_set",
+                    ui::lighting::step_index_to_percent(step)
+                ));
+                if capture {
+                    app.capture_active_profile();
+                }
+            },
+        );
+    }
+
+    /// Set brightness from a user action, fading to it when enabled
+    fn change_brightness(&mut self, brightness: u8) {
+        let from = self.status.keyboard_brightness;
+        if !self.settings.brightness_fade || self.device.is_none() || from == brightness {
+            self.set_brightness(brightness);
+            return;
+        }
+        // A new target mid-fade continues from the value last written
+        let from = self.brightness_fade.map_or(from, |fade| Self::faded_brightness(&fade));
+        self.brightness_fade = Some(BrightnessFade {
+            from,
+            to: brightness,
+            started: std::time::Instant::now(),
+            steps_done: 0,
+        });
+    }
+
+    /// Quick toggle: dim the keyboard to 0, or bring back the step it had before
+    fn toggle_brightness(&mut self) {
+        // Mid-fade the target is what the slider will end up on
+        let current = self.brightness_fade.map_or(self.status.keyboard_brightness, |fade| fade.to);
+        if current > 0 {
+            self.brightness_restore_step =
+                Some(ui::lighting::raw_brightness_to_step_index(current));
+            self.change_brightness(0);
+        } else {
+            let step =
+                self.brightness_restore_step.take().unwrap_or(ui::lighting::MAX_BRIGHTNESS_STEP);
+            self.change_brightness(ui::lighting::step_index_to_raw_brightness(step));
         }
     }
 
-    fn set_logo_mode(&mut self, mode: &str) {
-        let logo_mode = match Self::string_to_logo_mode(mode) {
-            Some(mode) => mode,
-            None => return,
+    fn faded_brightness(fade: &BrightnessFade) -> u8 {
+        let progress = fade.steps_done as f32 / BRIGHTNESS_FADE_STEPS as f32;
+        (fade.from as f32 + (fade.to as f32 - fade.from as f32) * progress).round() as u8
+    }
+
+    /// Issue the next intermediate write of a running fade; the final step goes through
+    /// `set_brightness` so status, slider step and profile tracking land on the target
+    fn process_brightness_fade(&mut self, ctx: &egui::Context) {
+        let Some(mut fade) = self.brightness_fade else {
+            return;
         };
-
-        match execute_device_command_simple(
-            self.device.as_ref(),
-            |device| command::set_logo_mode(device, logo_mode),
-            &format!("Logo mode set to {}", mode),
-            "Failed to set logo mode",
-        ) {
-            Ok(message) => {
-                self.status.logo_mode = mode.to_string();
-                self.set_optional_status_message(message);
-            }
-            Err(message) => {
-                self.set_error_message(message);
-            }
+        if self.brightness_slider_active || self.device.is_none() {
+            self.brightness_fade = None;
+            return;
         }
-    }
 
-    fn set_brightness(&mut self, brightness: u8) {
-        match execute_device_command_simple(
-            self.device.as_ref(),
-            |device| command::set_keyboard_brightness(device, brightness),
-            &format!(
-                "Brightness set to step {}",
-                ui::lighting::raw_brightness_to_step_index(brightness)
-            ),
-            "Failed to set brightness",
-        ) {
-            Ok(message) => {
-                self.status.keyboard_brightness = brightness;
-                self.temp_brightness_step = ui::lighting::raw_brightness_to_step_index(brightness);
-                self.set_optional_status_message(message);
-            }
-            Err(message) => {
-                self.set_error_message(message);
+        let step_interval = BRIGHTNESS_FADE_DURATION / BRIGHTNESS_FADE_STEPS;
+        let due = (fade.started.elapsed().as_millis() / step_interval.as_millis().max(1)) as u32;
+        if due > fade.steps_done {
+            fade.steps_done = due.min(BRIGHTNESS_FADE_STEPS);
+            if fade.steps_done >= BRIGHTNESS_FADE_STEPS {
+                self.brightness_fade = None;
+                self.set_brightness(fade.to);
+                return;
             }
+            // Intermediate values are cosmetic; a failure shows up on the final write
+            let brightness = Self::faded_brightness(&fade);
+            self.run_device_job(
+                move |device| command::set_keyboard_brightness(device, brightness),
+                |_, _| {},
+            );
+            self.brightness_fade = Some(fade);
         }
+        ctx.request_repaint_after(step_interval);
     }
 
     fn toggle_lights_always_on(&mut self) {
@@ -1008,23 +3234,28 @@ impl RazerGuiApp {
             LightsAlwaysOn::Disable
         };
 
-        if let Some(ref device) = self.device {
-            match command::set_lights_always_on(device, lights_always_on) {
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+        let enabled = self.status.lights_always_on;
+        self.run_device_job(
+            move |device| command::set_lights_always_on(device, lights_always_on),
+            move |app, result| match result {
                 Ok(_) => {
-                    self.set_optional_status_message(format!(
-                        "Keyboard Backlight Always On {}",
-                        if self.status.lights_always_on { "enabled" } else { "disabled" }
-                    ));
-                    self.update_stored_device_state();
+                    app.set_optional_status_message(if enabled {
+                        t!("messages.always_on_enabled").to_string()
+                    } else {
+                        t!("messages.always_on_disabled").to_string()
+                    });
+                    app.capture_active_profile();
                 }
                 Err(e) => {
-                    self.set_status_message(format!("Failed to set lights always on: {}", e));
-                    self.status.lights_always_on = !self.status.lights_always_on;
+                    app.set_status_message(t!("messages.always_on_failed", e));
+                    app.status.lights_always_on = !enabled;
                 }
-            }
-        } else {
-            self.set_no_device_message();
-        }
+            },
+        );
     }
 
     fn render_lighting_section(&mut self, ui: &mut egui::Ui) {
@@ -1033,20 +3264,45 @@ impl RazerGuiApp {
         let action = render_lighting_section(
             ui,
             &self.status.logo_mode,
+            self.logo_supported,
             &mut self.temp_brightness_step,
+            self.status.keyboard_backlight,
+            self.status.keyboard_color.as_mut(),
             &mut self.status.lights_always_on,
         );
 
+        let mut released = false;
         if let Some(active) = action.slider_active {
+            released = self.brightness_slider_active && !active;
             self.brightness_slider_active = active;
+            if released && action.brightness.is_none() && !self.settings.brightness_fade {
+                self.capture_active_profile();
+            }
         }
 
         if let Some(mode) = action.logo_mode {
             self.set_logo_mode(&mode);
         }
 
-        if let Some(brightness) = action.brightness {
-            self.set_brightness(brightness);
+        if let Some(on) = action.keyboard_backlight {
+            self.set_keyboard_backlight(on);
+        }
+
+        if action.brightness_toggle {
+            self.toggle_brightness();
+        }
+
+        // Fading writes once on release instead of following the drag
+        let follow_drag = !self.settings.brightness_fade || !self.brightness_slider_active;
+        if let Some(brightness) = action.brightness.filter(|_| follow_drag) {
+            self.change_brightness(brightness);
+        } else if released && self.settings.brightness_fade {
+            let target = ui::lighting::step_index_to_raw_brightness(self.temp_brightness_step);
+            self.change_brightness(target);
+        }
+
+        if let Some(rgb) = action.keyboard_color {
+            self.set_keyboard_color(rgb);
         }
 
         if action.lights_always_on {
@@ -1058,44 +3314,423 @@ impl RazerGuiApp {
         let battery_care =
             if self.status.battery_care { BatteryCare::Enable } else { BatteryCare::Disable };
 
-        if let Some(ref device) = self.device {
-            match command::set_battery_care(device, battery_care) {
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+        let enabled = self.status.battery_care;
+        self.run_device_job(
+            move |device| command::set_battery_care(device, battery_care),
+            move |app, result| match result {
                 Ok(_) => {
-                    self.set_optional_status_message(format!(
-                        "Battery care {}",
-                        if self.status.battery_care { "enabled" } else { "disabled" }
-                    ));
+                    app.set_optional_status_message(if enabled {
+                        t!("messages.battery_care_enabled").to_string()
+                    } else {
+                        t!("messages.battery_care_disabled").to_string()
+                    });
+                    app.capture_active_profile();
                 }
                 Err(e) => {
-                    self.set_status_message(format!("Failed to set battery care: {}", e));
+                    app.set_status_message(t!("messages.battery_care_failed", e));
                     // Revert the UI change on error
-                    self.status.battery_care = !self.status.battery_care;
+                    app.status.battery_care = !enabled;
                 }
-            }
-        } else {
-            self.set_no_device_message();
-        }
+            },
+        );
     }
 
     fn render_battery_section(&mut self, ui: &mut egui::Ui) {
         use ui::battery::{render_battery_section, BatteryAction};
 
-        let action = render_battery_section(ui, &mut self.status.battery_care);
+        let profile_names: Vec<&str> =
+            self.profiles.iter().map(|(name, _)| name.as_str()).collect();
+        let action = render_battery_section(
+            ui,
+            &mut self.status.battery_care,
+            self.battery_care_supported,
+            self.battery_status.as_ref(),
+            &mut self.settings.low_battery,
+            &profile_names,
+        );
 
         match action {
             BatteryAction::None => {}
             BatteryAction::ToggleBatteryCare => {
                 self.toggle_battery_care();
             }
+            BatteryAction::LowBatteryChanged => {
+                self.save_config();
+            }
+        }
+    }
+
+    fn apply_named_profile(&mut self, index: usize, success_message: Option<String>) {
+        let Some((name, profile)) = self.profiles.get(index).cloned() else {
+            return;
+        };
+        if self.device.is_none() {
+            self.set_no_device_message();
+            return;
+        }
+
+        self.start_profile_job(ProfileJob {
+            profile,
+            scope: None,
+            success_message: success_message
+                .unwrap_or_else(|| t!("messages.profile_applied", name)),
+            error_prefix: t!("messages.profile_apply_failed", name),
+            notification: None,
+            undo: false,
+            lights: None,
+            restores_lights: false,
+            profile_name: Some(name.clone()),
+        });
+    }
+
+    /// Apply the low battery profile once per downward threshold crossing
+    fn check_low_battery(&mut self) {
+        // Charge must recover this far above the threshold before it can trigger again
+        const LOW_BATTERY_HYSTERESIS: u8 = 5;
+
+        let Some(status) = self.battery_status else {
+            return;
+        };
+        let Some(percent) = status.percent else {
+            return;
+        };
+        let threshold = self.settings.low_battery.threshold;
+
+        if status.ac_online || percent >= threshold.saturating_add(LOW_BATTERY_HYSTERESIS) {
+            self.low_battery_applied = false;
+            return;
+        }
+        if percent > threshold || self.low_battery_applied || !self.settings.low_battery.enabled {
+            return;
+        }
+
+        self.low_battery_applied = true;
+        let Some(name) = self.settings.low_battery.profile.clone() else {
+            return;
+        };
+        match self.profiles.iter().position(|(profile_name, _)| *profile_name == name) {
+            Some(index) => self.apply_named_profile(
+                index,
+                Some(t!("messages.low_battery_applied", percent, name)),
+            ),
+            None => self.set_error_message(t!("messages.low_battery_missing", name)),
+        }
+    }
+
+    /// Apply the chosen startup profile once per launch, after any startup AC/Battery switch
+    fn apply_startup_profile(&mut self) {
+        if self.startup_profile_done
+            || !self.fully_initialized
+            || self.device.is_none()
+            || self.loading
+        {
+            return;
+        }
+        self.startup_profile_done = true;
+        let Some(name) = self.settings.startup_profile.clone() else {
+            return;
+        };
+        if self.monitor_only() {
+            self.set_optional_status_message(t!("messages.startup_monitor_only", name));
+            return;
+        }
+        match self.profiles.iter().position(|(profile_name, _)| *profile_name == name) {
+            // Queued behind a running AC/Battery switch, so the startup profile wins
+            Some(index) => {
+                self.apply_named_profile(index, Some(t!("messages.startup_applied", name)))
+            }
+            None => self.set_error_message(t!("messages.startup_missing", name)),
+        }
+    }
+
+    fn export_profiles(&mut self) {
+        let filter = system::dialog::FileFilter {
+            description: t!("messages.profile_file_type"),
+            extension: config::PROFILE_FILE_EXTENSION,
+        };
+        let default_name = format!("profiles.{}", config::PROFILE_FILE_EXTENSION);
+        let Some(path) =
+            system::dialog::save_file(t!("messages.export_title"), &default_name, &filter)
+        else {
+            return;
+        };
+
+        match config::export_profiles(&path, &self.profiles) {
+            Ok(()) => self.set_status_message(t!(
+                "messages.exported",
+                self.profiles.len(),
+                path.display()
+            )),
+            Err(e) => self.set_error_message(t!("messages.export_failed", format!("{:#}", e))),
+        }
+    }
+
+    fn import_profiles(&mut self) {
+        let filter = system::dialog::FileFilter {
+            description: t!("messages.profile_file_type"),
+            extension: config::PROFILE_FILE_EXTENSION,
+        };
+        let Some(path) = system::dialog::open_file(t!("messages.import_title"), &filter) else {
+            return;
+        };
+        let imported = match config::import_profiles(&path) {
+            Ok(imported) => imported,
+            Err(e) => {
+                self.set_error_message(t!("messages.import_failed", format!("{:#}", e)));
+                return;
+            }
+        };
+
+        let mut added = 0;
+        let mut skipped = Vec::new();
+        for (name, mut state) in imported {
+            if !self.profile_supported(&state) {
+                skipped.push(name);
+                continue;
+            }
+            state.clamp_fan_rpm(&self.fan_rpm_range);
+            // Keep existing profiles; give clashing imports a numbered name
+            let mut unique_name = name.trim().to_string();
+            let mut suffix = 2;
+            while unique_name.is_empty() || self.profiles.iter().any(|(n, _)| *n == unique_name) {
+                unique_name = format!("{} ({})", name.trim(), suffix);
+                suffix += 1;
+            }
+            self.profiles.push((unique_name, state));
+            added += 1;
+        }
+        if added > 0 {
+            self.save_config();
+        }
+
+        if skipped.is_empty() {
+            self.set_status_message(t!("messages.imported", added));
+        } else {
+            self.set_error_message(t!(
+                "messages.imported_skipped",
+                added,
+                skipped.len(),
+                skipped.join(", ")
+            ));
+        }
+    }
+
+    /// Whether the profile's performance mode and boosts are valid on the connected device
+    fn profile_supported(&self, state: &CompleteDeviceState) -> bool {
+        if self.device.is_some() && !self.available_performance_modes.contains(&state.perf_mode) {
+            return false;
+        }
+        if state.perf_mode != PerfMode::Custom {
+            return true;
+        }
+        let constraints = &self.boost_constraints;
+        state.cpu_boost.is_none_or(|boost| constraints.cpu.contains(&boost))
+            && state.gpu_boost.is_none_or(|boost| constraints.gpu.contains(&boost))
+            && state
+                .cpu_boost
+                .zip(state.gpu_boost)
+                .is_none_or(|pair| !constraints.disallowed_pairs.contains(&pair))
+    }
+
+    fn render_elevation_banner(&mut self, ui: &mut egui::Ui) {
+        use ui::elevation::{render_elevation_banner, ElevationAction};
+
+        match render_elevation_banner(ui) {
+            ElevationAction::None => {}
+            ElevationAction::Relaunch => match system::elevation::relaunch_elevated() {
+                Ok(()) => {
+                    // The elevated instance takes over; don't hide to the tray on the way out
+                    self.should_quit = true;
+                }
+                Err(e) => self.set_error_message(t!("messages.relaunch_failed", e)),
+            },
+            ElevationAction::Dismiss => self.show_elevation_banner = false,
+            ElevationAction::DontShowAgain => {
+                self.show_elevation_banner = false;
+                self.settings.hide_elevation_warning = true;
+                self.save_config();
+            }
+        }
+        ui.separator();
+    }
+
+    fn render_profiles_section(&mut self, ui: &mut egui::Ui) {
+        use ui::profiles::{render_profiles_section, ProfilesAction};
+
+        let action = render_profiles_section(
+            ui,
+            &self.profiles,
+            &mut self.profile_name_input,
+            &mut self.profile_rename,
+            self.device.is_none(),
+            &mut self.settings.auto_switch,
+            &mut self.settings.power_plan,
+            &mut self.settings.startup_profile,
+            self.ac_power,
+            self.edited_power_profile,
+        );
+
+        match action {
+            ProfilesAction::None => {}
+            ProfilesAction::Create(name) => {
+                self.update_stored_device_state(move |app| {
+                    if let Some(state) = app.device_state.clone() {
+                        app.profiles.push((name.clone(), state.clone()));
+                        app.active_profile = Some((name.clone(), state));
+                        app.profile_name_input.clear();
+                        app.save_config();
+                        app.set_optional_status_message(t!("messages.profile_saved", name));
+                    } else {
+                        app.set_error_message(t!("messages.profile_read_failed").to_string());
+                    }
+                });
+            }
+            ProfilesAction::Rename(index, name) => {
+                let taken =
+                    self.profiles.iter().enumerate().any(|(i, (n, _))| i != index && *n == name);
+                if name.is_empty() || taken {
+                    self.set_error_message(t!("messages.invalid_profile_name", name));
+                } else if let Some(profile) = self.profiles.get_mut(index) {
+                    let old_name = std::mem::replace(&mut profile.0, name.clone());
+                    self.profile_rename = None;
+                    self.save_config();
+                    if let Some((active_name, _)) = self.active_profile.as_mut() {
+                        if *active_name == old_name {
+                            *active_name = name.clone();
+                        }
+                    }
+                    // Keep settings that refer to the profile by name pointing at it
+                    if self.settings.low_battery.profile.as_deref() == Some(old_name.as_str()) {
+                        self.settings.low_battery.profile = Some(name.clone());
+                    }
+                    self.settings.power_plan.rename_profile(&old_name, Some(&name));
+                    if self.settings.startup_profile.as_deref() == Some(old_name.as_str()) {
+                        self.settings.startup_profile = Some(name.clone());
+                    }
+                    self.save_config();
+                }
+            }
+            ProfilesAction::Apply(index) => {
+                if self.profile_dirty() {
+                    self.pending_profile_switch = Some(index);
+                } else {
+                    self.apply_named_profile(index, None);
+                }
+            }
+            ProfilesAction::AutoSwitchChanged => self.save_config(),
+            ProfilesAction::CopyToOtherPowerProfile => self.copy_to_other_power_profile(),
+            ProfilesAction::Export => self.export_profiles(),
+            ProfilesAction::Import => self.import_profiles(),
+            ProfilesAction::Delete(index) => {
+                if index < self.profiles.len() {
+                    let (name, _) = self.profiles.remove(index);
+                    self.profile_rename = None;
+                    self.save_config();
+                    if self.active_profile.as_ref().is_some_and(|(active, _)| *active == name) {
+                        self.active_profile = None;
+                    }
+                    if self.settings.low_battery.profile.as_deref() == Some(name.as_str()) {
+                        self.settings.low_battery.profile = None;
+                    }
+                    self.settings.power_plan.rename_profile(&name, None);
+                    if self.settings.startup_profile.as_deref() == Some(name.as_str()) {
+                        self.settings.startup_profile = None;
+                    }
+                    self.save_config();
+                    self.set_optional_status_message(t!("messages.profile_deleted", name));
+                }
+            }
+        }
+    }
+
+    fn render_app_rules_section(&mut self, ui: &mut egui::Ui) {
+        use ui::app_rules::{render_app_rules_section, AppRulesAction};
+
+        let action = render_app_rules_section(
+            ui,
+            &mut self.settings.app_rules,
+            &mut self.app_rule_input,
+            &self.available_performance_modes,
+            self.last_foreground_exe.as_deref(),
+        );
+        if action == AppRulesAction::Changed {
+            self.save_config();
+        }
+    }
+
+    /// Apply the performance mode of the rule matching the focused application
+    fn check_app_rules(&mut self) {
+        if self.last_app_rule_check.elapsed() < APP_RULE_POLL_INTERVAL {
+            return;
+        }
+        self.last_app_rule_check = std::time::Instant::now();
+
+        let Some(exe) = system::foreground::foreground_process_name() else {
+            return;
+        };
+        // Focus on R-Helper itself means the user is about to change something by hand
+        let own_exe = std::env::current_exe()
+            .ok()
+            .and_then(|path| path.file_name().map(|name| name.to_string_lossy().into_owned()));
+        if own_exe.is_some_and(|own| own.eq_ignore_ascii_case(&exe)) {
+            return;
+        }
+        self.last_foreground_exe = Some(exe.clone());
+
+        let rules = &self.settings.app_rules;
+        if !rules.enabled
+            || self.last_manual_perf_change.is_some_and(|at| at.elapsed() < APP_RULE_MANUAL_GRACE)
+        {
+            return;
+        }
+
+        let target = rules
+            .rules
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(&exe))
+            .map_or(rules.default_mode, |&(_, mode)| mode);
+        if !self.available_performance_modes.contains(&target) {
+            return;
+        }
+
+        let target_name = Self::perf_mode_to_string(target);
+        if self.status.performance_mode != target_name {
+            self.set_performance_mode(&target_name);
+            self.set_optional_status_message(t!("messages.app_focused", exe, target_name));
         }
     }
 }
 
 impl eframe::App for RazerGuiApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        let minimized = ctx.input(|i| i.viewport().minimized.unwrap_or(false));
+        ctx.request_repaint_after(if minimized || self.window_hidden {
+            BACKGROUND_REPAINT_INTERVAL
+        } else {
+            VISIBLE_REPAINT_INTERVAL
+        });
+        // Temperatures are only shown in the window and used by the fan curve
+        self.temp_monitor
+            .set_active((!minimized && !self.window_hidden) || self.settings.fan_curve.is_some());
+        self.temperatures = self.temp_monitor.temperatures();
 
+        self.apply_ui_scale(ctx);
         self.process_background_initialization();
+        self.try_reconnect_device(ctx);
+
+        if self.topmost_pending && self.fully_initialized && ctx.cumulative_pass_nr() > 0 {
+            self.topmost_pending = false;
+            // The compact readout stays on top
+            if !self.settings.compact_mode {
+                ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(
+                    egui::WindowLevel::Normal,
+                ));
+            }
+        }
 
         let hidden_on =
             ctx.data(|d| d.get_temp::<bool>("perf_hidden_show".into()).unwrap_or(false));
@@ -1104,43 +3739,79 @@ impl eframe::App for RazerGuiApp {
                 self.available_performance_modes = PerfMode::iter().collect();
             } else {
                 self.detect_available_performance_modes();
+                self.note_reported_perf_mode();
             }
         }
 
         self.message_manager.update();
+        utils::set_verbose_logging(self.settings.status_messages);
 
-        // When minimized, poll infrequently to catch external performance mode changes
-        let minimized = ctx.input(|i| i.viewport().minimized.unwrap_or(false));
-        if minimized && self.fully_initialized {
-            const PERF_POLL_INTERVAL: f32 = 2.5; // seconds
-            if self.last_perf_poll_time.elapsed().as_secs_f32() >= PERF_POLL_INTERVAL {
-                if let Some(ref device) = self.device {
-                    if let Ok((perf_mode, fan_mode)) = command::get_perf_mode(device) {
-                        let new_mode = Self::perf_mode_to_string(perf_mode).to_string();
-                        if self.status.performance_mode != new_mode {
-                            self.status.performance_mode = new_mode;
-                            let (fan_speed, fan_rpm) =
-                                Self::get_fan_status_from_mode(fan_mode, device);
-                            self.status.fan_speed = fan_speed;
-                            self.status.fan_rpm = fan_rpm;
-                        }
-                    }
-                }
-                self.last_perf_poll_time = std::time::Instant::now();
-            }
+        // Pick up Windows theme changes made while R-Helper was in the background
+        let focused = ctx.input(|i| i.viewport().focused.unwrap_or(false));
+        if focused && !self.window_focused && self.settings.theme == Theme::System {
+            ctx.set_visuals(Theme::System.visuals());
+        }
+        self.window_focused = focused;
+
+        self.process_device_jobs();
+        self.process_device_poll(ctx);
+        self.apply_startup_profile();
+
+        self.process_tray_commands(ctx);
+        self.process_update_check();
+        self.process_api_commands(ctx);
+        self.process_telemetry_pipe();
+        self.process_brightness_fade(ctx);
+        self.process_hotkey(ctx);
+
+        self.process_shortcuts(ctx);
+
+        // Ctrl+Z reverts the last device change unless a text field has focus
+        let undo_shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Z);
+        if !ctx.wants_keyboard_input()
+            && self.profile_apply.is_none()
+            && ctx.input_mut(|i| i.consume_shortcut(&undo_shortcut))
+        {
+            self.undo_last_change();
         }
 
-        // Handle close request from X button
+        // Handle close request from X button (optionally hiding to the tray instead)
         if ctx.input(|i| i.viewport().close_requested()) {
-            self.should_quit = true;
+            let hide_to_tray = self.settings.close_to_tray
+                && self.tray.as_ref().is_some_and(|tray| !tray.quit_requested());
+            if hide_to_tray && !self.should_quit {
+                ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+                tray::hide_window(ctx);
+                self.window_hidden = true;
+            } else {
+                self.should_quit = true;
+            }
+        }
+
+        if !self.should_quit {
+            self.track_window_geometry(ctx);
         }
 
         // Handle quit
         if self.should_quit {
+            self.save_config();
             ctx.send_viewport_cmd(egui::ViewportCommand::Close);
             return;
         }
 
+        self.process_profile_apply();
+
+        // Power notifications are handled every frame (even minimized) so switching is instant
+        if self.fully_initialized && self.device.is_some() && !self.loading {
+            if let Some(ac_power) = self.power_watcher.as_ref().and_then(|w| w.latest()) {
+                self.handle_power_source(ac_power);
+            }
+            if let Some(plan) = self.power_watcher.as_ref().and_then(|w| w.latest_plan()) {
+                self.handle_power_plan(plan);
+            }
+            self.apply_settled_power_switch(ctx);
+        }
+
         // Only update when window is not minimized to save resources
         if !ctx.input(|i| i.viewport().minimized.unwrap_or(false)) {
             // Only do regular updates if fully initialized to avoid slow operations during startup
@@ -1148,48 +3819,31 @@ impl eframe::App for RazerGuiApp {
                 // Auto-refresh device status based on backlight setting
                 const AUTO_REFRESH_INTERVAL: f32 = 0.5;
                 if self.last_refresh_time.elapsed().as_secs_f32() >= AUTO_REFRESH_INTERVAL {
-                    if self.device.is_some() && !self.loading {
-                        // High-frequency AC power detection: switching triggers profile application.
-                        if let Ok(current_ac_power) = get_power_state() {
-                            if current_ac_power != self.ac_power {
-                                self.ac_power = current_ac_power;
-                                self.auto_switch_profile();
-                            }
-                        }
-
-                        if let Some(ref device) = self.device {
-                            self.status.fan_actual_rpm =
-                                get_fan_rpm_actual(device, librazer::types::FanZone::Zone1);
+                    if let Ok(battery_status) = get_battery_status() {
+                        self.battery_status = Some(battery_status);
+                    }
 
-                            let (current_fan_mode, _) = Self::read_current_fan_state(device);
-                            let (fan_speed, _) =
-                                Self::get_fan_status_from_mode(current_fan_mode, device);
-                            self.status.fan_speed = fan_speed;
+                    // Device polling pauses while a profile worker is talking to the device
+                    if self.device.is_some() && !self.loading && self.profile_apply.is_none() {
+                        // Polling fallback when power notifications could not be registered
+                        if self.power_watcher.is_none() {
+                            if let Ok(current_ac_power) = get_power_state() {
+                                self.handle_power_source(current_ac_power);
+                            }
                         }
-
-                        if self.last_fan_enforce_time.elapsed().as_secs_f32() >= 1.0 {
-                            self.enforce_manual_fan_rpm();
+                        // After the AC/Battery switch so a low battery profile wins over it
+                        if self.automatic_writes_allowed() {
+                            self.check_low_battery();
+                            self.check_app_rules();
                         }
 
-                        if let Some(ref device) = self.device {
-                            if !self.brightness_slider_active {
-                                if let Ok(brightness) = command::get_keyboard_brightness(device) {
-                                    self.status.keyboard_brightness = brightness;
-                                    self.temp_brightness_step =
-                                        ui::lighting::raw_brightness_to_step_index(brightness);
-                                }
-                            }
-                        }
+                        // Fan speeds and brightness arrive from the device worker
+                        self.check_stuck_fan();
 
-                        self.sync_other_dynamic_state();
-                        if self.device.is_some() {
-                            if self.last_state_check_time.elapsed().as_secs_f32() >= 3.0 {
-                                if let Err(_e) = self.check_device_state_changes() {
-                                    // Fallback: read full device status instead of minimal subset
-                                    let _ = self.read_device_status();
-                                }
-                                self.last_state_check_time = std::time::Instant::now();
-                            }
+                        if self.automatic_writes_allowed()
+                            && self.last_fan_enforce_time.elapsed().as_secs_f32() >= 1.0
+                        {
+                            self.enforce_manual_fan_rpm();
                         }
                     }
 
@@ -1198,65 +3852,149 @@ impl eframe::App for RazerGuiApp {
             }
         }
         // Enforce a minimum detecting period before showing "No device detected"
-        if self.detecting_device && self.device.is_none() && self.device_detection_done {
-            if std::time::Instant::now() >= self.min_detecting_until {
-                self.detecting_device = false;
+        if self.detecting_device
+            && self.device.is_none()
+            && !self.connecting
+            && self.device_detection_done
+            && std::time::Instant::now() >= self.min_detecting_until
+        {
+            self.detecting_device = false;
+        }
+        if self.settings.compact_mode {
+            let compact = egui::CentralPanel::default().show(ctx, |ui| {
+                ui::compact::render_compact_view(
+                    ui,
+                    &self.status.performance_mode,
+                    &self.status.fan_speed,
+                    self.status.fan_actual_rpm,
+                )
+            });
+            match compact.inner {
+                ui::compact::CompactAction::None => {}
+                ui::compact::CompactAction::CyclePerformanceMode => self.cycle_performance_mode(),
+                ui::compact::CompactAction::Expand => self.set_compact_mode(ctx, false),
             }
+            return;
         }
+
         // (clear_status_message_if_disabled removed)
-        let footer_height = egui::TopBottomPanel::bottom("footer")
-            .show(ctx, |ui| {
-                ui::footer::render_footer(ui, &mut self.status_messages);
-            })
-            .response
-            .rect
-            .height();
+        let footer = egui::TopBottomPanel::bottom("footer").show(ctx, |ui| {
+            ui::footer::render_footer(
+                ui,
+                &mut self.settings,
+                self.tray.is_some(),
+                &self.autostart,
+                self.available_update.as_ref(),
+                self.last_read_latency,
+            )
+        });
+        match footer.inner {
+            ui::footer::FooterAction::None => {}
+            ui::footer::FooterAction::SettingsChanged => {
+                self.message_manager.set_durations(self.settings.message_durations);
+                self.message_manager.set_max_visible(self.settings.visible_messages);
+                ctx.set_visuals(self.settings.theme.visuals());
+                i18n::set_language(self.settings.language);
+                self.save_config();
+            }
+            ui::footer::FooterAction::SetAutostart(enabled) => self.set_autostart(enabled),
+            ui::footer::FooterAction::ResetWindow => self.reset_window(ctx),
+            ui::footer::FooterAction::EnterCompact => self.set_compact_mode(ctx, true),
+            ui::footer::FooterAction::CopyDiagnostics => self.copy_diagnostics(ctx),
+            ui::footer::FooterAction::ResetToHome => self.request_reset_to_home(),
+            ui::footer::FooterAction::SetHomeToCurrent => self.set_home_to_current(),
+            ui::footer::FooterAction::RestoreHomeDefaults => {
+                self.settings.home_state = config::default_home_state();
+                self.save_config();
+                self.set_status_message(t!("messages.home_defaults").to_string());
+            }
+        }
+
+        if self.settings.show_message_history {
+            egui::TopBottomPanel::bottom("message_history").show(ctx, |ui| {
+                ui::history::render_message_history(ui, &self.message_manager);
+            });
+        }
 
-        let central_response = egui::CentralPanel::default().show(ctx, |ui| {
+        egui::CentralPanel::default().show(ctx, |ui| {
             // Header with device name and status messages
-            ui::header::render_header(
+            let refresh_enabled = self.profile_apply.is_none()
+                && self
+                    .last_manual_refresh
+                    .is_none_or(|at| at.elapsed() >= MANUAL_REFRESH_COOLDOWN);
+            let header_action = ui::header::render_header(
                 ui,
                 ctx,
-                self.loading,
+                self.loading || self.profile_apply.is_some(),
                 &self.system_specs,
                 &self.device,
+                self.status.firmware_version.as_deref(),
                 &self.message_manager,
                 self.detecting_device,
+                refresh_enabled,
+                !self.undo_history.is_empty()
+                    && self.profile_apply.is_none()
+                    && !self.monitor_only(),
+                self.settings.manual_control_only,
+                self.monitor_only(),
+                self.active_profile.as_ref().map(|(name, _)| (name.as_str(), self.profile_dirty())),
             );
+            match header_action {
+                ui::header::HeaderAction::None => {}
+                ui::header::HeaderAction::Refresh => self.refresh_device_state(),
+                ui::header::HeaderAction::Undo => self.undo_last_change(),
+                ui::header::HeaderAction::SaveProfile => self.save_active_profile(),
+                ui::header::HeaderAction::RetryDetection => self.retry_device_detection(ctx),
+            }
             ui.separator();
 
-            self.render_performance_section(ui);
-            ui.separator();
+            if self.show_elevation_banner {
+                self.render_elevation_banner(ui);
+            }
 
-            self.render_fan_section(ui);
-            ui.separator();
+            // Scroll so expanding the profiles list never pushes sections out of the fixed window
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                // Monitor mode keeps the readouts but grays out every control
+                ui.add_enabled_ui(!self.monitor_only(), |ui| {
+                    self.render_performance_section(ui);
+                    ui.separator();
 
-            self.render_lighting_section(ui);
-            ui.separator();
+                    self.render_fan_section(ui);
+                    ui.separator();
+
+                    self.render_lighting_section(ui);
+                    ui.separator();
+
+                    self.render_battery_section(ui);
+                    ui.separator();
+
+                    self.render_profiles_section(ui);
+                    ui.separator();
 
-            self.render_battery_section(ui);
+                    self.render_app_rules_section(ui);
+                })
+                .response
+                .on_disabled_hover_text(t!("messages.monitor_only"));
+            });
         });
+        self.render_perf_change_confirm(ctx);
+        self.render_reset_home_confirm(ctx);
+        self.render_profile_switch_confirm(ctx);
         // Discrete height adjustment only when custom/debug controls appear or disappear
         let custom_visible_now = self.device.is_some() && self.status.performance_mode == "Custom";
-        if self.base_window_height == 0.0 {
-            // Capture initial (non-custom) height once
-            self.base_window_height =
-                central_response.response.rect.height() + footer_height + 16.0;
-        }
         if custom_visible_now != self.custom_controls_visible_last {
-            let width = 450.0;
-            if custom_visible_now {
-                // Estimate added height for custom controls (CPU row + GPU row + spacing)
-                let added = 3.0 * ctx.style().spacing.interact_size.y;
-                self.expanded_window_height = Some(self.base_window_height + added);
-                ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(
-                    width,
-                    self.expanded_window_height.unwrap(),
-                )));
-            } else {
+            if let Some(inner) = ctx.input(|i| i.viewport().inner_rect) {
+                // Grow/shrink by the custom controls (CPU row + GPU row + spacing), keeping
+                // whatever size the user resized the window to
+                let added = Self::custom_controls_height(ctx);
+                let height = if custom_visible_now {
+                    inner.height() + added
+                } else {
+                    inner.height() - added
+                };
                 ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(egui::vec2(
-                    width,
-                    self.base_window_height,
+                    inner.width(),
+                    height,
                 )));
             }
             self.custom_controls_visible_last = custom_visible_now;
@@ -1304,18 +4042,38 @@ fn set_windows_app_id() {
 fn set_windows_app_id() {}
 
 fn main() -> Result<(), eframe::Error> {
+    let (monitor_only, minimized) = match cli::run_from_args() {
+        cli::Launch::Gui { monitor_only, minimized } => (monitor_only, minimized),
+        cli::Launch::Exit(exit_code) => std::process::exit(exit_code),
+    };
+
     set_windows_app_id();
-    let initial_height = 500.0;
+    let config = config::load_config();
+    let settings = &config.settings;
+    i18n::set_language(settings.language);
+    let start_hidden = minimized || settings.start_minimized;
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_inner_size(DEFAULT_WINDOW_SIZE)
+        .with_min_inner_size(MIN_WINDOW_SIZE);
+    if let Some(geometry) = settings.window {
+        let geometry = system::display::clamp_to_work_area(geometry);
+        viewport = viewport
+            .with_position([geometry.x, geometry.y])
+            .with_inner_size([geometry.width, geometry.height]);
+    }
+    if settings.compact_mode {
+        viewport =
+            viewport.with_inner_size(COMPACT_WINDOW_SIZE).with_min_inner_size(COMPACT_WINDOW_SIZE);
+    }
     let options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default()
-            .with_inner_size([450.0, initial_height])
-            .with_resizable(false)
+        viewport: viewport
+            .with_resizable(true)
             .with_maximize_button(false)
             .with_fullscreen(false)
             .with_title(APP_NAME)
             .with_icon(load_icon())
             .with_always_on_top()
-            .with_active(true),
+            .with_active(!start_hidden),
         ..Default::default()
     };
 
@@ -1323,17 +4081,51 @@ fn main() -> Result<(), eframe::Error> {
         APP_NAME,
         options,
         Box::new(move |cc| {
-            let ctx = cc.egui_ctx.clone();
-            std::thread::spawn(move || {
-                std::thread::sleep(std::time::Duration::from_millis(500));
-                ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(
-                    egui::WindowLevel::Normal,
-                ));
-            });
-
-            let mut app = RazerGuiApp::new();
-            app.base_window_height = initial_height as f32;
+            let mut app = RazerGuiApp::new(config);
+            app.monitor_only_launch = monitor_only;
+            cc.egui_ctx.set_visuals(app.settings.theme.visuals());
+            match tray::AppTray::new(&cc.egui_ctx, load_icon(), APP_NAME) {
+                Ok(tray) => app.tray = Some(tray),
+                Err(e) => eprintln!("Tray icon unavailable: {}", e),
+            }
+            if start_hidden {
+                app.start_hidden(&cc.egui_ctx);
+            }
+            match hotkey::AppHotkey::new(&cc.egui_ctx, &app.settings.cycle_perf_hotkey) {
+                Ok(hotkey) => app.hotkey = Some(hotkey),
+                Err(e) => eprintln!("Global hotkey unavailable: {}", e),
+            }
+            let repaint_ctx = cc.egui_ctx.clone();
+            app.device_poller =
+                Some(device::DevicePoller::start(EXTERNAL_CHANGE_POLL_INTERVAL, move || {
+                    repaint_ctx.request_repaint()
+                }));
+            let repaint_ctx = cc.egui_ctx.clone();
+            match power::PowerWatcher::start(move || repaint_ctx.request_repaint()) {
+                Ok(watcher) => app.power_watcher = Some(watcher),
+                Err(e) => eprintln!("Power notifications unavailable, polling instead: {}", e),
+            }
             Ok(Box::new(app))
         }),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perf_mode_strings_round_trip() {
+        for mode in PerfMode::iter() {
+            let text = RazerGuiApp::perf_mode_to_string(mode);
+            assert_eq!(RazerGuiApp::string_to_perf_mode(&text), Some(mode), "{}", text);
+        }
+    }
+
+    #[test]
+    fn unknown_perf_mode_string_is_rejected() {
+        for text in ["", "Turbo", "Balanced2", "Custom mode"] {
+            assert_eq!(RazerGuiApp::string_to_perf_mode(text), None, "{:?}", text);
+        }
+    }
+}
@@ -2,8 +2,20 @@
 //!
 //! Provides status and error message handling with fade animations.
 
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
+/// Number of past messages kept for the history panel
+const HISTORY_CAPACITY: usize = 100;
+/// Upper bound for the number of messages stacked in the header
+pub const MAX_STACKED_MESSAGES: usize = 5;
+
+/// How long a message takes to fade out after its display time
+pub const FADE_DURATION: Duration = Duration::from_millis(2000);
+/// Extra time after the fade before a message is dropped, so the last frame renders fully transparent
+const EXPIRY_MARGIN: Duration = Duration::from_millis(100);
+
 // ============================================================================
 // Message Types & Priorities
 // ============================================================================
@@ -26,30 +38,57 @@ pub enum MessagePriority {
     Critical,
 }
 
+/// How long messages stay fully visible before fading, per priority (persisted in settings)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MessageDurations {
+    /// Display time of status messages, in seconds
+    pub normal_secs: f32,
+    /// Display time of error messages, in seconds
+    pub critical_secs: f32,
+}
+
+impl MessageDurations {
+    pub const MIN_SECS: f32 = 1.0;
+    pub const MAX_SECS: f32 = 30.0;
+
+    fn for_priority(&self, priority: &MessagePriority) -> Duration {
+        let secs = match priority {
+            MessagePriority::Normal => self.normal_secs,
+            MessagePriority::Critical => self.critical_secs,
+        };
+        Duration::from_secs_f32(secs.clamp(Self::MIN_SECS, Self::MAX_SECS))
+    }
+}
+
+impl Default for MessageDurations {
+    fn default() -> Self {
+        Self { normal_secs: 3.0, critical_secs: 8.0 }
+    }
+}
+
 /// A user message with metadata for smart display management
 #[derive(Debug, Clone)]
 pub struct UserMessage {
     pub content: String,
     pub message_type: MessageType,
+    pub priority: MessagePriority,
     pub timestamp: Instant,
+    /// Time fully visible before fading; set from the manager's durations when added
     pub duration: Duration,
 }
 
 impl UserMessage {
     /// Create a new user message
     pub fn new(content: String, message_type: MessageType, priority: MessagePriority) -> Self {
-        let duration = match priority {
-            MessagePriority::Normal => Duration::from_secs(3),
-            MessagePriority::Critical => Duration::from_secs(8),
-        };
-
-        Self { content, message_type, timestamp: Instant::now(), duration }
+        let duration = MessageDurations::default().for_priority(&priority);
+        Self { content, message_type, priority, timestamp: Instant::now(), duration }
     }
 
     /// Check if this message has expired
     pub fn is_expired(&self) -> bool {
-        // Allow extra time for fade animation (3 second display + 2.1 second fade)
-        self.timestamp.elapsed() > (self.duration + std::time::Duration::from_millis(2100))
+        // Allow extra time for the fade animation
+        self.timestamp.elapsed() > self.duration + FADE_DURATION + EXPIRY_MARGIN
     }
 
     /// Check if this message should start fading
@@ -71,20 +110,57 @@ impl UserMessage {
 pub struct MessageManager {
     current_message: Option<UserMessage>,
     message_queue: Vec<UserMessage>,
+    history: VecDeque<UserMessage>,
+    durations: MessageDurations,
+    /// Messages shown at once; 1 keeps the single replacing message
+    max_visible: usize,
 }
 
 impl MessageManager {
     /// Create a new message manager
     pub fn new() -> Self {
-        Self { current_message: None, message_queue: Vec::new() }
+        Self {
+            current_message: None,
+            message_queue: Vec::new(),
+            history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            durations: MessageDurations::default(),
+            max_visible: 1,
+        }
     }
 
-    /// Add a new message, overriding current message instantly
-    pub fn add_message(&mut self, message: UserMessage) {
-        // New messages always override current messages for instant display
+    /// Change how many messages are shown at once (clamped to 1..=MAX_STACKED_MESSAGES)
+    pub fn set_max_visible(&mut self, count: usize) {
+        self.max_visible = count.clamp(1, MAX_STACKED_MESSAGES);
+    }
+
+    /// Change display durations; applies to messages added from now on
+    pub fn set_durations(&mut self, durations: MessageDurations) {
+        self.durations = durations;
+    }
+
+    /// Add a new message, overriding current message instantly unless that would hide an error
+    pub fn add_message(&mut self, mut message: UserMessage) {
+        message.duration = self.durations.for_priority(&message.priority);
+
+        // Every message is kept in the history, even if it is replaced before it is seen
+        if self.history.len() == HISTORY_CAPACITY {
+            self.history.pop_front();
+        }
+        self.history.push_back(message.clone());
+
+        // A visible error is never replaced by a less important message; show it afterwards
+        if let Some(current) = &self.current_message {
+            if current.priority > message.priority && !current.should_fade() {
+                self.message_queue.push(message);
+                self.cleanup_queue();
+                return;
+            }
+        }
+
+        // New messages otherwise override current messages for instant display
         // Save current message to queue only if it hasn't started fading yet
         if let Some(current) = &self.current_message {
-            if current.age_seconds() < 3.0 && !current.is_expired() {
+            if !current.should_fade() {
                 self.message_queue.push(current.clone());
             }
         }
@@ -107,6 +183,26 @@ impl MessageManager {
         }
     }
 
+    /// Messages to display, most important first: errors above status messages, newest first
+    /// within a priority. At most one unless stacking was enabled with `set_max_visible`.
+    pub fn visible_messages(&self) -> Vec<&UserMessage> {
+        if self.max_visible == 1 {
+            return self.get_current_message().into_iter().collect();
+        }
+
+        let mut visible: Vec<&UserMessage> =
+            self.history.iter().rev().filter(|message| !message.is_expired()).collect();
+        // Stable sort keeps newest-first order within each priority
+        visible.sort_by(|a, b| b.priority.cmp(&a.priority));
+        visible.truncate(self.max_visible);
+        visible
+    }
+
+    /// Past messages, oldest first
+    pub fn history(&self) -> &VecDeque<UserMessage> {
+        &self.history
+    }
+
     /// Update the message manager (call this each frame)
     pub fn update(&mut self) {
         if let Some(current) = &self.current_message {
@@ -0,0 +1,47 @@
+// Native desktop notifications
+#[cfg(target_os = "windows")]
+use crate::utils::execute_powershell_command;
+
+/// Show a toast notification without blocking the UI thread (Windows only, errors ignored)
+#[cfg(target_os = "windows")]
+pub fn show_toast(title: &str, body: &str) {
+    // Unpackaged apps need a registered AppUserModelID for toasts to appear; PowerShell's is always present.
+    const TOAST_APP_ID: &str =
+        r"{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\WindowsPowerShell\v1.0\powershell.exe";
+
+    let xml = format!(
+        "<toast><visual><binding template=\"ToastGeneric\"><text>{}</text><text>{}</text></binding></visual></toast>",
+        escape_xml(title),
+        escape_xml(body)
+    );
+    let script = format!(
+        r#"
+        [Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] | Out-Null
+        [Windows.Data.Xml.Dom.XmlDocument, Windows.Data.Xml.Dom.XmlDocument, ContentType = WindowsRuntime] | Out-Null
+        $xml = New-Object Windows.Data.Xml.Dom.XmlDocument
+        $xml.LoadXml('{}')
+        $toast = [Windows.UI.Notifications.ToastNotification]::new($xml)
+        [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier('{}').Show($toast)
+        "#,
+        xml.replace('\'', "''"),
+        TOAST_APP_ID
+    );
+
+    std::thread::spawn(move || {
+        if let Err(e) = execute_powershell_command(&script) {
+            eprintln!("Failed to show notification: {}", e);
+        }
+    });
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn show_toast(_title: &str, _body: &str) {}
+
+#[cfg(target_os = "windows")]
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
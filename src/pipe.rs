@@ -0,0 +1,176 @@
+// Telemetry stream for desktop widgets (Rainmeter etc.) on `\\.\pipe\r-helper`.
+//
+// A connected client receives one JSON object per line,
+// `{"perf_mode":"Balanced","fan_rpm":3200,"battery_percent":80,"ac":true}`, at the UI's
+// refresh cadence. One client is served at a time; when it goes away the pipe listens again.
+
+use anyhow::Result;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread::JoinHandle;
+
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub const PIPE_NAME: &str = r"\\.\pipe\r-helper";
+
+/// One line of the stream
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Telemetry {
+    pub perf_mode: String,
+    pub fan_rpm: Option<u16>,
+    pub battery_percent: Option<u8>,
+    pub ac: bool,
+}
+
+pub struct TelemetryPipe {
+    sender: mpsc::Sender<Telemetry>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TelemetryPipe {
+    /// Create the pipe and serve it on a background thread
+    #[cfg(target_os = "windows")]
+    pub fn start() -> Result<Self> {
+        let pipe = server::create()?;
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let thread = std::thread::spawn(move || server::run(pipe, receiver, &thread_stop));
+        Ok(Self { sender, stop, thread: Some(thread) })
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    pub fn start() -> Result<Self> {
+        Err(anyhow::anyhow!("Named pipes are only supported on Windows"))
+    }
+
+    /// Queue a sample; the server thread writes the newest one to the client
+    pub fn publish(&self, telemetry: Telemetry) {
+        let _ = self.sender.send(telemetry);
+    }
+}
+
+impl Drop for TelemetryPipe {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod server {
+    use super::{Telemetry, PIPE_NAME};
+    use anyhow::{Context, Result};
+    use std::fs::File;
+    use std::io::{ErrorKind, Write};
+    use std::mem::ManuallyDrop;
+    use std::os::windows::io::FromRawHandle;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc;
+    use std::time::Duration;
+    use windows::core::HSTRING;
+    use windows::Win32::Foundation::{
+        CloseHandle, ERROR_NO_DATA, ERROR_PIPE_CONNECTED, ERROR_PIPE_LISTENING, HANDLE,
+    };
+    use windows::Win32::Storage::FileSystem::PIPE_ACCESS_OUTBOUND;
+    use windows::Win32::System::Pipes::{
+        ConnectNamedPipe, CreateNamedPipeW, DisconnectNamedPipe, PIPE_NOWAIT, PIPE_READMODE_BYTE,
+        PIPE_REJECT_REMOTE_CLIENTS, PIPE_TYPE_BYTE,
+    };
+
+    /// How long the loop waits for a sample before checking the stop flag and for clients
+    const POLL_INTERVAL: Duration = Duration::from_millis(100);
+    const OUT_BUFFER_BYTES: u32 = 4096;
+
+    /// Owned pipe handle, closed on drop
+    pub struct Pipe(HANDLE);
+
+    // The handle is only used by the server thread after creation
+    unsafe impl Send for Pipe {}
+
+    impl Drop for Pipe {
+        fn drop(&mut self) {
+            unsafe {
+                let _ = DisconnectNamedPipe(self.0);
+                let _ = CloseHandle(self.0);
+            }
+        }
+    }
+
+    pub fn create() -> Result<Pipe> {
+        // Non-blocking so the loop can poll for clients and notice when it should stop
+        let handle = unsafe {
+            CreateNamedPipeW(
+                &HSTRING::from(PIPE_NAME),
+                PIPE_ACCESS_OUTBOUND,
+                PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_NOWAIT | PIPE_REJECT_REMOTE_CLIENTS,
+                1,
+                OUT_BUFFER_BYTES,
+                0,
+                0,
+                None,
+            )
+        };
+        if handle.is_invalid() {
+            return Err(windows::core::Error::from_thread())
+                .with_context(|| format!("Failed to create {}", PIPE_NAME));
+        }
+        Ok(Pipe(handle))
+    }
+
+    pub fn run(pipe: Pipe, receiver: mpsc::Receiver<Telemetry>, stop: &AtomicBool) {
+        // Writes go through std's File; the handle itself stays owned by `pipe`
+        let file = ManuallyDrop::new(unsafe { File::from_raw_handle(pipe.0 .0) });
+        let mut connected = false;
+
+        while !stop.load(Ordering::Relaxed) {
+            let latest = match receiver.recv_timeout(POLL_INTERVAL) {
+                Ok(sample) => Some(receiver.try_iter().last().unwrap_or(sample)),
+                Err(mpsc::RecvTimeoutError::Timeout) => None,
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            };
+
+            if !connected {
+                connected = match unsafe { ConnectNamedPipe(pipe.0, None) } {
+                    Ok(()) => true,
+                    Err(e) if e.code() == ERROR_PIPE_CONNECTED.to_hresult() => true,
+                    Err(e) if e.code() == ERROR_PIPE_LISTENING.to_hresult() => false,
+                    // A client came and went before we noticed; reset for the next one
+                    Err(e) if e.code() == ERROR_NO_DATA.to_hresult() => {
+                        unsafe {
+                            let _ = DisconnectNamedPipe(pipe.0);
+                        }
+                        false
+                    }
+                    Err(e) => {
+                        crate::utils::log_error(&format!("Telemetry pipe stopped: {}", e));
+                        break;
+                    }
+                };
+            }
+
+            let Some(sample) = latest.filter(|_| connected) else {
+                continue;
+            };
+            let Ok(mut line) = serde_json::to_string(&sample) else {
+                continue;
+            };
+            line.push('\n');
+            match (&*file).write_all(line.as_bytes()) {
+                Ok(()) => {}
+                // Client is not reading and the buffer is full; drop this sample
+                Err(e) if e.kind() == ErrorKind::WriteZero => {}
+                Err(e) => {
+                    crate::utils::log_debug(&format!("Telemetry client disconnected: {}", e));
+                    unsafe {
+                        let _ = DisconnectNamedPipe(pipe.0);
+                    }
+                    connected = false;
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,91 @@
 use anyhow::Result;
 
+pub mod watcher;
+pub use watcher::{PowerPlan, PowerWatcher};
+
 #[cfg(target_os = "windows")]
 use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
 
+/// AC and battery information reported by the OS
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BatteryStatus {
+    pub ac_online: bool,
+    /// Remaining charge, `None` when unknown
+    pub percent: Option<u8>,
+    /// Estimated runtime on battery, `None` when unknown or charging
+    pub seconds_remaining: Option<u32>,
+    /// Estimated time until fully charged, `None` when not charging or not yet measurable
+    pub seconds_to_full: Option<u32>,
+    pub charge_state: ChargeState,
+}
+
+/// What the battery is doing right now
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub enum ChargeState {
+    /// No battery, or the OS does not know
+    #[default]
+    Unknown,
+    Charging,
+    /// On AC without taking charge, e.g. held at the battery care limit
+    NotCharging,
+    Full,
+    Discharging,
+    /// Discharging and below the OS "low" level (33%)
+    Low,
+    /// Discharging and below the OS "critical" level (5%)
+    Critical,
+}
+
+/// Derives a charge rate from observed percentage changes while on AC.
+/// `SYSTEM_POWER_STATUS` has no charge rate, so the estimate only appears once the
+/// charge level has moved since charging started.
+#[cfg(target_os = "windows")]
+mod charge_estimate {
+    use std::sync::Mutex;
+    use std::time::Instant;
+
+    struct ChargeSample {
+        /// Level when charging was first observed
+        first_seen: u8,
+        /// Time and level of the first change; `None` until the level moves
+        anchor: Option<(Instant, u8)>,
+    }
+
+    static SAMPLE: Mutex<Option<ChargeSample>> = Mutex::new(None);
+
+    pub fn seconds_to_full(charging: bool, percent: Option<u8>) -> Option<u32> {
+        let mut sample = SAMPLE.lock().ok()?;
+        let percent = match percent {
+            Some(percent) if charging && percent < 100 => percent,
+            _ => {
+                *sample = None;
+                return None;
+            }
+        };
+
+        let state = sample.get_or_insert(ChargeSample { first_seen: percent, anchor: None });
+        match state.anchor {
+            // Start timing at a level boundary so partial percents don't skew the rate
+            None if percent != state.first_seen => {
+                state.anchor = Some((Instant::now(), percent));
+                None
+            }
+            None => None,
+            Some((_, anchor_percent)) if percent < anchor_percent => {
+                *sample = None;
+                None
+            }
+            Some((_, anchor_percent)) if percent == anchor_percent => None,
+            Some((anchor_time, anchor_percent)) => {
+                let per_percent =
+                    anchor_time.elapsed().as_secs_f32() / (percent - anchor_percent) as f32;
+                Some((per_percent * (100 - percent) as f32) as u32)
+            }
+        }
+    }
+}
+
 #[cfg(target_os = "windows")]
 pub fn get_power_state() -> Result<bool> {
     unsafe {
@@ -15,7 +98,95 @@ pub fn get_power_state() -> Result<bool> {
     }
 }
 
+/// Read AC state from sysfs: an `AC*` adapter's `online` flag, else whether a `BAT*`
+/// battery is discharging. Assumes AC when neither is present (desktops, other Unixes).
 #[cfg(not(target_os = "windows"))]
 pub fn get_power_state() -> Result<bool> {
+    use std::fs;
+    use std::path::PathBuf;
+
+    const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+
+    let Ok(entries) = fs::read_dir(POWER_SUPPLY_DIR) else {
+        return Ok(true);
+    };
+    let mut supplies: Vec<(String, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| (entry.file_name().to_string_lossy().into_owned(), entry.path()))
+        .collect();
+    supplies.sort();
+
+    for (_, path) in supplies.iter().filter(|(name, _)| name.starts_with("AC")) {
+        if let Ok(online) = fs::read_to_string(path.join("online")) {
+            return Ok(online.trim() == "1");
+        }
+    }
+
+    for (_, path) in supplies.iter().filter(|(name, _)| name.starts_with("BAT")) {
+        if let Ok(status) = fs::read_to_string(path.join("status")) {
+            return Ok(status.trim() != "Discharging");
+        }
+    }
+
     Ok(true)
 }
+
+#[cfg(target_os = "windows")]
+pub fn get_battery_status() -> Result<BatteryStatus> {
+    // Sentinels documented for SYSTEM_POWER_STATUS
+    const UNKNOWN_PERCENT: u8 = 255;
+    const UNKNOWN_LIFETIME: u32 = u32::MAX;
+    // BatteryFlag bits
+    const LOW_FLAG: u8 = 2;
+    const CRITICAL_FLAG: u8 = 4;
+    const CHARGING_FLAG: u8 = 8;
+    const NO_BATTERY_FLAG: u8 = 128;
+    const UNKNOWN_FLAG: u8 = 255;
+
+    let status = unsafe {
+        let mut status: SYSTEM_POWER_STATUS = std::mem::zeroed();
+        GetSystemPowerStatus(&mut status)?;
+        status
+    };
+
+    let ac_online = status.ACLineStatus == 1;
+    let percent =
+        (status.BatteryLifePercent != UNKNOWN_PERCENT).then_some(status.BatteryLifePercent);
+    let flags = status.BatteryFlag;
+    let charging = ac_online && flags & CHARGING_FLAG != 0;
+    let charge_state = if flags == UNKNOWN_FLAG || flags & NO_BATTERY_FLAG != 0 {
+        ChargeState::Unknown
+    } else if charging {
+        ChargeState::Charging
+    } else if ac_online && percent == Some(100) {
+        ChargeState::Full
+    } else if ac_online {
+        ChargeState::NotCharging
+    } else if flags & CRITICAL_FLAG != 0 {
+        ChargeState::Critical
+    } else if flags & LOW_FLAG != 0 {
+        ChargeState::Low
+    } else {
+        ChargeState::Discharging
+    };
+
+    Ok(BatteryStatus {
+        ac_online,
+        percent,
+        seconds_remaining: (status.BatteryLifeTime != UNKNOWN_LIFETIME)
+            .then_some(status.BatteryLifeTime),
+        seconds_to_full: charge_estimate::seconds_to_full(charging, percent),
+        charge_state,
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn get_battery_status() -> Result<BatteryStatus> {
+    Ok(BatteryStatus {
+        ac_online: true,
+        percent: None,
+        seconds_remaining: None,
+        seconds_to_full: None,
+        charge_state: ChargeState::Unknown,
+    })
+}
@@ -0,0 +1,236 @@
+// Event-driven AC/battery and power plan transitions via a hidden message window
+use anyhow::Result;
+use std::sync::mpsc;
+
+/// Called on the watcher thread after each reported change, e.g. to repaint the UI
+#[cfg(target_os = "windows")]
+type Wake = Box<dyn Fn() + Send + 'static>;
+
+/// Windows power plan personality (the base of the active power scheme)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub enum PowerPlan {
+    HighPerformance,
+    Balanced,
+    PowerSaver,
+}
+
+impl PowerPlan {
+    pub const ALL: [PowerPlan; 3] =
+        [PowerPlan::HighPerformance, PowerPlan::Balanced, PowerPlan::PowerSaver];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PowerPlan::HighPerformance => "High performance",
+            PowerPlan::Balanced => "Balanced",
+            PowerPlan::PowerSaver => "Power saver",
+        }
+    }
+}
+
+/// Receives AC power and power plan changes as Windows reports them, instead of polling
+pub struct PowerWatcher {
+    receiver: mpsc::Receiver<bool>,
+    plan_receiver: mpsc::Receiver<PowerPlan>,
+}
+
+impl PowerWatcher {
+    /// Most recent AC state reported since the last call (non-blocking)
+    pub fn latest(&self) -> Option<bool> {
+        self.receiver.try_iter().last()
+    }
+
+    /// Most recent power plan reported since the last call (non-blocking)
+    pub fn latest_plan(&self) -> Option<PowerPlan> {
+        self.plan_receiver.try_iter().last()
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod window {
+    use super::super::get_power_state;
+    use super::{PowerPlan, Wake};
+    use anyhow::{anyhow, Result};
+    use std::cell::RefCell;
+    use std::sync::mpsc;
+    use windows::core::{w, GUID, PCWSTR};
+    use windows::Win32::Foundation::{HANDLE, HINSTANCE, HWND, LPARAM, LRESULT, WPARAM};
+    use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+    use windows::Win32::System::Power::{RegisterPowerSettingNotification, POWERBROADCAST_SETTING};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, RegisterClassW,
+        DEVICE_NOTIFY_WINDOW_HANDLE, HWND_MESSAGE, MSG, PBT_APMPOWERSTATUSCHANGE,
+        PBT_POWERSETTINGCHANGE, WINDOW_EX_STYLE, WINDOW_STYLE, WM_POWERBROADCAST, WNDCLASSW,
+    };
+
+    const CLASS_NAME: PCWSTR = w!("RHelperPowerWatcher");
+
+    /// GUID_ACDC_POWER_SOURCE (defined here to avoid pulling in Win32_System_SystemServices)
+    const GUID_ACDC_POWER_SOURCE: GUID = GUID::from_u128(0x5d3e9a59_e9d5_4b00_a6bd_ff34ff516548);
+    /// GUID_POWERSCHEME_PERSONALITY and its values, from the same header
+    const GUID_POWERSCHEME_PERSONALITY: GUID =
+        GUID::from_u128(0x245d8541_3943_4422_b025_13a784f679b7);
+    const GUID_MIN_POWER_SAVINGS: GUID = GUID::from_u128(0x8c5e7fda_e8bf_4a96_9a85_a6e23a8c635c);
+    const GUID_TYPICAL_POWER_SAVINGS: GUID =
+        GUID::from_u128(0x381b4222_f694_41f0_9685_ff5bb260df2e);
+    const GUID_MAX_POWER_SAVINGS: GUID = GUID::from_u128(0xa1841308_3541_4fab_bc81_f71556f20b4a);
+
+    struct EventSink {
+        ac: mpsc::Sender<bool>,
+        plan: mpsc::Sender<PowerPlan>,
+        wake: Wake,
+    }
+
+    thread_local! {
+        // The window procedure runs on the thread that created the window
+        static EVENT_SINK: RefCell<Option<EventSink>> = const { RefCell::new(None) };
+    }
+
+    /// Create the message-only window and subscribe it to AC/DC source and power plan changes
+    pub fn create(
+        sender: mpsc::Sender<bool>,
+        plan_sender: mpsc::Sender<PowerPlan>,
+        wake: Wake,
+    ) -> Result<()> {
+        unsafe {
+            let instance = HINSTANCE(GetModuleHandleW(None)?.0);
+            let class = WNDCLASSW {
+                lpfnWndProc: Some(window_proc),
+                hInstance: instance,
+                lpszClassName: CLASS_NAME,
+                ..Default::default()
+            };
+            if RegisterClassW(&class) == 0 {
+                return Err(anyhow!("Failed to register power watcher window class"));
+            }
+
+            let hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE::default(),
+                CLASS_NAME,
+                w!("R-Helper power events"),
+                WINDOW_STYLE::default(),
+                0,
+                0,
+                0,
+                0,
+                Some(HWND_MESSAGE),
+                None,
+                Some(instance),
+                None,
+            )?;
+
+            // Message-only windows miss broadcasts, so register for the power setting directly
+            RegisterPowerSettingNotification(
+                HANDLE(hwnd.0),
+                &GUID_ACDC_POWER_SOURCE,
+                DEVICE_NOTIFY_WINDOW_HANDLE,
+            )?;
+            RegisterPowerSettingNotification(
+                HANDLE(hwnd.0),
+                &GUID_POWERSCHEME_PERSONALITY,
+                DEVICE_NOTIFY_WINDOW_HANDLE,
+            )?;
+        }
+
+        EVENT_SINK.with(|sink| {
+            *sink.borrow_mut() = Some(EventSink { ac: sender, plan: plan_sender, wake })
+        });
+        Ok(())
+    }
+
+    /// Personality carried by a PBT_POWERSETTINGCHANGE, `None` for other settings
+    ///
+    /// # Safety
+    /// `setting` must point to the POWERBROADCAST_SETTING passed with the message
+    unsafe fn power_plan_change(setting: *const POWERBROADCAST_SETTING) -> Option<PowerPlan> {
+        let setting = unsafe { setting.as_ref()? };
+        if setting.PowerSetting != GUID_POWERSCHEME_PERSONALITY
+            || (setting.DataLength as usize) < std::mem::size_of::<GUID>()
+        {
+            return None;
+        }
+        // The GUID value follows the header in place of the one-byte `Data` array
+        let personality = unsafe { std::ptr::read_unaligned(setting.Data.as_ptr().cast::<GUID>()) };
+        match personality {
+            GUID_MIN_POWER_SAVINGS => Some(PowerPlan::HighPerformance),
+            GUID_TYPICAL_POWER_SAVINGS => Some(PowerPlan::Balanced),
+            GUID_MAX_POWER_SAVINGS => Some(PowerPlan::PowerSaver),
+            _ => None,
+        }
+    }
+
+    /// Pump messages for the window until the process exits
+    pub fn run_message_loop() {
+        let mut msg = MSG::default();
+        unsafe {
+            while GetMessageW(&mut msg, None, 0, 0).0 > 0 {
+                DispatchMessageW(&msg);
+            }
+        }
+    }
+
+    unsafe extern "system" fn window_proc(
+        hwnd: HWND,
+        msg: u32,
+        wparam: WPARAM,
+        lparam: LPARAM,
+    ) -> LRESULT {
+        if msg == WM_POWERBROADCAST
+            && matches!(wparam.0 as u32, PBT_APMPOWERSTATUSCHANGE | PBT_POWERSETTINGCHANGE)
+        {
+            let is_setting_change = wparam.0 as u32 == PBT_POWERSETTINGCHANGE;
+            let plan = if is_setting_change {
+                unsafe { power_plan_change(lparam.0 as *const POWERBROADCAST_SETTING) }
+            } else {
+                None
+            };
+            EVENT_SINK.with(|sink| {
+                let sink = sink.borrow();
+                let Some(sink) = sink.as_ref() else {
+                    return;
+                };
+                if let Some(plan) = plan {
+                    let _ = sink.plan.send(plan);
+                } else if let Ok(ac_power) = get_power_state() {
+                    let _ = sink.ac.send(ac_power);
+                }
+                (sink.wake)();
+            });
+            return LRESULT(1);
+        }
+        unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) }
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl PowerWatcher {
+    /// Start listening on a background thread; errors if the notification can't be registered.
+    /// `wake` runs after every change so the owner can pick it up promptly.
+    pub fn start(wake: impl Fn() + Send + 'static) -> Result<Self> {
+        let (sender, receiver) = mpsc::channel();
+        let (plan_sender, plan_receiver) = mpsc::channel();
+        let (ready_sender, ready_receiver) = mpsc::channel();
+        let wake: Wake = Box::new(wake);
+
+        std::thread::spawn(move || {
+            let created = window::create(sender, plan_sender, wake);
+            let ok = created.is_ok();
+            let _ = ready_sender.send(created);
+            if ok {
+                window::run_message_loop();
+            }
+        });
+
+        ready_receiver
+            .recv()
+            .map_err(|_| anyhow::anyhow!("Power watcher thread exited during startup"))??;
+        Ok(Self { receiver, plan_receiver })
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+impl PowerWatcher {
+    pub fn start(_wake: impl Fn() + Send + 'static) -> Result<Self> {
+        Err(anyhow::anyhow!("Power notifications are only supported on Windows"))
+    }
+}
@@ -0,0 +1,127 @@
+// Launch-at-login registration (HKCU Run key)
+use anyhow::Result;
+
+/// State of the Run key entry relative to the running executable
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub enum AutostartState {
+    Disabled,
+    Enabled,
+    /// Registered, but the stored path no longer exists (exe was moved)
+    Stale(String),
+}
+
+#[cfg(target_os = "windows")]
+mod registry {
+    use anyhow::{Context, Result};
+    use windows::core::{w, PCWSTR};
+    use windows::Win32::Foundation::ERROR_FILE_NOT_FOUND;
+    use windows::Win32::System::Registry::{
+        RegDeleteKeyValueW, RegGetValueW, RegSetKeyValueW, HKEY_CURRENT_USER, REG_SZ, RRF_RT_REG_SZ,
+    };
+
+    const RUN_KEY: PCWSTR = w!(r"Software\Microsoft\Windows\CurrentVersion\Run");
+    const VALUE_NAME: PCWSTR = w!("R-Helper");
+
+    /// Command line stored under the Run key, `None` when not registered
+    pub fn read_run_value() -> Result<Option<String>> {
+        unsafe {
+            let mut size = 0u32;
+            let status = RegGetValueW(
+                HKEY_CURRENT_USER,
+                RUN_KEY,
+                VALUE_NAME,
+                RRF_RT_REG_SZ,
+                None,
+                None,
+                Some(&mut size),
+            );
+            if status == ERROR_FILE_NOT_FOUND {
+                return Ok(None);
+            }
+            status.ok().context("Failed to query autostart entry")?;
+
+            let mut buffer = vec![0u16; (size as usize).div_ceil(2)];
+            RegGetValueW(
+                HKEY_CURRENT_USER,
+                RUN_KEY,
+                VALUE_NAME,
+                RRF_RT_REG_SZ,
+                None,
+                Some(buffer.as_mut_ptr().cast()),
+                Some(&mut size),
+            )
+            .ok()
+            .context("Failed to read autostart entry")?;
+
+            let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+            Ok(Some(String::from_utf16_lossy(&buffer[..len])))
+        }
+    }
+
+    pub fn write_run_value(command: &str) -> Result<()> {
+        let data: Vec<u16> = command.encode_utf16().chain(std::iter::once(0)).collect();
+        unsafe {
+            RegSetKeyValueW(
+                HKEY_CURRENT_USER,
+                RUN_KEY,
+                VALUE_NAME,
+                REG_SZ.0,
+                Some(data.as_ptr().cast()),
+                (data.len() * std::mem::size_of::<u16>()) as u32,
+            )
+            .ok()
+            .context("Failed to write autostart entry")
+        }
+    }
+
+    pub fn delete_run_value() -> Result<()> {
+        let status = unsafe { RegDeleteKeyValueW(HKEY_CURRENT_USER, RUN_KEY, VALUE_NAME) };
+        if status == ERROR_FILE_NOT_FOUND {
+            return Ok(());
+        }
+        status.ok().context("Failed to remove autostart entry")
+    }
+}
+
+/// Read the current registration so the UI reflects what Windows will actually do
+#[cfg(target_os = "windows")]
+pub fn get_autostart_state() -> AutostartState {
+    match registry::read_run_value() {
+        Ok(Some(command)) => {
+            let path = command.trim().trim_matches('"').to_string();
+            if std::path::Path::new(&path).exists() {
+                AutostartState::Enabled
+            } else {
+                AutostartState::Stale(path)
+            }
+        }
+        Ok(None) => AutostartState::Disabled,
+        Err(e) => {
+            eprintln!("{}", e);
+            AutostartState::Disabled
+        }
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn get_autostart_state() -> AutostartState {
+    AutostartState::Disabled
+}
+
+/// Register (pointing at the running exe) or unregister launch at login.
+/// Registering again also repairs a stale entry.
+#[cfg(target_os = "windows")]
+pub fn set_autostart(enabled: bool) -> Result<()> {
+    if enabled {
+        let exe = std::env::current_exe()?;
+        registry::write_run_value(&format!("\"{}\"", exe.display()))
+    } else {
+        registry::delete_run_value()
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn set_autostart(_enabled: bool) -> Result<()> {
+    Err(anyhow::anyhow!("Autostart is only supported on Windows"))
+}
@@ -0,0 +1,94 @@
+// Native open/save file dialogs
+use std::path::PathBuf;
+
+/// A file type offered by the dialog, e.g. ("R-Helper profiles", "rhp")
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub struct FileFilter<'a> {
+    pub description: &'a str,
+    pub extension: &'a str,
+}
+
+/// Ask for a path to save to; `None` when cancelled
+pub fn save_file(title: &str, default_name: &str, filter: &FileFilter) -> Option<PathBuf> {
+    show_dialog(title, default_name, filter, true)
+}
+
+/// Ask for an existing file to open; `None` when cancelled
+pub fn open_file(title: &str, filter: &FileFilter) -> Option<PathBuf> {
+    show_dialog(title, "", filter, false)
+}
+
+#[cfg(target_os = "windows")]
+fn show_dialog(
+    title: &str,
+    default_name: &str,
+    filter: &FileFilter,
+    save: bool,
+) -> Option<PathBuf> {
+    use std::os::windows::ffi::OsStringExt;
+    use windows::core::{PCWSTR, PWSTR};
+    use windows::Win32::UI::Controls::Dialogs::{
+        GetOpenFileNameW, GetSaveFileNameW, OFN_FILEMUSTEXIST, OFN_NOCHANGEDIR,
+        OFN_OVERWRITEPROMPT, OFN_PATHMUSTEXIST, OPENFILENAMEW,
+    };
+
+    const MAX_PATH_CHARS: usize = 1024;
+
+    let wide = |text: &str| text.encode_utf16().chain(std::iter::once(0)).collect::<Vec<u16>>();
+    // Pairs of NUL-terminated description/pattern strings, ended by an extra NUL
+    let filter_spec: Vec<u16> = format!(
+        "{} (*.{ext})\0*.{ext}\0All files (*.*)\0*.*\0\0",
+        filter.description,
+        ext = filter.extension
+    )
+    .encode_utf16()
+    .collect();
+    let title = wide(title);
+    let extension = wide(filter.extension);
+
+    let mut path = vec![0u16; MAX_PATH_CHARS];
+    for (slot, unit) in path.iter_mut().zip(default_name.encode_utf16().take(MAX_PATH_CHARS - 1)) {
+        *slot = unit;
+    }
+
+    let flags = if save {
+        OFN_OVERWRITEPROMPT | OFN_PATHMUSTEXIST | OFN_NOCHANGEDIR
+    } else {
+        OFN_FILEMUSTEXIST | OFN_PATHMUSTEXIST | OFN_NOCHANGEDIR
+    };
+    let mut options = OPENFILENAMEW {
+        lStructSize: std::mem::size_of::<OPENFILENAMEW>() as u32,
+        lpstrFilter: PCWSTR(filter_spec.as_ptr()),
+        nFilterIndex: 1,
+        lpstrFile: PWSTR(path.as_mut_ptr()),
+        nMaxFile: path.len() as u32,
+        lpstrTitle: PCWSTR(title.as_ptr()),
+        lpstrDefExt: PCWSTR(extension.as_ptr()),
+        Flags: flags,
+        ..Default::default()
+    };
+
+    let chosen = unsafe {
+        if save {
+            GetSaveFileNameW(&mut options).as_bool()
+        } else {
+            GetOpenFileNameW(&mut options).as_bool()
+        }
+    };
+    if !chosen {
+        return None;
+    }
+
+    let len = path.iter().position(|&unit| unit == 0).unwrap_or(path.len());
+    Some(PathBuf::from(std::ffi::OsString::from_wide(&path[..len])))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn show_dialog(
+    _title: &str,
+    _default_name: &str,
+    _filter: &FileFilter,
+    _save: bool,
+) -> Option<PathBuf> {
+    None
+}
@@ -0,0 +1,50 @@
+// Monitor work-area queries for restoring the window
+use crate::config::WindowGeometry;
+
+/// Move/shrink a saved window rectangle so it lies inside the work area of the nearest
+/// monitor (a window saved on a since-disconnected display would otherwise open off-screen)
+#[cfg(target_os = "windows")]
+pub fn clamp_to_work_area(geometry: WindowGeometry) -> WindowGeometry {
+    use windows::Win32::Foundation::RECT;
+    use windows::Win32::Graphics::Gdi::{
+        GetMonitorInfoW, MonitorFromRect, MONITORINFO, MONITOR_DEFAULTTONEAREST,
+    };
+
+    // Saved values are egui points; monitor APIs work in physical pixels
+    let scale = if geometry.scale > 0.0 { geometry.scale } else { 1.0 };
+    let rect = RECT {
+        left: (geometry.x * scale) as i32,
+        top: (geometry.y * scale) as i32,
+        right: ((geometry.x + geometry.width) * scale) as i32,
+        bottom: ((geometry.y + geometry.height) * scale) as i32,
+    };
+
+    let mut info =
+        MONITORINFO { cbSize: std::mem::size_of::<MONITORINFO>() as u32, ..Default::default() };
+    let found = unsafe {
+        let monitor = MonitorFromRect(&rect, MONITOR_DEFAULTTONEAREST);
+        GetMonitorInfoW(monitor, &mut info).as_bool()
+    };
+    if !found {
+        return geometry;
+    }
+
+    let work = info.rcWork;
+    let width = (rect.right - rect.left).min(work.right - work.left);
+    let height = (rect.bottom - rect.top).min(work.bottom - work.top);
+    let left = rect.left.clamp(work.left, work.right - width);
+    let top = rect.top.clamp(work.top, work.bottom - height);
+
+    WindowGeometry {
+        x: left as f32 / scale,
+        y: top as f32 / scale,
+        width: width as f32 / scale,
+        height: height as f32 / scale,
+        scale,
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn clamp_to_work_area(geometry: WindowGeometry) -> WindowGeometry {
+    geometry
+}
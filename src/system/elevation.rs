@@ -0,0 +1,61 @@
+// Administrator privilege detection and elevated relaunch
+use anyhow::Result;
+
+/// Whether the process token includes the Administrators group (i.e. runs elevated)
+#[cfg(target_os = "windows")]
+pub fn is_elevated() -> bool {
+    use windows::core::BOOL;
+    use windows::Win32::Security::{
+        CheckTokenMembership, CreateWellKnownSid, WinBuiltinAdministratorsSid, PSID,
+        SECURITY_MAX_SID_SIZE,
+    };
+
+    let mut sid = [0u8; SECURITY_MAX_SID_SIZE as usize];
+    let mut sid_size = SECURITY_MAX_SID_SIZE;
+    let sid_ptr = PSID(sid.as_mut_ptr().cast());
+    let mut is_member = BOOL(0);
+    unsafe {
+        CreateWellKnownSid(WinBuiltinAdministratorsSid, None, Some(sid_ptr), &mut sid_size)
+            .and_then(|_| CheckTokenMembership(None, sid_ptr, &mut is_member))
+            .is_ok()
+            && is_member.as_bool()
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn is_elevated() -> bool {
+    true
+}
+
+/// Start another instance through the UAC prompt; the caller exits on success
+#[cfg(target_os = "windows")]
+pub fn relaunch_elevated() -> Result<()> {
+    use windows::core::{w, HSTRING};
+    use windows::Win32::UI::Shell::ShellExecuteW;
+    use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+
+    // Values above 32 mean success; declining the UAC prompt also ends up here as an error
+    const SHELL_EXECUTE_SUCCESS_MIN: isize = 32;
+
+    let exe = std::env::current_exe()?;
+    let args: Vec<String> = std::env::args().skip(1).map(|arg| format!("\"{}\"", arg)).collect();
+    let result = unsafe {
+        ShellExecuteW(
+            None,
+            w!("runas"),
+            &HSTRING::from(exe.as_os_str()),
+            &HSTRING::from(args.join(" ")),
+            None,
+            SW_SHOWNORMAL,
+        )
+    };
+    if result.0 as isize <= SHELL_EXECUTE_SUCCESS_MIN {
+        anyhow::bail!("Elevated launch was cancelled or failed");
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn relaunch_elevated() -> Result<()> {
+    Err(anyhow::anyhow!("Elevated relaunch is only supported on Windows"))
+}
@@ -1,3 +1,12 @@
+pub mod autostart;
+pub mod dialog;
+pub mod display;
+pub mod elevation;
+pub mod foreground;
 pub mod specs;
+pub mod temps;
+pub mod theme;
 
-pub use specs::{get_system_specs, SystemSpecs};
+pub use specs::{get_system_specs, load_cached_specs, update_specs_cache, SystemSpecs};
+pub use temps::{TempMonitor, TempUnit, Temperatures};
+pub use theme::Theme;
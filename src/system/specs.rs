@@ -1,18 +1,50 @@
-use crate::utils::{clean_display_string, execute_powershell_command};
+use crate::config::{self, SpecsCache};
+#[cfg(target_os = "windows")]
+use crate::utils::{clean_display_string, execute_powershell_command_timeout};
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SystemSpecs {
     pub device_model: String,
+    /// `device_model` comes from the firmware because librazer did not recognize the laptop
+    pub device_model_unmatched: bool,
+    /// GPU names, with " (disabled)" appended when Windows reports the device as disabled
     pub gpu_models: Vec<String>,
+    /// Only an integrated GPU is visible, e.g. the dGPU is disabled or hidden by the MUX switch
+    #[serde(default)]
+    pub integrated_gpu_only: bool,
+    /// Other running Razer control software that may overwrite device settings.
+    /// Changes between launches, so it is never cached.
+    #[serde(skip)]
+    pub control_apps: Vec<String>,
 }
 
 impl Default for SystemSpecs {
     fn default() -> Self {
-        Self { device_model: "Unknown".to_string(), gpu_models: vec!["Unknown".to_string()] }
+        Self {
+            device_model: "Unknown".to_string(),
+            device_model_unmatched: false,
+            gpu_models: vec!["Unknown".to_string()],
+            integrated_gpu_only: false,
+            control_apps: Vec::new(),
+        }
     }
 }
 
+/// (process name, display name) of Razer software known to write device settings
+#[cfg(target_os = "windows")]
+const CONTROL_APP_PROCESSES: &[(&str, &str)] = &[
+    ("Razer Synapse Service", "Razer Synapse"),
+    ("Razer Synapse 3", "Razer Synapse"),
+    ("RazerAppEngine", "Razer Synapse 4"),
+    ("RazerCentralService", "Razer Central"),
+];
+
+/// PowerShell spec queries are abandoned after this long
+#[cfg(target_os = "windows")]
+const SPEC_QUERY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
 pub fn get_system_specs(device_name: Option<&str>) -> SystemSpecs {
     let mut specs = SystemSpecs::default();
 
@@ -20,18 +52,54 @@ pub fn get_system_specs(device_name: Option<&str>) -> SystemSpecs {
     if let Some(device) = device_name {
         // Keep only: model + inch size + optional year (e.g., "Razer Blade 16" (2025)")
         specs.device_model = simplify_model_name(device);
+    } else if let Some(model) = get_razer_system_model() {
+        // Unsupported Blades still get a name in the header and bug reports
+        specs.device_model = model;
+        specs.device_model_unmatched = true;
     }
 
     // Fetch GPU info (Windows only); ignore CPU and RAM
     if let Ok(gpus) = get_gpu_info() {
         if !gpus.is_empty() {
+            specs.integrated_gpu_only = gpus.iter().all(|gpu| is_integrated_gpu(gpu));
             specs.gpu_models = gpus;
         }
     }
 
+    if let Ok(apps) = get_control_apps() {
+        specs.control_apps = apps;
+    }
+
     specs
 }
 
+/// Specs saved by a previous launch on this machine, shown until the fresh query finishes.
+/// `None` when there is no cache or the OS build/hardware changed since it was written.
+pub fn load_cached_specs() -> Option<SystemSpecs> {
+    let cache = config::load_specs_cache()?;
+    (cache.fingerprint == machine_fingerprint()).then_some(cache.specs)
+}
+
+/// Save freshly queried specs for the next launch; skipped when the cache already matches
+pub fn update_specs_cache(specs: &SystemSpecs) {
+    let cache = SpecsCache { fingerprint: machine_fingerprint(), specs: specs.clone() };
+    let unchanged = config::load_specs_cache().is_some_and(|cached| {
+        cached.fingerprint == cache.fingerprint && cached.specs == cache.specs
+    });
+    if !unchanged {
+        if let Err(e) = config::save_specs_cache(&cache) {
+            crate::utils::log_debug(&format!("Failed to cache system specs: {}", e));
+        }
+    }
+}
+
+/// Identifies the machine and OS build the cached specs belong to
+fn machine_fingerprint() -> String {
+    let (manufacturer, model) = get_system_manufacturer_model().unwrap_or_default();
+    let processor = std::env::var("PROCESSOR_IDENTIFIER").unwrap_or_default();
+    format!("{}|{}|{}|{}", manufacturer, model, processor, get_os_build().unwrap_or_default())
+}
+
 // Short and robust: keep up to the year if present; otherwise keep up to the inch size after "Blade".
 fn simplify_model_name(name: &str) -> String {
     let s = name.trim();
@@ -58,16 +126,189 @@ fn simplify_model_name(name: &str) -> String {
     s.to_string()
 }
 
+/// Firmware-reported model of this computer, if it was made by Razer
+fn get_razer_system_model() -> Option<String> {
+    let (manufacturer, model) = get_system_manufacturer_model()?;
+    if !manufacturer.to_ascii_lowercase().contains("razer") || model.is_empty() {
+        return None;
+    }
+    if model.to_ascii_lowercase().starts_with("razer") {
+        Some(model)
+    } else {
+        Some(format!("Razer {}", model))
+    }
+}
+
+/// (manufacturer, model) from the SMBIOS data Windows mirrors into the registry
+#[cfg(target_os = "windows")]
+fn get_system_manufacturer_model() -> Option<(String, String)> {
+    use windows::core::w;
+
+    let key = w!(r"HARDWARE\DESCRIPTION\System\BIOS");
+    Some((
+        read_registry_string(key, w!("SystemManufacturer"))?,
+        read_registry_string(key, w!("SystemProductName"))?,
+    ))
+}
+
+/// Windows build number including the update revision, e.g. "26100.4061"
+#[cfg(target_os = "windows")]
+fn get_os_build() -> Option<String> {
+    use windows::core::w;
+    use windows::Win32::System::Registry::{RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_DWORD};
+
+    let key = w!(r"SOFTWARE\Microsoft\Windows NT\CurrentVersion");
+    let build = read_registry_string(key, w!("CurrentBuild"))?;
+    let mut revision = 0u32;
+    let mut size = std::mem::size_of_val(&revision) as u32;
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_LOCAL_MACHINE,
+            key,
+            w!("UBR"),
+            RRF_RT_REG_DWORD,
+            None,
+            Some((&mut revision as *mut u32).cast()),
+            Some(&mut size),
+        )
+    };
+    Some(if status.is_ok() { format!("{}.{}", build, revision) } else { build })
+}
+
+/// A REG_SZ value under HKEY_LOCAL_MACHINE
+#[cfg(target_os = "windows")]
+fn read_registry_string(key: windows::core::PCWSTR, name: windows::core::PCWSTR) -> Option<String> {
+    use windows::Win32::System::Registry::{RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_SZ};
+
+    let mut buffer = [0u16; 256];
+    let mut size = std::mem::size_of_val(&buffer) as u32;
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_LOCAL_MACHINE,
+            key,
+            name,
+            RRF_RT_REG_SZ,
+            None,
+            Some(buffer.as_mut_ptr().cast()),
+            Some(&mut size),
+        )
+    };
+    if status.is_err() {
+        return None;
+    }
+    let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    Some(clean_display_string(&String::from_utf16_lossy(&buffer[..len])))
+}
+
+/// (manufacturer, model) from the kernel's DMI export
+#[cfg(not(target_os = "windows"))]
+fn get_system_manufacturer_model() -> Option<(String, String)> {
+    let read = |name: &str| {
+        std::fs::read_to_string(format!("/sys/class/dmi/id/{}", name))
+            .ok()
+            .map(|value| value.trim().to_string())
+    };
+    Some((read("sys_vendor")?, read("product_name")?))
+}
+
+/// Kernel release, standing in for the Windows build
+#[cfg(not(target_os = "windows"))]
+fn get_os_build() -> Option<String> {
+    std::fs::read_to_string("/proc/sys/kernel/osrelease").ok().map(|value| value.trim().to_string())
+}
+
+/// Suffix for GPUs that are installed but disabled in Device Manager
+const DISABLED_GPU_SUFFIX: &str = " (disabled)";
+
+/// Whether a GPU name belongs to a CPU-integrated graphics adapter
+fn is_integrated_gpu(name: &str) -> bool {
+    let name = name.trim_end_matches(DISABLED_GPU_SUFFIX).to_ascii_lowercase();
+    if name.contains("nvidia") {
+        return false;
+    }
+    // Intel Arc A-series cards are discrete; Core Ultra "Arc Graphics" is integrated
+    let intel_integrated = name.contains("intel") && !name.contains("arc(tm) a");
+    // AMD APUs report e.g. "AMD Radeon(TM) Graphics" or "AMD Radeon 890M Graphics"
+    let amd_integrated = name.contains("radeon") && !name.contains(" rx ");
+    intel_integrated || amd_integrated
+}
+
+/// GPU names via DXGI adapter enumeration, falling back to WMI through PowerShell.
+/// DXGI skips disabled adapters, so an integrated-only result is double-checked with WMI,
+/// which still lists a dGPU that was disabled in Device Manager.
 #[cfg(target_os = "windows")]
 fn get_gpu_info() -> Result<Vec<String>> {
-    let script = "Get-WmiObject -Class Win32_VideoController | Where-Object { $_.Name -notlike '*Virtual*' -and $_.Name -notlike '*Basic*' } | Select-Object -ExpandProperty Name";
-    let output = execute_powershell_command(script)?;
+    match get_gpu_info_dxgi() {
+        Ok(gpu_names) if !gpu_names.is_empty() => {
+            if !gpu_names.iter().all(|gpu| is_integrated_gpu(gpu)) {
+                return Ok(gpu_names);
+            }
+            match get_gpu_info_wmi() {
+                Ok(wmi_names) if wmi_names.len() > gpu_names.len() => Ok(wmi_names),
+                _ => Ok(gpu_names),
+            }
+        }
+        _ => get_gpu_info_wmi(),
+    }
+}
+
+// DXGI lists every hardware adapter (including an idle dGPU on hybrid laptops) in-process,
+// avoiding a PowerShell launch on the startup path.
+#[cfg(target_os = "windows")]
+fn get_gpu_info_dxgi() -> Result<Vec<String>> {
+    use windows::Win32::Graphics::Dxgi::{
+        CreateDXGIFactory1, IDXGIFactory1, DXGI_ADAPTER_FLAG_SOFTWARE,
+    };
+
+    let factory: IDXGIFactory1 = unsafe { CreateDXGIFactory1()? };
+    let mut gpu_names: Vec<String> = Vec::new();
+
+    let mut index = 0;
+    while let Ok(adapter) = unsafe { factory.EnumAdapters1(index) } {
+        index += 1;
+        let desc = unsafe { adapter.GetDesc1()? };
+        if desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 as u32 != 0 {
+            continue;
+        }
+
+        let len = desc.Description.iter().position(|&c| c == 0).unwrap_or(desc.Description.len());
+        let name = clean_display_string(&String::from_utf16_lossy(&desc.Description[..len]));
+        if name.is_empty() || name.contains("Virtual") || name.contains("Basic") {
+            continue;
+        }
+        if !gpu_names.contains(&name) {
+            gpu_names.push(name);
+        }
+    }
 
-    let gpu_names: Vec<String> = output
-        .lines()
-        .map(|line| clean_display_string(line))
-        .filter(|line| !line.is_empty())
-        .collect();
+    Ok(gpu_names)
+}
+
+#[cfg(target_os = "windows")]
+fn get_gpu_info_wmi() -> Result<Vec<String>> {
+    // Config Manager error 22 means the device is disabled
+    const CM_DEVICE_DISABLED: &str = "22";
+
+    let script = "Get-WmiObject -Class Win32_VideoController | Where-Object { $_.Name -notlike '*Virtual*' -and $_.Name -notlike '*Basic*' } | ForEach-Object { \"$($_.Name)|$($_.ConfigManagerErrorCode)\" }";
+    let output = run_spec_query("GPU", script)?;
+
+    let mut gpu_names: Vec<String> = Vec::new();
+    for line in output.lines() {
+        let (name, error_code) = line.rsplit_once('|').unwrap_or((line, ""));
+        let name = clean_display_string(name);
+        if name.is_empty() {
+            continue;
+        }
+        let name = if error_code.trim() == CM_DEVICE_DISABLED {
+            format!("{}{}", name, DISABLED_GPU_SUFFIX)
+        } else {
+            name
+        };
+        // Some drivers register the same adapter twice
+        if !gpu_names.contains(&name) {
+            gpu_names.push(name);
+        }
+    }
 
     if gpu_names.is_empty() {
         Ok(vec!["No discrete GPU detected".to_string()])
@@ -76,7 +317,45 @@ fn get_gpu_info() -> Result<Vec<String>> {
     }
 }
 
+/// Run a spec query under `SPEC_QUERY_TIMEOUT`; some video drivers make WMI hang for a long
+/// time, and the specs should still arrive (with that field left at its default)
+#[cfg(target_os = "windows")]
+fn run_spec_query(what: &str, script: &str) -> Result<String> {
+    execute_powershell_command_timeout(script, SPEC_QUERY_TIMEOUT).inspect_err(|e| {
+        crate::utils::log_warning(&format!("{} query failed: {}", what, e));
+    })
+}
+
 #[cfg(not(target_os = "windows"))]
 fn get_gpu_info() -> Result<Vec<String>> {
     Err(anyhow::anyhow!("System specs detection only supported on Windows"))
 }
+
+/// Display names of running Razer control apps (deduplicated)
+#[cfg(target_os = "windows")]
+fn get_control_apps() -> Result<Vec<String>> {
+    let names: Vec<String> =
+        CONTROL_APP_PROCESSES.iter().map(|(process, _)| format!("'{}'", process)).collect();
+    let script = format!(
+        "Get-Process -Name {} -ErrorAction SilentlyContinue | Select-Object -ExpandProperty ProcessName -Unique",
+        names.join(",")
+    );
+    let output = run_spec_query("control app", &script)?;
+
+    let mut apps: Vec<String> = Vec::new();
+    for line in output.lines().map(str::trim) {
+        if let Some((_, display)) =
+            CONTROL_APP_PROCESSES.iter().find(|(process, _)| *process == line)
+        {
+            if !apps.iter().any(|app| app == display) {
+                apps.push(display.to_string());
+            }
+        }
+    }
+    Ok(apps)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn get_control_apps() -> Result<Vec<String>> {
+    Ok(Vec::new())
+}
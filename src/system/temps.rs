@@ -0,0 +1,198 @@
+// Temperature sensors
+#[cfg(target_os = "windows")]
+use crate::utils::execute_powershell_command_timeout;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How often the background monitor re-reads sensors (PowerShell is too slow for every tick)
+const TEMP_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Longest a single sensor query may take; a hung WMI provider is killed instead of waited on
+#[cfg(target_os = "windows")]
+const TEMP_QUERY_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How long a source that returned nothing is skipped before it is tried again
+/// (e.g. LibreHardwareMonitor started after the app)
+const SOURCE_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Plausible sensor range; anything outside is an unpopulated or misreporting sensor
+#[cfg(target_os = "windows")]
+const VALID_TEMP_RANGE: std::ops::RangeInclusive<f32> = 0.0..=125.0;
+
+/// CPU temperature from the ACPI thermal zones, in °C
+#[cfg(target_os = "windows")]
+fn get_thermal_zone_temp() -> Option<f32> {
+    // ACPI thermal zones report tenths of Kelvin; the performance counter is the
+    // fallback for machines where the WMI class requires elevation.
+    let script = r#"
+        $zones = Get-CimInstance -Namespace root/WMI -ClassName MSAcpi_ThermalZoneTemperature -ErrorAction SilentlyContinue
+        if ($zones) {
+            ($zones | Measure-Object -Property CurrentTemperature -Maximum).Maximum
+        } else {
+            $samples = (Get-Counter '\Thermal Zone Information(*)\High Precision Temperature' -ErrorAction SilentlyContinue).CounterSamples
+            if ($samples) { ($samples | Measure-Object -Property CookedValue -Maximum).Maximum }
+        }
+    "#;
+
+    let output = execute_powershell_command_timeout(script, TEMP_QUERY_TIMEOUT).ok()?;
+    let deci_kelvin: f32 = output.trim().parse().ok()?;
+    let celsius = deci_kelvin / 10.0 - 273.15;
+
+    // Discard obviously bogus readings (unpopulated zones report 0 K or similar)
+    VALID_TEMP_RANGE.contains(&celsius).then_some(celsius)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn get_thermal_zone_temp() -> Option<f32> {
+    None
+}
+
+/// Highest temperature sensor named `sensor_name` under a LibreHardwareMonitor hardware prefix
+#[cfg(target_os = "windows")]
+fn get_lhm_temp(identifier_prefix: &str, sensor_name: &str) -> Option<f32> {
+    let script = format!(
+        "Get-CimInstance -Namespace root/LibreHardwareMonitor -ClassName Sensor -ErrorAction SilentlyContinue | \
+         Where-Object {{ $_.SensorType -eq 'Temperature' -and $_.Identifier -like '{}*' -and $_.Name -eq '{}' }} | \
+         Measure-Object -Property Value -Maximum | Select-Object -ExpandProperty Maximum",
+        identifier_prefix, sensor_name
+    );
+
+    let output = execute_powershell_command_timeout(&script, TEMP_QUERY_TIMEOUT).ok()?;
+    let celsius: f32 = output.trim().parse().ok()?;
+    VALID_TEMP_RANGE.contains(&celsius).then_some(celsius)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn get_lhm_temp(_identifier_prefix: &str, _sensor_name: &str) -> Option<f32> {
+    None
+}
+
+/// One sensor query the monitor runs (each is a PowerShell process)
+#[derive(Debug, Clone, Copy)]
+enum TempSource {
+    /// LibreHardwareMonitor's CPU package sensor (when it is running)
+    LhmCpu,
+    /// ACPI thermal zones, the CPU fallback
+    ThermalZone,
+    /// LibreHardwareMonitor's GPU core sensor
+    LhmGpu,
+}
+
+impl TempSource {
+    fn read(self) -> Option<f32> {
+        match self {
+            TempSource::LhmCpu => get_lhm_temp("/cpu", "CPU Package"),
+            TempSource::ThermalZone => get_thermal_zone_temp(),
+            TempSource::LhmGpu => get_lhm_temp("/gpu", "GPU Core"),
+        }
+    }
+}
+
+/// A source plus when it may be queried again after returning nothing
+struct SourcePoll {
+    source: TempSource,
+    skip_until: Option<Instant>,
+}
+
+impl SourcePoll {
+    fn new(source: TempSource) -> Self {
+        Self { source, skip_until: None }
+    }
+
+    /// Query the source unless it recently returned nothing
+    fn read(&mut self) -> Option<f32> {
+        if self.skip_until.is_some_and(|until| Instant::now() < until) {
+            return None;
+        }
+        let reading = self.source.read();
+        self.skip_until = reading.is_none().then(|| Instant::now() + SOURCE_RETRY_INTERVAL);
+        reading
+    }
+}
+
+/// Unit temperatures are displayed in (readings are always stored in °C)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TempUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl TempUnit {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            TempUnit::Celsius => "°C",
+            TempUnit::Fahrenheit => "°F",
+        }
+    }
+
+    /// Convert a Celsius value into this unit
+    pub fn convert(self, celsius: f32) -> f32 {
+        match self {
+            TempUnit::Celsius => celsius,
+            TempUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    /// Rounded display string, e.g. "72°C"
+    pub fn format(self, celsius: f32) -> String {
+        format!("{:.0}{}", self.convert(celsius), self.symbol())
+    }
+}
+
+/// Latest sensor readings in °C
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Temperatures {
+    pub cpu: Option<f32>,
+    pub gpu: Option<f32>,
+}
+
+/// Polls temperature sensors on a background thread and keeps the latest reading
+pub struct TempMonitor {
+    latest: Arc<Mutex<Temperatures>>,
+    active: Arc<AtomicBool>,
+}
+
+impl TempMonitor {
+    pub fn start() -> Self {
+        let latest = Arc::new(Mutex::new(Temperatures::default()));
+        let active = Arc::new(AtomicBool::new(true));
+        let shared = latest.clone();
+        let polling = active.clone();
+
+        std::thread::spawn(move || {
+            let mut lhm_cpu = SourcePoll::new(TempSource::LhmCpu);
+            let mut thermal_zone = SourcePoll::new(TempSource::ThermalZone);
+            let mut lhm_gpu = SourcePoll::new(TempSource::LhmGpu);
+            loop {
+                // Paused: drop the old reading so nothing acts on it once polling resumes
+                let reading = if polling.load(Ordering::Relaxed) {
+                    Temperatures {
+                        cpu: lhm_cpu.read().or_else(|| thermal_zone.read()),
+                        gpu: lhm_gpu.read(),
+                    }
+                } else {
+                    Temperatures::default()
+                };
+                if let Ok(mut latest) = shared.lock() {
+                    *latest = reading;
+                }
+                std::thread::sleep(TEMP_POLL_INTERVAL);
+            }
+        });
+
+        Self { latest, active }
+    }
+
+    /// Pause or resume polling; paused while nothing shows or uses the temperatures
+    pub fn set_active(&self, active: bool) {
+        self.active.store(active, Ordering::Relaxed);
+    }
+
+    /// Most recent readings
+    pub fn temperatures(&self) -> Temperatures {
+        self.latest.lock().map(|latest| *latest).unwrap_or_default()
+    }
+}
@@ -0,0 +1,67 @@
+// Color theme selection, including following the Windows app theme
+use crate::i18n::t;
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+/// Theme picked in the settings menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Theme {
+    /// Follow the Windows "app mode" setting
+    System,
+    #[default]
+    Dark,
+    Light,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::System, Theme::Dark, Theme::Light];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Theme::System => t!("theme.system"),
+            Theme::Dark => t!("theme.dark"),
+            Theme::Light => t!("theme.light"),
+        }
+    }
+
+    /// egui visuals for this theme (System queries Windows on every call)
+    pub fn visuals(self) -> egui::Visuals {
+        let dark = match self {
+            Theme::System => !system_uses_light_theme(),
+            Theme::Dark => true,
+            Theme::Light => false,
+        };
+        if dark {
+            egui::Visuals::dark()
+        } else {
+            egui::Visuals::light()
+        }
+    }
+}
+
+/// Windows `AppsUseLightTheme` preference; dark when the value is missing
+#[cfg(target_os = "windows")]
+fn system_uses_light_theme() -> bool {
+    use windows::core::w;
+    use windows::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD};
+
+    let mut value = 0u32;
+    let mut size = std::mem::size_of::<u32>() as u32;
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            w!(r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize"),
+            w!("AppsUseLightTheme"),
+            RRF_RT_REG_DWORD,
+            None,
+            Some((&mut value as *mut u32).cast()),
+            Some(&mut size),
+        )
+    };
+    status.is_ok() && value != 0
+}
+
+#[cfg(not(target_os = "windows"))]
+fn system_uses_light_theme() -> bool {
+    false
+}
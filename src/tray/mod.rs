@@ -0,0 +1,215 @@
+// System tray icon with performance mode quick-switch and a Show/Reset window/Quit menu
+use anyhow::Result;
+use eframe::egui::{self, IconData};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+
+/// Commands forwarded from the tray icon to the app
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+pub enum TrayCommand {
+    Show,
+    /// Restore the default window size and position (for a window stuck off-screen)
+    ResetWindow,
+    /// Switch to the named performance mode
+    SetPerformanceMode(String),
+    Quit,
+}
+
+pub struct AppTray {
+    #[cfg(target_os = "windows")]
+    icon: tray_icon::TrayIcon,
+    #[cfg(target_os = "windows")]
+    menu: tray_icon::menu::Menu,
+    #[cfg(target_os = "windows")]
+    mode_items: Vec<tray_icon::menu::CheckMenuItem>,
+    #[cfg(target_os = "windows")]
+    mode_separator: tray_icon::menu::PredefinedMenuItem,
+    /// Menu item ids of the performance modes, shared with the menu event handler
+    #[cfg(target_os = "windows")]
+    mode_ids: Arc<std::sync::Mutex<Vec<(tray_icon::menu::MenuId, String)>>>,
+    /// Modes currently listed in the menu
+    shown_modes: Vec<String>,
+    shown_tooltip: String,
+    receiver: mpsc::Receiver<TrayCommand>,
+    quit_requested: Arc<AtomicBool>,
+}
+
+impl AppTray {
+    /// Next pending tray command, if any (non-blocking)
+    pub fn try_recv(&self) -> Option<TrayCommand> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Whether Quit was chosen from the tray menu (close must not be turned into hide)
+    pub fn quit_requested(&self) -> bool {
+        self.quit_requested.load(Ordering::Relaxed)
+    }
+
+    /// Keep the performance mode entries, their checkmark and the tooltip in sync with the app
+    pub fn sync(&mut self, modes: &[String], current_mode: &str, tooltip: &str) {
+        if self.shown_modes != modes {
+            self.rebuild_mode_items(modes);
+            self.shown_modes = modes.to_vec();
+        }
+        self.check_mode(current_mode);
+
+        if self.shown_tooltip != tooltip {
+            self.set_tooltip(tooltip);
+            self.shown_tooltip = tooltip.to_string();
+        }
+    }
+}
+
+/// Restore the window and bring it to the front
+pub fn show_window(ctx: &egui::Context) {
+    ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+    ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+    ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+}
+
+/// Hide the window, leaving only the tray icon
+pub fn hide_window(ctx: &egui::Context) {
+    ctx.send_viewport_cmd(egui::ViewportCommand::Visible(false));
+}
+
+#[cfg(target_os = "windows")]
+impl AppTray {
+    /// Create the tray icon. Must be called on the UI (event loop) thread.
+    pub fn new(ctx: &egui::Context, icon: IconData, tooltip: &str) -> Result<Self> {
+        use crate::i18n::t;
+        use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+        use tray_icon::{Icon, MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+
+        let menu = Menu::new();
+        // Labels use the language active at launch
+        let show_item = MenuItem::new(t!("tray.show"), true, None);
+        let reset_item = MenuItem::new(t!("tray.reset_window"), true, None);
+        let quit_item = MenuItem::new(t!("tray.quit"), true, None);
+        // Performance mode entries are inserted above this once the device is known
+        let mode_separator = PredefinedMenuItem::separator();
+        menu.append(&show_item)?;
+        menu.append(&reset_item)?;
+        menu.append(&quit_item)?;
+
+        let icon = Icon::from_rgba(icon.rgba, icon.width, icon.height)?;
+        let tray = TrayIconBuilder::new()
+            .with_tooltip(tooltip)
+            .with_icon(icon)
+            .with_menu(Box::new(menu.clone()))
+            .with_menu_on_left_click(false)
+            .build()?;
+
+        let (sender, receiver) = mpsc::channel();
+        let quit_requested = Arc::new(AtomicBool::new(false));
+        let mode_ids: Arc<std::sync::Mutex<Vec<(tray_icon::menu::MenuId, String)>>> =
+            Arc::default();
+
+        // Handlers run outside of `update()`, so act on the viewport directly and wake the UI.
+        let click_ctx = ctx.clone();
+        let click_sender = sender.clone();
+        TrayIconEvent::set_event_handler(Some(move |event: TrayIconEvent| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                show_window(&click_ctx);
+                let _ = click_sender.send(TrayCommand::Show);
+                click_ctx.request_repaint();
+            }
+        }));
+
+        let menu_ctx = ctx.clone();
+        let menu_quit = quit_requested.clone();
+        let show_id = show_item.id().clone();
+        let reset_id = reset_item.id().clone();
+        let quit_id = quit_item.id().clone();
+        let menu_mode_ids = mode_ids.clone();
+        MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+            if event.id == show_id {
+                show_window(&menu_ctx);
+                let _ = sender.send(TrayCommand::Show);
+            } else if event.id == reset_id {
+                show_window(&menu_ctx);
+                let _ = sender.send(TrayCommand::ResetWindow);
+            } else if event.id == quit_id {
+                menu_quit.store(true, Ordering::Relaxed);
+                let _ = sender.send(TrayCommand::Quit);
+                menu_ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+            } else if let Some((_, mode)) = menu_mode_ids
+                .lock()
+                .ok()
+                .and_then(|ids| ids.iter().find(|(id, _)| *id == event.id).cloned())
+            {
+                let _ = sender.send(TrayCommand::SetPerformanceMode(mode));
+            }
+            menu_ctx.request_repaint();
+        }));
+
+        Ok(Self {
+            icon: tray,
+            menu,
+            mode_items: Vec::new(),
+            mode_separator,
+            mode_ids,
+            shown_modes: Vec::new(),
+            shown_tooltip: tooltip.to_string(),
+            receiver,
+            quit_requested,
+        })
+    }
+
+    fn rebuild_mode_items(&mut self, modes: &[String]) {
+        use tray_icon::menu::CheckMenuItem;
+
+        for item in self.mode_items.drain(..) {
+            let _ = self.menu.remove(&item);
+        }
+        let _ = self.menu.remove(&self.mode_separator);
+
+        let mut ids = Vec::with_capacity(modes.len());
+        for (position, mode) in modes.iter().enumerate() {
+            let item = CheckMenuItem::new(mode, true, false, None);
+            if self.menu.insert(&item, position).is_ok() {
+                ids.push((item.id().clone(), mode.clone()));
+                self.mode_items.push(item);
+            }
+        }
+        if !self.mode_items.is_empty() {
+            let _ = self.menu.insert(&self.mode_separator, self.mode_items.len());
+        }
+
+        if let Ok(mut mode_ids) = self.mode_ids.lock() {
+            *mode_ids = ids;
+        }
+    }
+
+    fn check_mode(&self, current_mode: &str) {
+        for (item, mode) in self.mode_items.iter().zip(&self.shown_modes) {
+            let checked = mode == current_mode;
+            // Clicking a check item toggles it, so re-assert the state every time
+            if item.is_checked() != checked {
+                item.set_checked(checked);
+            }
+        }
+    }
+
+    fn set_tooltip(&self, tooltip: &str) {
+        let _ = self.icon.set_tooltip(Some(tooltip));
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+impl AppTray {
+    pub fn new(_ctx: &egui::Context, _icon: IconData, _tooltip: &str) -> Result<Self> {
+        Err(anyhow::anyhow!("Tray icon is only supported on Windows"))
+    }
+
+    fn rebuild_mode_items(&mut self, _modes: &[String]) {}
+
+    fn check_mode(&self, _current_mode: &str) {}
+
+    fn set_tooltip(&self, _tooltip: &str) {}
+}
@@ -0,0 +1,10 @@
+// Screen-reader names for controls whose visible text is an icon or lacks context
+use eframe::egui::{Response, WidgetInfo, WidgetType};
+
+/// Name an on/off or one-of-many button and report whether it is selected.
+/// Buttons already react to Space/Enter when focused; this only fixes what is announced.
+pub fn toggle(response: &Response, name: &str, selected: bool) {
+    response.widget_info(|| {
+        WidgetInfo::selected(WidgetType::SelectableLabel, response.enabled(), selected, name)
+    });
+}
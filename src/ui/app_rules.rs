@@ -0,0 +1,131 @@
+use crate::config::AppRulesSettings;
+use crate::i18n::t;
+use eframe::egui::{self, Align, Layout, RichText};
+use librazer::types::PerfMode;
+
+// App rules UI actions
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppRulesAction {
+    // No action
+    None,
+    // Rules or the enabled flag were edited and should be saved
+    Changed,
+}
+
+/// Renders the collapsible per-application performance rules section
+///
+/// # Arguments
+/// * `settings` - Rules being edited in place
+/// * `new_exe` - Text buffer for the executable name of a new rule
+/// * `modes` - Performance modes that can be picked
+/// * `foreground_exe` - Last focused executable (other than R-Helper), offered as a quick fill
+pub fn render_app_rules_section(
+    ui: &mut egui::Ui,
+    settings: &mut AppRulesSettings,
+    new_exe: &mut String,
+    modes: &[PerfMode],
+    foreground_exe: Option<&str>,
+) -> AppRulesAction {
+    let mut changed = false;
+
+    ui.group(|ui| {
+        egui::CollapsingHeader::new(format!("🎮 {}", t!("app_rules.title")))
+            .id_salt("app_rules_section")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    changed |= ui
+                        .checkbox(&mut settings.enabled, t!("app_rules.enabled"))
+                        .on_hover_text(t!("app_rules.enabled_hint"))
+                        .changed();
+                    ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                        changed |=
+                            render_mode_combo(ui, "default", &mut settings.default_mode, modes);
+                        ui.add(egui::Label::new(t!("app_rules.otherwise")).selectable(false));
+                    });
+                });
+                ui.separator();
+
+                if settings.rules.is_empty() {
+                    ui.add(
+                        egui::Label::new(RichText::new(t!("app_rules.no_rules")).weak())
+                            .selectable(false),
+                    );
+                }
+
+                let mut remove = None;
+                for (index, (exe, mode)) in settings.rules.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.add(egui::Label::new(exe.as_str()).selectable(false));
+                        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                            if ui.button("🗑").on_hover_text(t!("app_rules.delete")).clicked() {
+                                remove = Some(index);
+                            }
+                            changed |= render_mode_combo(ui, index, mode, modes);
+                        });
+                    });
+                }
+                if let Some(index) = remove {
+                    settings.rules.remove(index);
+                    changed = true;
+                }
+
+                ui.separator();
+                changed |= render_add_row(ui, settings, new_exe, foreground_exe);
+            });
+    });
+
+    if changed {
+        AppRulesAction::Changed
+    } else {
+        AppRulesAction::None
+    }
+}
+
+fn render_mode_combo(
+    ui: &mut egui::Ui,
+    id: impl std::hash::Hash,
+    mode: &mut PerfMode,
+    modes: &[PerfMode],
+) -> bool {
+    let mut changed = false;
+    egui::ComboBox::from_id_salt(("app_rule_mode", id))
+        .selected_text(format!("{:?}", mode))
+        .show_ui(ui, |ui| {
+            for &option in modes {
+                changed |= ui.selectable_value(mode, option, format!("{:?}", option)).changed();
+            }
+        });
+    changed
+}
+
+/// Renders the executable name field and Add button
+fn render_add_row(
+    ui: &mut egui::Ui,
+    settings: &mut AppRulesSettings,
+    new_exe: &mut String,
+    foreground_exe: Option<&str>,
+) -> bool {
+    let mut added = false;
+
+    ui.horizontal(|ui| {
+        ui.add(egui::TextEdit::singleline(new_exe).hint_text("game.exe").desired_width(160.0));
+
+        let exe = new_exe.trim();
+        let duplicate =
+            settings.rules.iter().any(|(existing, _)| existing.eq_ignore_ascii_case(exe));
+        let add = egui::Button::new(format!("➕ {}", t!("app_rules.add")));
+        if ui.add_enabled(!exe.is_empty() && !duplicate, add).clicked() {
+            settings.rules.push((exe.to_string(), PerfMode::Performance));
+            new_exe.clear();
+            added = true;
+        }
+
+        if let Some(foreground) = foreground_exe {
+            if ui.button(t!("app_rules.use_last_app")).on_hover_text(foreground).clicked() {
+                *new_exe = foreground.to_string();
+            }
+        }
+    });
+
+    added
+}
@@ -1,4 +1,7 @@
-use eframe::egui::{self, RichText};
+use crate::config::LowBatterySettings;
+use crate::i18n::t;
+use crate::power::{BatteryStatus, ChargeState};
+use eframe::egui::{self, Align, Color32, Layout, RichText};
 
 // Battery UI actions
 #[derive(Debug, Clone, PartialEq)]
@@ -7,31 +10,178 @@ pub enum BatteryAction {
     None,
     // Toggle battery care
     ToggleBatteryCare,
+    // Low battery automation settings were edited
+    LowBatteryChanged,
 }
 
-pub fn render_battery_section(ui: &mut egui::Ui, battery_care: &mut bool) -> BatteryAction {
+const LOW_BATTERY_THRESHOLD_RANGE: std::ops::RangeInclusive<u8> = 5..=50;
+
+pub fn render_battery_section(
+    ui: &mut egui::Ui,
+    battery_care: &mut bool,
+    battery_care_supported: bool,
+    battery_status: Option<&BatteryStatus>,
+    low_battery: &mut LowBatterySettings,
+    profile_names: &[&str],
+) -> BatteryAction {
     let mut action = BatteryAction::None;
 
     ui.group(|ui| {
-        ui.add(egui::Label::new("🔋 Battery").selectable(false));
+        render_battery_header(ui, battery_status, battery_care_supported && *battery_care);
         ui.separator();
 
         ui.horizontal(|ui| {
-            if ui.checkbox(battery_care, "Battery Health Optimizer").clicked() {
+            if ui
+                .add_enabled(
+                    battery_care_supported,
+                    egui::Checkbox::new(battery_care, t!("battery.care")),
+                )
+                .on_disabled_hover_text(t!("battery.care_unsupported"))
+                .clicked()
+            {
                 action = BatteryAction::ToggleBatteryCare;
             }
         });
 
-        render_battery_status(ui, *battery_care);
+        if battery_care_supported {
+            render_battery_status(ui, *battery_care);
+        }
+
+        ui.separator();
+        if render_low_battery_controls(ui, low_battery, profile_names) {
+            action = BatteryAction::LowBatteryChanged;
+        }
     });
 
     action
 }
 
+fn render_battery_header(
+    ui: &mut egui::Ui,
+    battery_status: Option<&BatteryStatus>,
+    battery_care_active: bool,
+) {
+    ui.horizontal(|ui| {
+        ui.add(egui::Label::new(format!("🔋 {}", t!("battery.title"))).selectable(false));
+
+        // Charge level and estimated runtime (or time to full while charging) on the right
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            if let Some(status) = battery_status {
+                render_charge_state(ui, status.charge_state, battery_care_active);
+            }
+            if let Some(percent) = battery_status.and_then(|s| s.percent) {
+                let mut text = format!("{}%", percent);
+                if let Some(seconds) = battery_status.and_then(|s| s.seconds_remaining) {
+                    text.push_str(&format!(
+                        " • {}",
+                        t!("battery.remaining", format_duration(seconds))
+                    ));
+                } else if let Some(seconds) = battery_status.and_then(|s| s.seconds_to_full) {
+                    text.push_str(&format!(
+                        " • {}",
+                        t!("battery.to_full", format_duration(seconds))
+                    ));
+                }
+                ui.add(egui::Label::new(RichText::new(text)).selectable(false));
+            }
+        });
+    });
+}
+
+/// Icon and label for the charge state. Plugged in but not charging gets its own wording, since
+/// a battery held at the care limit is easily mistaken for a charging fault.
+fn render_charge_state(ui: &mut egui::Ui, state: ChargeState, battery_care_active: bool) {
+    let (icon, label, color) = match state {
+        ChargeState::Unknown => return,
+        ChargeState::Charging => ("⚡", t!("battery.charging"), Color32::GREEN),
+        ChargeState::NotCharging => ("🔌", t!("battery.not_charging"), Color32::LIGHT_BLUE),
+        ChargeState::Full => ("🔌", t!("battery.full"), Color32::GREEN),
+        ChargeState::Discharging => ("🔋", t!("battery.discharging"), ui.visuals().text_color()),
+        ChargeState::Low => ("🪫", t!("battery.low"), Color32::ORANGE),
+        ChargeState::Critical => ("🪫", t!("battery.critical"), Color32::RED),
+    };
+    let response = ui.add(
+        egui::Label::new(RichText::new(format!("{} {}", icon, label)).color(color))
+            .selectable(false),
+    );
+    if state == ChargeState::NotCharging {
+        response.on_hover_text(if battery_care_active {
+            t!("battery.held_at_limit")
+        } else {
+            t!("battery.not_taking_charge")
+        });
+    }
+}
+
 fn render_battery_status(ui: &mut egui::Ui, battery_care_enabled: bool) {
     ui.horizontal(|ui| {
-        let status_text =
-            if battery_care_enabled { "Active (Hardware default: 80%)" } else { "Disabled" };
+        let status_text = if battery_care_enabled {
+            t!("battery.care_active")
+        } else {
+            t!("battery.care_disabled")
+        };
         ui.add(egui::Label::new(RichText::new(status_text)).selectable(false));
     });
 }
+
+/// Renders the low battery automation row, returning true when a setting changed
+fn render_low_battery_controls(
+    ui: &mut egui::Ui,
+    low_battery: &mut LowBatterySettings,
+    profile_names: &[&str],
+) -> bool {
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        let toggle = ui
+            .checkbox(&mut low_battery.enabled, format!("🪫 {}", t!("battery.below")))
+            .on_hover_text(t!("battery.below_hint"));
+        changed |= toggle.changed();
+
+        ui.add_enabled_ui(low_battery.enabled, |ui| {
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut low_battery.threshold)
+                        .range(LOW_BATTERY_THRESHOLD_RANGE)
+                        .suffix("%"),
+                )
+                .labelled_by(toggle.id)
+                .changed();
+
+            let apply_label = ui.add(egui::Label::new(t!("battery.apply")).selectable(false));
+
+            let selected = low_battery.profile.as_deref().unwrap_or(t!("battery.select_profile"));
+            let profile_combo = egui::ComboBox::from_id_salt("low_battery_profile")
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    if profile_names.is_empty() {
+                        ui.add(
+                            egui::Label::new(RichText::new(t!("profiles.none")).weak())
+                                .selectable(false),
+                        );
+                    }
+                    for name in profile_names {
+                        let is_selected = low_battery.profile.as_deref() == Some(*name);
+                        if ui.selectable_label(is_selected, *name).clicked() && !is_selected {
+                            low_battery.profile = Some(name.to_string());
+                            changed = true;
+                        }
+                    }
+                });
+            profile_combo.response.labelled_by(toggle.id).labelled_by(apply_label.id);
+        });
+    });
+
+    changed
+}
+
+/// Formats seconds as "2h 05m" (or "45m" below an hour)
+fn format_duration(seconds: u32) -> String {
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
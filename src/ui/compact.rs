@@ -0,0 +1,52 @@
+use crate::i18n::t;
+use eframe::egui::{self, Align, Layout, RichText};
+
+// Compact view actions
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompactAction {
+    // No action
+    None,
+    // Switch to the next performance mode
+    CyclePerformanceMode,
+    // Return to the full window
+    Expand,
+}
+
+/// Renders the single-row compact readout of performance mode and fan speed
+///
+/// # Arguments
+/// * `performance_mode` - Current performance mode name
+/// * `fan_speed` - Fan mode name ("Auto" / "Manual")
+/// * `fan_actual_rpm` - Measured fan speed, if available
+pub fn render_compact_view(
+    ui: &mut egui::Ui,
+    performance_mode: &str,
+    fan_speed: &str,
+    fan_actual_rpm: Option<u16>,
+) -> CompactAction {
+    let mut action = CompactAction::None;
+
+    ui.horizontal_centered(|ui| {
+        if ui
+            .button(RichText::new(format!("🚀 {}", performance_mode)).strong())
+            .on_hover_text(t!("compact.next_mode"))
+            .clicked()
+        {
+            action = CompactAction::CyclePerformanceMode;
+        }
+
+        let rpm = fan_actual_rpm.map_or_else(|| "-".to_string(), |rpm| rpm.to_string());
+        ui.add(
+            egui::Label::new(format!("🌀 {} RPM ({})", rpm, super::fan::fan_mode_label(fan_speed)))
+                .selectable(false),
+        );
+
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            if ui.button("⛶").on_hover_text(t!("compact.full_view")).clicked() {
+                action = CompactAction::Expand;
+            }
+        });
+    });
+
+    action
+}
@@ -0,0 +1,108 @@
+use crate::i18n::t;
+use eframe::egui::{self, Align, Layout, RichText};
+
+// Performance mode confirmation actions
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfirmAction {
+    // No action
+    None,
+    // Switch the mode
+    Confirm,
+    // Switch the mode and stop asking
+    ConfirmDontAsk,
+    // Keep the current mode
+    Cancel,
+}
+
+/// Renders the modal shown before switching away from a profile with unsaved edits
+pub fn render_discard_profile_confirm(ctx: &egui::Context, profile: &str) -> ConfirmAction {
+    let mut action = ConfirmAction::None;
+
+    let modal = egui::Modal::new(egui::Id::new("discard_profile_confirm")).show(ctx, |ui| {
+        ui.set_max_width(320.0);
+        ui.add(
+            egui::Label::new(RichText::new(t!("confirm.discard_title", profile)).strong())
+                .selectable(false),
+        );
+        ui.add_space(4.0);
+        ui.add(egui::Label::new(t!("confirm.discard_body")).wrap().selectable(false));
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            if ui.button(t!("confirm.discard_switch")).clicked() {
+                action = ConfirmAction::Confirm;
+            }
+            if ui.button(t!("confirm.cancel")).clicked() {
+                action = ConfirmAction::Cancel;
+            }
+        });
+    });
+
+    if action == ConfirmAction::None && modal.should_close() {
+        action = ConfirmAction::Cancel;
+    }
+    action
+}
+
+/// Renders the modal shown before the home state is written; `summary` describes it
+pub fn render_reset_home_confirm(ctx: &egui::Context, summary: &str) -> ConfirmAction {
+    let mut action = ConfirmAction::None;
+
+    let modal = egui::Modal::new(egui::Id::new("reset_home_confirm")).show(ctx, |ui| {
+        ui.set_max_width(320.0);
+        ui.add(
+            egui::Label::new(RichText::new(t!("confirm.reset_home_title")).strong())
+                .selectable(false),
+        );
+        ui.add_space(4.0);
+        ui.add(egui::Label::new(t!("confirm.reset_home_body", summary)).wrap().selectable(false));
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            if ui.button(t!("confirm.reset")).clicked() {
+                action = ConfirmAction::Confirm;
+            }
+            if ui.button(t!("confirm.cancel")).clicked() {
+                action = ConfirmAction::Cancel;
+            }
+        });
+    });
+
+    if action == ConfirmAction::None && modal.should_close() {
+        action = ConfirmAction::Cancel;
+    }
+    action
+}
+
+/// Renders the modal shown before a mode change while the fans are in manual mode
+pub fn render_perf_change_confirm(ctx: &egui::Context, mode: &str, rpm: u16) -> ConfirmAction {
+    let mut action = ConfirmAction::None;
+
+    let modal = egui::Modal::new(egui::Id::new("perf_change_confirm")).show(ctx, |ui| {
+        ui.set_max_width(320.0);
+        ui.add(
+            egui::Label::new(RichText::new(t!("confirm.perf_title", mode)).strong())
+                .selectable(false),
+        );
+        ui.add_space(4.0);
+        ui.add(egui::Label::new(t!("confirm.perf_body", rpm)).wrap().selectable(false));
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            if ui.button(t!("confirm.switch")).clicked() {
+                action = ConfirmAction::Confirm;
+            }
+            if ui.button(t!("confirm.cancel")).clicked() {
+                action = ConfirmAction::Cancel;
+            }
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                if ui.button(t!("confirm.switch_dont_ask")).clicked() {
+                    action = ConfirmAction::ConfirmDontAsk;
+                }
+            });
+        });
+    });
+
+    // Escape or a click outside the dialog
+    if action == ConfirmAction::None && modal.should_close() {
+        action = ConfirmAction::Cancel;
+    }
+    action
+}
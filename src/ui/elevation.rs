@@ -0,0 +1,47 @@
+use crate::i18n::t;
+use eframe::egui::{self, Align, Color32, Layout, RichText};
+
+// Elevation banner actions
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElevationAction {
+    // No action
+    None,
+    // Restart through the UAC prompt
+    Relaunch,
+    // Hide the banner for this session
+    Dismiss,
+    // Hide the banner now and on later launches
+    DontShowAgain,
+}
+
+/// Renders the warning shown when the app runs without administrator rights
+pub fn render_elevation_banner(ui: &mut egui::Ui) -> ElevationAction {
+    let mut action = ElevationAction::None;
+
+    ui.group(|ui| {
+        ui.add(
+            egui::Label::new(
+                RichText::new(format!("⚠ {}", t!("elevation.title")))
+                    .color(Color32::ORANGE)
+                    .strong(),
+            )
+            .selectable(false),
+        );
+        ui.add(egui::Label::new(t!("elevation.body")).selectable(false));
+        ui.horizontal(|ui| {
+            if ui.button(format!("🛡 {}", t!("elevation.relaunch"))).clicked() {
+                action = ElevationAction::Relaunch;
+            }
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                if ui.button(t!("elevation.dont_show_again")).clicked() {
+                    action = ElevationAction::DontShowAgain;
+                }
+                if ui.button(t!("elevation.dismiss")).clicked() {
+                    action = ElevationAction::Dismiss;
+                }
+            });
+        });
+    });
+
+    action
+}
@@ -1,37 +1,79 @@
-use eframe::egui::{self, Align, Color32, Layout, RichText};
+use super::a11y;
+use crate::device::fan_curve::{FanCurve, MAX_CURVE_TEMP};
+use crate::device::{MAX_MANUAL_RPM, MIN_MANUAL_RPM};
+use crate::i18n::t;
+use crate::system::{TempUnit, Temperatures};
+use eframe::egui::{self, Align, Color32, Layout, RichText, Sense, Stroke};
+use librazer::types::FanZone;
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
 
-const MIN_RPM_FOR_COLOR: f32 = 1900.0;
-const MAX_RPM_FOR_COLOR: f32 = 5000.0;
-const MIN_MANUAL_RPM: u16 = 2000;
-const MAX_MANUAL_RPM: u16 = 5500;
+/// RPM span of the green→red readout gradient when the device does not report its range
+pub const DEFAULT_RPM_COLOR_RANGE: RangeInclusive<u16> = 1900..=5000;
 const RPM_STEP: f64 = 100.0;
+const MIN_TEMP_FOR_COLOR: f32 = 45.0;
+const MAX_TEMP_FOR_COLOR: f32 = 95.0;
 const DARK_GREEN_MAX: u8 = 120;
 const ORANGE_MAX: u8 = 100;
+const CURVE_MIN_TEMP: u8 = 30;
+const CURVE_EDITOR_HEIGHT: f32 = 120.0;
+const CURVE_POINT_RADIUS: f32 = 5.0;
+const RPM_HISTORY_HEIGHT: f32 = 32.0;
+/// Time span covered by the RPM sparkline
+pub const RPM_HISTORY_WINDOW: Duration = Duration::from_secs(60);
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum FanAction {
     None,
     SetAutoMode,
     SetManualMode(u16),
-    SetManualRpm(u16),
-    SliderDragging(u16),
+    SetManualRpm(FanZone, u16),
+    SliderDragging(FanZone, u16),
+    ApplyFanCurve(FanCurve),
+    ClearFanCurve,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn render_fan_section(
     ui: &mut egui::Ui,
     fan_speed: &str,
     fan_actual_rpm: Option<u16>,
+    fan2_actual_rpm: Option<u16>,
     fan_set_rpm: Option<u16>,
     manual_fan_rpm: &mut u16,
+    manual_fan2_rpm: Option<&mut u16>,
+    rpm_range: RangeInclusive<u16>,
+    rpm_range_listed: bool,
+    color_range: RangeInclusive<u16>,
+    show_percent: bool,
     show_status_messages: bool,
-    custom_mode_active: bool,
     max_fan_speed_enabled: bool,
+    fan_curve: Option<&FanCurve>,
+    curve_draft: &mut Vec<(u8, u16)>,
+    temperatures: Temperatures,
+    temp_unit: TempUnit,
+    rpm_history: &VecDeque<(Instant, u16)>,
 ) -> (FanAction, bool) {
     let mut action = FanAction::None;
     let mut toggle_max = max_fan_speed_enabled;
 
     ui.group(|ui| {
-        render_fan_header(ui, fan_actual_rpm, fan_set_rpm, show_status_messages);
+        render_fan_header(
+            ui,
+            fan_actual_rpm,
+            fan2_actual_rpm,
+            fan_set_rpm,
+            temperatures,
+            temp_unit,
+            show_status_messages,
+            &color_range,
+            *rpm_range.end(),
+            show_percent,
+        );
+        if rpm_history.len() >= 2 {
+            render_rpm_history(ui, rpm_history, &color_range);
+        }
         ui.separator();
         // Fan Mode Selection row with Max on the right
         let available_width = ui.available_width();
@@ -41,36 +83,99 @@ pub fn render_fan_section(
             |ui| {
                 // Use two columns for clean right alignment
                 ui.columns(2, |cols| {
-                    // Left column: Auto / Manual
+                    // Left column: Auto / Manual / Curve
                     cols[0].horizontal(|ui| {
                         let auto_selected = fan_speed.eq_ignore_ascii_case("auto");
-                        if ui.selectable_label(auto_selected, "Auto").clicked() && !auto_selected {
+                        let auto_response = ui.selectable_label(auto_selected, t!("fan.auto"));
+                        a11y::toggle(&auto_response, t!("fan.a11y_auto"), auto_selected);
+                        if auto_response.on_hover_text(t!("fan.mode_shortcut")).clicked()
+                            && !auto_selected
+                        {
                             action = FanAction::SetAutoMode;
                         }
-                        let manual_selected = fan_speed.eq_ignore_ascii_case("manual");
-                        if ui.selectable_label(manual_selected, "Manual").clicked()
+                        let manual_mode = fan_speed.eq_ignore_ascii_case("manual");
+                        let manual_selected = manual_mode && fan_curve.is_none();
+                        let manual_response =
+                            ui.selectable_label(manual_selected, t!("fan.manual"));
+                        a11y::toggle(&manual_response, t!("fan.a11y_manual"), manual_selected);
+                        if manual_response.on_hover_text(t!("fan.mode_shortcut")).clicked()
                             && !manual_selected
                         {
-                            action = FanAction::SetManualMode(*manual_fan_rpm);
+                            action = if fan_curve.is_some() {
+                                FanAction::ClearFanCurve
+                            } else {
+                                FanAction::SetManualMode(*manual_fan_rpm)
+                            };
+                        }
+                        let curve_selected = manual_mode && fan_curve.is_some();
+                        let curve_response = ui.selectable_label(curve_selected, t!("fan.curve"));
+                        a11y::toggle(&curve_response, t!("fan.a11y_curve"), curve_selected);
+                        let curve_response = curve_response.on_hover_text(t!("fan.curve_hint"));
+                        if curve_response.clicked() && !curve_selected {
+                            if let Ok(curve) = FanCurve::new(curve_draft.clone()) {
+                                action = FanAction::ApplyFanCurve(curve);
+                            }
                         }
                     });
-                    // Right column: Max (toggle) - only when Custom mode AND in-app Debug are enabled
+                    // Right column: Max (toggle)
                     cols[1].with_layout(Layout::right_to_left(Align::Center), |ui| {
-                        if custom_mode_active && show_status_messages {
-                            let max_selected = toggle_max;
-                            let response = ui.selectable_label(max_selected, "Max");
-                            if response.clicked() {
-                                toggle_max = !toggle_max;
-                            }
+                        let response = ui.selectable_label(toggle_max, t!("fan.max"));
+                        a11y::toggle(&response, t!("fan.a11y_max"), toggle_max);
+                        let response = response.on_hover_text(t!("fan.max_hint"));
+                        if response.clicked() {
+                            toggle_max = !toggle_max;
                         }
                     });
                 });
             },
         );
 
-        // Manual RPM Slider (shown only in manual mode)
+        // Manual RPM Slider or curve editor (shown only in manual mode)
         if fan_speed.eq_ignore_ascii_case("manual") {
-            if let Some(manual_action) = render_manual_fan_controls(ui, manual_fan_rpm) {
+            let range_text = if rpm_range_listed {
+                t!("fan.supported_range", rpm_range.start(), rpm_range.end())
+            } else {
+                t!("fan.default_range", rpm_range.start(), rpm_range.end())
+            };
+            if let Some(curve) = fan_curve {
+                if let Some(curve_action) =
+                    render_fan_curve_controls(ui, curve, curve_draft, temperatures.cpu, temp_unit)
+                {
+                    action = curve_action;
+                }
+            } else if let Some(manual_fan2_rpm) = manual_fan2_rpm {
+                // Independent sliders when the device exposes a second fan zone
+                if let Some(manual_action) = render_manual_fan_controls(
+                    ui,
+                    t!("fan.fan1"),
+                    FanZone::Zone1,
+                    manual_fan_rpm,
+                    rpm_range.clone(),
+                    &range_text,
+                    show_percent,
+                ) {
+                    action = manual_action;
+                }
+                if let Some(manual_action) = render_manual_fan_controls(
+                    ui,
+                    t!("fan.fan2"),
+                    FanZone::Zone2,
+                    manual_fan2_rpm,
+                    rpm_range.clone(),
+                    &range_text,
+                    show_percent,
+                ) {
+                    action = manual_action;
+                }
+            } else if let Some(manual_action) = render_manual_fan_controls(
+                ui,
+                "RPM:",
+                FanZone::Zone1,
+                manual_fan_rpm,
+                rpm_range,
+                &range_text,
+                show_percent,
+            ) {
                 action = manual_action;
             }
         }
@@ -81,38 +186,71 @@ pub fn render_fan_section(
     (action, toggle_max)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_fan_header(
     ui: &mut egui::Ui,
     fan_actual_rpm: Option<u16>,
+    fan2_actual_rpm: Option<u16>,
     fan_set_rpm: Option<u16>,
+    temperatures: Temperatures,
+    temp_unit: TempUnit,
     show_status_messages: bool,
+    color_range: &RangeInclusive<u16>,
+    max_rpm: u16,
+    show_percent: bool,
 ) {
     ui.horizontal(|ui| {
-        ui.add(egui::Label::new("🌀 Fan Control").selectable(false));
+        ui.add(egui::Label::new(format!("🌀 {}", t!("fan.title"))).selectable(false));
 
         ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            // Right-to-left: second zone is added first so it ends up rightmost
+            if let Some(actual_rpm) = fan2_actual_rpm {
+                let rpm_color = calculate_rpm_color(actual_rpm, color_range);
+                ui.add(
+                    egui::Label::new(
+                        RichText::new(format_rpm(actual_rpm, max_rpm, show_percent))
+                            .color(rpm_color),
+                    )
+                    .selectable(false),
+                );
+                ui.add(egui::Label::new("/").selectable(false));
+            }
+
             if let Some(actual_rpm) = fan_actual_rpm {
-                let rpm_color = calculate_rpm_color(actual_rpm);
+                let rpm_color = calculate_rpm_color(actual_rpm, color_range);
                 ui.add(
-                    egui::Label::new(RichText::new(format!("{} RPM", actual_rpm)).color(rpm_color))
-                        .selectable(false),
+                    egui::Label::new(
+                        RichText::new(format_rpm(actual_rpm, max_rpm, show_percent))
+                            .color(rpm_color),
+                    )
+                    .selectable(false),
                 );
             } else {
-                ui.add(egui::Label::new(RichText::new("N/A")).selectable(false));
+                ui.add(egui::Label::new(RichText::new(t!("fan.unavailable"))).selectable(false));
+            }
+
+            if temperatures.cpu.is_some() || temperatures.gpu.is_some() {
+                ui.add(egui::Label::new("|").selectable(false));
+                render_temp_label(ui, "GPU", temperatures.gpu, temp_unit);
+                render_temp_label(ui, "CPU", temperatures.cpu, temp_unit);
             }
 
             if show_status_messages {
                 if let Some(set_rpm) = fan_set_rpm {
                     ui.add(
                         egui::Label::new(
-                            RichText::new(format!("Set: {} |", set_rpm)).color(Color32::LIGHT_GRAY),
+                            RichText::new(format!("{} |", t!("fan.set_rpm", set_rpm)))
+                                .color(Color32::LIGHT_GRAY),
                         )
                         .selectable(false),
                     );
                 } else {
                     ui.add(
-                        egui::Label::new(RichText::new("Set: Auto |").color(Color32::LIGHT_GRAY))
-                            .selectable(false),
+                        egui::Label::new(
+                            RichText::new(format!("{} |", t!("fan.set_auto")))
+                                .color(Color32::LIGHT_GRAY),
+                        )
+                        .selectable(false),
                     );
                 }
             }
@@ -120,19 +258,49 @@ fn render_fan_header(
     });
 }
 
+fn render_temp_label(ui: &mut egui::Ui, name: &str, temp: Option<f32>, unit: TempUnit) {
+    if let Some(temp) = temp {
+        // Color thresholds are in °C; only the text is converted
+        ui.add(
+            egui::Label::new(
+                RichText::new(format!("{} {}", name, unit.format(temp)))
+                    .color(calculate_temp_color(temp)),
+            )
+            .selectable(false),
+        );
+    }
+}
+
 // (Removed old separate render_fan_mode_controls; integrated directly for alignment needs)
 
-fn render_manual_fan_controls(ui: &mut egui::Ui, manual_fan_rpm: &mut u16) -> Option<FanAction> {
+fn render_manual_fan_controls(
+    ui: &mut egui::Ui,
+    label: &str,
+    zone: FanZone,
+    manual_fan_rpm: &mut u16,
+    rpm_range: RangeInclusive<u16>,
+    range_text: &str,
+    show_percent: bool,
+) -> Option<FanAction> {
     ui.horizontal(|ui| {
-        ui.add(egui::Label::new("RPM:").selectable(false));
-        let fan_response = ui.add(
-            egui::Slider::new(manual_fan_rpm, MIN_MANUAL_RPM..=MAX_MANUAL_RPM).step_by(RPM_STEP),
-        );
+        let label = ui.add(egui::Label::new(label).selectable(false));
+        let max_rpm = *rpm_range.end();
+        let mut slider = egui::Slider::new(manual_fan_rpm, rpm_range).step_by(RPM_STEP);
+        if show_percent {
+            // Typed values are percentages of the maximum; the device still gets RPM
+            slider = slider
+                .custom_formatter(move |rpm, _| format!("{}%", rpm_percent(rpm as u16, max_rpm)))
+                .custom_parser(move |text| {
+                    let percent = text.trim().trim_end_matches('%').trim().parse::<f64>().ok()?;
+                    Some(percent / 100.0 * max_rpm as f64)
+                });
+        }
+        let fan_response = ui.add(slider).labelled_by(label.id).on_hover_text(range_text);
 
         if fan_response.dragged() || fan_response.has_focus() {
-            Some(FanAction::SliderDragging(*manual_fan_rpm))
+            Some(FanAction::SliderDragging(zone, *manual_fan_rpm))
         } else if fan_response.drag_stopped() || fan_response.lost_focus() {
-            Some(FanAction::SetManualRpm(*manual_fan_rpm))
+            Some(FanAction::SetManualRpm(zone, *manual_fan_rpm))
         } else {
             None
         }
@@ -140,17 +308,255 @@ fn render_manual_fan_controls(ui: &mut egui::Ui, manual_fan_rpm: &mut u16) -> Op
     .inner
 }
 
+/// Renders the curve editor with its readout and apply button
+fn render_fan_curve_controls(
+    ui: &mut egui::Ui,
+    active_curve: &FanCurve,
+    curve_draft: &mut Vec<(u8, u16)>,
+    cpu_temp: Option<f32>,
+    temp_unit: TempUnit,
+) -> Option<FanAction> {
+    let mut action = None;
+
+    render_fan_curve_editor(ui, curve_draft, cpu_temp, temp_unit);
+
+    let validated = FanCurve::new(curve_draft.clone());
+    ui.horizontal(|ui| {
+        match cpu_temp {
+            Some(temp) => {
+                let label = format!(
+                    "CPU {} → {} RPM",
+                    temp_unit.format(temp),
+                    active_curve.target_rpm(temp)
+                );
+                ui.add(egui::Label::new(label).selectable(false));
+            }
+            None => {
+                ui.add(
+                    egui::Label::new(RichText::new(t!("fan.cpu_temp_unavailable")).weak())
+                        .selectable(false),
+                );
+            }
+        }
+
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            let changed = validated.as_ref().is_ok_and(|curve| curve != active_curve);
+            if ui.add_enabled(changed, egui::Button::new(t!("fan.apply_curve"))).clicked() {
+                if let Ok(curve) = validated.as_ref() {
+                    action = Some(FanAction::ApplyFanCurve(curve.clone()));
+                }
+            }
+            if ui.button(t!("fan.reset_curve")).on_hover_text(t!("fan.reset_curve_hint")).clicked()
+            {
+                *curve_draft = active_curve.points().to_vec();
+            }
+        });
+    });
+
+    if let Err(e) = &validated {
+        ui.add(
+            egui::Label::new(RichText::new(e.to_string()).color(Color32::LIGHT_RED))
+                .selectable(false),
+        );
+    }
+
+    action
+}
+
+/// Draggable temperature/RPM plot. Double-click adds a point, right-click removes one.
+fn render_fan_curve_editor(
+    ui: &mut egui::Ui,
+    points: &mut Vec<(u8, u16)>,
+    cpu_temp: Option<f32>,
+    temp_unit: TempUnit,
+) {
+    let size = egui::vec2(ui.available_width(), CURVE_EDITOR_HEIGHT);
+    let (canvas, painter) = ui.allocate_painter(size, Sense::click());
+    let rect = canvas.rect.shrink(CURVE_POINT_RADIUS);
+    let visuals = ui.visuals().clone();
+
+    let temp_span = (MAX_CURVE_TEMP - CURVE_MIN_TEMP) as f32;
+    let rpm_span = (MAX_MANUAL_RPM - MIN_MANUAL_RPM) as f32;
+    let to_screen = |(temp, rpm): (u8, u16)| {
+        egui::pos2(
+            rect.left() + (temp.saturating_sub(CURVE_MIN_TEMP)) as f32 / temp_span * rect.width(),
+            rect.bottom() - (rpm.saturating_sub(MIN_MANUAL_RPM)) as f32 / rpm_span * rect.height(),
+        )
+    };
+    let from_screen = |pos: egui::Pos2| {
+        let temp_ratio = ((pos.x - rect.left()) / rect.width()).clamp(0.0, 1.0);
+        let rpm_ratio = ((rect.bottom() - pos.y) / rect.height()).clamp(0.0, 1.0);
+        let temp = CURVE_MIN_TEMP as f32 + temp_ratio * temp_span;
+        let rpm = MIN_MANUAL_RPM as f32 + rpm_ratio * rpm_span;
+        let rpm = (rpm / RPM_STEP as f32).round() * RPM_STEP as f32;
+        (temp.round() as u8, rpm as u16)
+    };
+
+    painter.rect_filled(canvas.rect, 4.0, visuals.extreme_bg_color);
+
+    // Current temperature marker
+    if let Some(temp) = cpu_temp {
+        let x = rect.left() + ((temp - CURVE_MIN_TEMP as f32) / temp_span) * rect.width();
+        if rect.x_range().contains(x) {
+            painter.vline(x, rect.y_range(), Stroke::new(1.0, Color32::from_rgb(255, 165, 0)));
+        }
+    }
+
+    let line: Vec<egui::Pos2> = points.iter().map(|&point| to_screen(point)).collect();
+    painter.add(egui::Shape::line(line, Stroke::new(2.0, visuals.selection.bg_fill)));
+
+    let mut remove = None;
+    for (index, point) in points.iter_mut().enumerate() {
+        let center = to_screen(*point);
+        let hit = egui::Rect::from_center_size(center, egui::Vec2::splat(CURVE_POINT_RADIUS * 3.0));
+        let response = ui
+            .interact(hit, canvas.id.with(index), Sense::click_and_drag())
+            .on_hover_text(format!("{} → {} RPM", temp_unit.format(point.0 as f32), point.1));
+
+        if response.dragged() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                *point = from_screen(pos);
+            }
+        }
+        if response.secondary_clicked() {
+            remove = Some(index);
+        }
+
+        let color = if response.hovered() || response.dragged() {
+            visuals.strong_text_color()
+        } else {
+            visuals.selection.bg_fill
+        };
+        painter.circle_filled(center, CURVE_POINT_RADIUS, color);
+    }
+
+    if let Some(index) = remove {
+        if points.len() > 2 {
+            points.remove(index);
+        }
+    }
+
+    if canvas.double_clicked() {
+        if let Some(pos) = canvas.interact_pointer_pos() {
+            let new_point = from_screen(pos);
+            let insert_at = points.partition_point(|&(temp, _)| temp < new_point.0);
+            points.insert(insert_at, new_point);
+        }
+    }
+}
+
+/// Sparkline of recent actual RPM; the y-axis matches the RPM color range
+fn render_rpm_history(
+    ui: &mut egui::Ui,
+    rpm_history: &VecDeque<(Instant, u16)>,
+    color_range: &RangeInclusive<u16>,
+) {
+    let size = egui::vec2(ui.available_width(), RPM_HISTORY_HEIGHT);
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+
+    let now = Instant::now();
+    let window = RPM_HISTORY_WINDOW.as_secs_f32();
+    let to_screen = |(time, rpm): (Instant, u16)| {
+        let age = now.duration_since(time).as_secs_f32().min(window);
+        let normalized = normalize_rpm(rpm, color_range);
+        egui::pos2(
+            rect.right() - age / window * rect.width(),
+            rect.bottom() - normalized * rect.height(),
+        )
+    };
+
+    let line: Vec<egui::Pos2> = rpm_history.iter().map(|&sample| to_screen(sample)).collect();
+    let latest_color = rpm_history.back().map(|&(_, rpm)| calculate_rpm_color(rpm, color_range));
+    painter.add(egui::Shape::line(
+        line,
+        Stroke::new(1.5, latest_color.unwrap_or(ui.visuals().text_color())),
+    ));
+
+    if let (Some(min), Some(max)) = (
+        rpm_history.iter().map(|&(_, rpm)| rpm).min(),
+        rpm_history.iter().map(|&(_, rpm)| rpm).max(),
+    ) {
+        response.on_hover_text(t!("fan.history_range", min, max));
+    }
+}
+
+/// Share of the maximum RPM, rounded to whole percent
+fn rpm_percent(rpm: u16, max_rpm: u16) -> u16 {
+    if max_rpm == 0 {
+        return 0;
+    }
+    (rpm as f32 / max_rpm as f32 * 100.0).round() as u16
+}
+
+/// Fan readout text: absolute RPM, with the share of the maximum when enabled
+fn format_rpm(rpm: u16, max_rpm: u16, show_percent: bool) -> String {
+    if show_percent {
+        format!("{} RPM ({}%)", rpm, rpm_percent(rpm, max_rpm))
+    } else {
+        format!("{} RPM", rpm)
+    }
+}
+
 fn render_current_status(ui: &mut egui::Ui, fan_speed: &str) {
-    ui.add(egui::Label::new(format!("Current: {}", fan_speed)).selectable(false));
+    ui.add(egui::Label::new(t!("fan.current_mode", fan_mode_label(fan_speed))).selectable(false));
 }
 
-fn calculate_rpm_color(actual_rpm: u16) -> Color32 {
-    let normalized_rpm = ((actual_rpm as f32 - MIN_RPM_FOR_COLOR)
-        / (MAX_RPM_FOR_COLOR - MIN_RPM_FOR_COLOR))
-        .clamp(0.0, 1.0);
-    let green_component = ((1.0 - normalized_rpm) * DARK_GREEN_MAX as f32) as u8;
-    let red_component = (normalized_rpm * 255.0) as u8;
-    let orange_component = (normalized_rpm * 165.0) as u8;
+/// Translated name of a fan mode as reported by the device ("Auto" / "Manual")
+pub fn fan_mode_label(fan_speed: &str) -> &str {
+    if fan_speed.eq_ignore_ascii_case("auto") {
+        t!("fan.auto")
+    } else if fan_speed.eq_ignore_ascii_case("manual") {
+        t!("fan.manual")
+    } else {
+        fan_speed
+    }
+}
+
+fn calculate_rpm_color(actual_rpm: u16, color_range: &RangeInclusive<u16>) -> Color32 {
+    gradient_color(normalize_rpm(actual_rpm, color_range))
+}
+
+/// Position of an RPM within the color range, 0..=1
+fn normalize_rpm(rpm: u16, color_range: &RangeInclusive<u16>) -> f32 {
+    let (min, max) = (*color_range.start() as f32, *color_range.end() as f32);
+    if max <= min {
+        return 0.0;
+    }
+    ((rpm as f32 - min) / (max - min)).clamp(0.0, 1.0)
+}
+
+fn calculate_temp_color(temp: f32) -> Color32 {
+    let normalized_temp =
+        ((temp - MIN_TEMP_FOR_COLOR) / (MAX_TEMP_FOR_COLOR - MIN_TEMP_FOR_COLOR)).clamp(0.0, 1.0);
+    gradient_color(normalized_temp)
+}
+
+/// Green (cool/quiet) to red (hot/loud) for a value normalized to 0..=1
+fn gradient_color(normalized: f32) -> Color32 {
+    let green_component = ((1.0 - normalized) * DARK_GREEN_MAX as f32) as u8;
+    let red_component = (normalized * 255.0) as u8;
+    let orange_component = (normalized * 165.0) as u8;
 
     Color32::from_rgb(red_component, green_component, orange_component.min(ORANGE_MAX))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rpm_color_ramp_never_goes_backward() {
+        let range = MIN_MANUAL_RPM..=MAX_MANUAL_RPM;
+        let mut previous = calculate_rpm_color(0, &range);
+        // Past both ends too, where the ramp is clamped
+        for rpm in (100..=MAX_MANUAL_RPM + 1000).step_by(100) {
+            let color = calculate_rpm_color(rpm, &range);
+            assert!(color.r() >= previous.r(), "red fell at {} RPM", rpm);
+            assert!(color.g() <= previous.g(), "green rose at {} RPM", rpm);
+            assert!(color.b() >= previous.b(), "blue fell at {} RPM", rpm);
+            previous = color;
+        }
+    }
+}
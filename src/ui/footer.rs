@@ -1,43 +1,120 @@
+use crate::config::Settings;
+use crate::i18n::t;
+use crate::system::autostart::AutostartState;
+use crate::update::Release;
 use eframe::egui::{self, Align, Layout, RichText};
 
 const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
 use std::process::Command;
+use std::time::Duration;
+
+// Footer UI actions
+#[derive(Debug, Clone, PartialEq)]
+pub enum FooterAction {
+    // No action
+    None,
+    // A persisted setting was toggled
+    SettingsChanged,
+    // Register/unregister launch at login (true also repairs a stale entry)
+    SetAutostart(bool),
+    // Restore the default window size and center it
+    ResetWindow,
+    // Switch to the single-row compact view
+    EnterCompact,
+    // Copy a diagnostics report for bug reports to the clipboard
+    CopyDiagnostics,
+    // Ask to write the home state to the device
+    ResetToHome,
+    // Make the current device state the home state
+    SetHomeToCurrent,
+    // Put the home state back to the built-in defaults
+    RestoreHomeDefaults,
+}
 
 /// Renders the application footer with version info and controls
-pub fn render_footer(ui: &mut egui::Ui, status_messages: &mut bool) {
+pub fn render_footer(
+    ui: &mut egui::Ui,
+    settings: &mut Settings,
+    tray_available: bool,
+    autostart: &AutostartState,
+    available_update: Option<&Release>,
+    read_latency: Option<Duration>,
+) -> FooterAction {
+    let mut action = FooterAction::None;
+
     // Add vertical padding for better spacing
     ui.add_space(8.0);
 
     ui.horizontal(|ui| {
         render_version_info(ui);
         ui.separator();
-        render_status_toggle(ui, status_messages);
+        if render_status_toggle(ui, &mut settings.status_messages) {
+            action = FooterAction::SettingsChanged;
+        }
+        if let Some(latency) = read_latency.filter(|_| settings.status_messages) {
+            ui.add(
+                egui::Label::new(
+                    RichText::new(t!("footer.read_time", latency.as_millis())).small().weak(),
+                )
+                .selectable(false),
+            )
+            .on_hover_text(t!("footer.read_time_hint"));
+        }
+        if ui
+            .selectable_label(settings.show_message_history, "📜")
+            .on_hover_text(t!("history.title"))
+            .clicked()
+        {
+            settings.show_message_history = !settings.show_message_history;
+            action = FooterAction::SettingsChanged;
+        }
+        ui.menu_button("⚙", |ui| {
+            if let Some(menu_action) =
+                super::settings::render_settings_menu(ui, settings, tray_available, autostart)
+            {
+                action = menu_action;
+            }
+        })
+        .response
+        .on_hover_text(t!("footer.settings"));
 
         // GitHub button on the right side
         ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
             if ui.button("🌐 GitHub").clicked() {
                 let _ = Command::new("cmd")
-                    .args(&["/c", "start", "https://github.com/Fatalution/r-helper"])
+                    .args(["/c", "start", "https://github.com/Fatalution/r-helper"])
                     .spawn();
             }
+            if let Some(release) = available_update.filter(|_| settings.check_for_updates) {
+                let label = RichText::new(t!("footer.update_available", release.tag))
+                    .small()
+                    .color(ui.visuals().hyperlink_color);
+                let response = ui
+                    .add(egui::Label::new(label).sense(egui::Sense::click()))
+                    .on_hover_text(t!("footer.open_release"));
+                if response.clicked() {
+                    let _ = Command::new("cmd").args(["/c", "start", &release.url]).spawn();
+                }
+            }
         });
     });
 
     // Add bottom padding for balance
     ui.add_space(8.0);
+
+    action
 }
 
 /// Renders the application version information
 fn render_version_info(ui: &mut egui::Ui) {
-    let text = format!("{} • Made with ♥ by Fatalution", APP_VERSION);
+    let text = format!("{} • {}", APP_VERSION, t!("footer.made_by"));
     let label = egui::Label::new(RichText::new(text)).selectable(false).sense(egui::Sense::click());
     if ui.add(label).clicked() {
-        let _ =
-            Command::new("cmd").args(&["/c", "start", "https://paypal.me/fatalutionDE"]).spawn();
+        let _ = Command::new("cmd").args(["/c", "start", "https://paypal.me/fatalutionDE"]).spawn();
     }
 }
 
-/// Renders the status messages toggle
-fn render_status_toggle(ui: &mut egui::Ui, status_messages: &mut bool) {
-    ui.checkbox(status_messages, "🐛 Debug");
+/// Renders the status messages toggle, returning true when changed
+fn render_status_toggle(ui: &mut egui::Ui, status_messages: &mut bool) -> bool {
+    ui.checkbox(status_messages, format!("🐛 {}", t!("footer.debug"))).changed()
 }
@@ -1,28 +1,125 @@
-use crate::messaging::{MessageManager, MessageType};
+use crate::i18n::t;
+use crate::messaging::{MessageManager, MessageType, FADE_DURATION};
 use crate::system::SystemSpecs;
 use eframe::egui::{self, Align, Color32, Layout, RichText};
+use librazer::descriptor::Descriptor;
 use librazer::device::Device;
 
-const FADE_START_TIME: f32 = 3.0;
-const FADE_DURATION: f32 = 2.0;
 const FULL_ALPHA: u8 = 255;
 
+// Header UI actions
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeaderAction {
+    // No action
+    None,
+    // Re-read everything from the device
+    Refresh,
+    // Revert the last device change
+    Undo,
+    // Write the current settings into the active profile
+    SaveProfile,
+    // Look for a device again after detection found none
+    RetryDetection,
+}
+
 /// Renders the application header with device name and status messages
+#[allow(clippy::too_many_arguments)]
 pub fn render_header(
     ui: &mut egui::Ui,
     ctx: &egui::Context,
     loading: bool,
     system_specs: &SystemSpecs,
-    device: &Option<Device>,
+    device: &Option<Descriptor>,
+    firmware_version: Option<&str>,
     message_manager: &MessageManager,
     detecting_device: bool,
-) {
+    refresh_enabled: bool,
+    can_undo: bool,
+    manual_control_only: bool,
+    monitor_only: bool,
+    active_profile: Option<(&str, bool)>,
+) -> HeaderAction {
+    let mut action = HeaderAction::None;
+
     ui.horizontal(|ui| {
         // Device name
-        render_device_name(ui, device, system_specs);
+        render_device_name(ui, device, firmware_version, system_specs);
 
         // Status messages and connection status
         ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            if device.is_some() {
+                if ui
+                    .add_enabled(refresh_enabled, egui::Button::new("🔄"))
+                    .on_hover_text(t!("header.refresh_hint"))
+                    .clicked()
+                {
+                    action = HeaderAction::Refresh;
+                }
+                if ui
+                    .add_enabled(can_undo, egui::Button::new("↶"))
+                    .on_hover_text(t!("header.undo_hint"))
+                    .clicked()
+                {
+                    action = HeaderAction::Undo;
+                }
+            }
+
+            if device.is_some() {
+                let (text, hover) = match active_profile {
+                    Some((name, false)) => (format!("📁 {}", name), t!("header.profile_matches")),
+                    Some((name, true)) => (format!("📁 {}*", name), t!("header.profile_changed")),
+                    None => (format!("📁 {}", t!("header.unsaved")), t!("header.unsaved_hint")),
+                };
+                if let Some((_, dirty)) = active_profile {
+                    if ui
+                        .add_enabled(dirty, egui::Button::new(RichText::new("💾").small()))
+                        .on_hover_text(t!("header.save_profile_hint"))
+                        .on_disabled_hover_text(t!("header.no_unsaved_changes"))
+                        .clicked()
+                    {
+                        action = HeaderAction::SaveProfile;
+                    }
+                }
+                ui.add(egui::Label::new(RichText::new(text).small().weak()).selectable(false))
+                    .on_hover_text(hover);
+            }
+
+            if device.is_none() {
+                if detecting_device {
+                    if !loading {
+                        ui.spinner();
+                    }
+                } else if ui
+                    .button(format!("🔁 {}", t!("header.retry")))
+                    .on_hover_text(t!("header.retry_hint"))
+                    .clicked()
+                {
+                    action = HeaderAction::RetryDetection;
+                }
+            }
+
+            if monitor_only {
+                ui.add(
+                    egui::Label::new(
+                        RichText::new(format!("👁 {}", t!("header.monitor_only")))
+                            .small()
+                            .color(Color32::ORANGE),
+                    )
+                    .selectable(false),
+                )
+                .on_hover_text(t!("header.monitor_only_hint"));
+            } else if manual_control_only {
+                ui.add(
+                    egui::Label::new(
+                        RichText::new(format!("✋ {}", t!("header.manual_only")))
+                            .small()
+                            .color(Color32::ORANGE),
+                    )
+                    .selectable(false),
+                )
+                .on_hover_text(t!("header.manual_only_hint"));
+            }
+
             if loading {
                 ui.spinner();
             }
@@ -31,21 +128,57 @@ pub fn render_header(
             render_status_messages(ui, ctx, message_manager, device, detecting_device);
         });
     });
+
+    action
 }
 
-/// Renders device name section
-fn render_device_name(ui: &mut egui::Ui, device: &Option<Device>, system_specs: &SystemSpecs) {
+/// Renders device name section, with the matched librazer device details on hover
+fn render_device_name(
+    ui: &mut egui::Ui,
+    device: &Option<Descriptor>,
+    firmware_version: Option<&str>,
+    system_specs: &SystemSpecs,
+) {
     let device_text = if device.is_some() || system_specs.device_model != "Unknown" {
         if system_specs.device_model != "Unknown" {
             format!("💻 {}", system_specs.device_model)
         } else {
-            "💻 Connected Device".to_string()
+            format!("💻 {}", t!("header.connected_device"))
         }
     } else {
-        "💻 No Razer Device".to_string()
+        format!("💻 {}", t!("header.no_razer_device"))
     };
 
-    ui.add(egui::Label::new(egui::RichText::new(device_text).heading()).selectable(false));
+    let response =
+        ui.add(egui::Label::new(egui::RichText::new(device_text).heading()).selectable(false));
+    if device.is_none() && system_specs.device_model_unmatched {
+        ui.add(
+            egui::Label::new(RichText::new(format!("({})", t!("header.unmatched"))).weak())
+                .selectable(false),
+        )
+        .on_hover_text(t!("header.unmatched_hint"));
+    }
+    let mut details = Vec::new();
+    if let Some(info) = device {
+        details.push(info.name.to_string());
+        details.push(format!("VID:PID {:04x}:{:04x}", Device::RAZER_VID, info.pid));
+        if let Some(firmware) = firmware_version {
+            details.push(t!("header.firmware", firmware));
+        }
+    }
+    details.extend(
+        system_specs
+            .gpu_models
+            .iter()
+            .filter(|gpu| *gpu != "Unknown")
+            .map(|gpu| format!("GPU: {}", gpu)),
+    );
+    if system_specs.integrated_gpu_only {
+        details.push(t!("header.integrated_gpu_only").to_string());
+    }
+    if !details.is_empty() {
+        response.on_hover_text(details.join("\n"));
+    }
 }
 
 /// Renders status messages with fade animation
@@ -53,43 +186,54 @@ fn render_status_messages(
     ui: &mut egui::Ui,
     ctx: &egui::Context,
     message_manager: &MessageManager,
-    device: &Option<Device>,
+    device: &Option<Descriptor>,
     detecting_device: bool,
 ) {
-    if let Some(current_message) = message_manager.get_current_message() {
-        let elapsed = current_message.age_seconds();
+    let messages = message_manager.visible_messages();
+    if !messages.is_empty() {
+        // Stacked messages share one right-aligned column, each fading on its own timer
+        ui.with_layout(Layout::top_down(Align::Max), |ui| {
+            for message in messages {
+                let elapsed = message.age_seconds();
 
-        // Calculate fade and apply to message
-        let (base_color, icon) = get_message_style_from_type(&current_message.message_type);
-        let alpha = calculate_fade_alpha(elapsed);
-        let faded_color = apply_alpha_to_color(base_color, alpha);
+                // Calculate fade and apply to message
+                let (base_color, icon) = get_message_style_from_type(&message.message_type);
+                let alpha = calculate_fade_alpha(elapsed, message.duration.as_secs_f32());
+                let faded_color = apply_alpha_to_color(base_color, alpha);
 
-        ui.add(
-            egui::Label::new(
-                RichText::new(format!("{} {}", icon, current_message.content)).color(faded_color),
-            )
-            .selectable(false),
-        );
-
-        // Request repaint for smooth animation
-        if current_message.should_fade() {
-            ctx.request_repaint();
-        }
+                ui.add(
+                    egui::Label::new(
+                        RichText::new(format!("{} {}", icon, message.content)).color(faded_color),
+                    )
+                    .selectable(false),
+                );
+
+                // Request repaint for smooth animation, or wake up when the fade starts
+                if message.should_fade() {
+                    ctx.request_repaint();
+                } else {
+                    ctx.request_repaint_after_secs(message.duration.as_secs_f32() - elapsed);
+                }
+            }
+        });
     } else {
         // Show connection status when no device detected
         if device.is_none() {
             if detecting_device {
                 ui.add(
                     egui::Label::new(
-                        RichText::new("🔎 Detecting device…").color(Color32::LIGHT_BLUE),
+                        RichText::new(format!("🔎 {}", t!("header.detecting")))
+                            .color(Color32::LIGHT_BLUE),
                     )
                     .selectable(false),
                 );
                 ctx.request_repaint();
             } else {
                 ui.add(
-                    egui::Label::new(RichText::new("❌ No device detected").color(Color32::RED))
-                        .selectable(false),
+                    egui::Label::new(
+                        RichText::new(format!("❌ {}", t!("header.no_device"))).color(Color32::RED),
+                    )
+                    .selectable(false),
                 );
             }
         }
@@ -97,7 +241,7 @@ fn render_status_messages(
 }
 
 /// Message style based on type
-fn get_message_style_from_type(message_type: &MessageType) -> (Color32, &'static str) {
+pub fn get_message_style_from_type(message_type: &MessageType) -> (Color32, &'static str) {
     match message_type {
         MessageType::Info => (Color32::LIGHT_BLUE, "ℹ"),
         MessageType::Error => (Color32::RED, "⚠"),
@@ -105,11 +249,12 @@ fn get_message_style_from_type(message_type: &MessageType) -> (Color32, &'static
 }
 
 /// Calculates alpha value for fade animation
-fn calculate_fade_alpha(elapsed: f32) -> f32 {
-    if elapsed < FADE_START_TIME {
+/// Fading starts when the message's display time is up
+fn calculate_fade_alpha(elapsed: f32, fade_start: f32) -> f32 {
+    if elapsed < fade_start {
         1.0
     } else {
-        let fade_progress = (elapsed - FADE_START_TIME) / FADE_DURATION;
+        let fade_progress = (elapsed - fade_start) / FADE_DURATION.as_secs_f32();
         (1.0 - fade_progress).max(0.0)
     }
 }
@@ -0,0 +1,77 @@
+use crate::i18n::t;
+use crate::messaging::{MessageManager, MessageType};
+use crate::ui::header::get_message_style_from_type;
+use eframe::egui::{self, RichText};
+
+const HISTORY_PANEL_HEIGHT: f32 = 140.0;
+
+/// Renders the message history panel, newest first.
+///
+/// The list stays pinned to the newest entry while scrolled to the top; when the user
+/// has scrolled down, the view is shifted by the rows added above so it does not jump.
+pub fn render_message_history(ui: &mut egui::Ui, message_manager: &MessageManager) {
+    let history = message_manager.history();
+    let count_id = egui::Id::new("message_history_count");
+    let seen_count = ui.ctx().data(|d| d.get_temp::<usize>(count_id)).unwrap_or(history.len());
+    let added = history.len().saturating_sub(seen_count);
+
+    ui.horizontal(|ui| {
+        ui.add(egui::Label::new(format!("📜 {}", t!("history.title"))).selectable(false));
+        ui.add(
+            egui::Label::new(RichText::new(format!("({})", history.len())).weak())
+                .selectable(false),
+        );
+    });
+    ui.separator();
+
+    let row_height = ui.text_style_height(&egui::TextStyle::Body) + ui.spacing().item_spacing.y;
+    let mut scroll = egui::ScrollArea::vertical()
+        .id_salt("message_history_scroll")
+        .max_height(HISTORY_PANEL_HEIGHT)
+        .auto_shrink([false, true]);
+
+    // Same id derivation ScrollArea uses internally for its persisted state
+    let state_id = ui.make_persistent_id(egui::Id::new("message_history_scroll"));
+    let current_offset = egui::scroll_area::State::load(ui.ctx(), state_id)
+        .map(|state| state.offset.y)
+        .unwrap_or(0.0);
+    if added > 0 && current_offset > 0.0 {
+        scroll = scroll.vertical_scroll_offset(current_offset + added as f32 * row_height);
+    }
+
+    scroll.show(ui, |ui| {
+        if history.is_empty() {
+            ui.add(egui::Label::new(RichText::new(t!("history.empty")).weak()).selectable(false));
+        }
+
+        for message in history.iter().rev() {
+            let (color, icon) = get_message_style_from_type(&message.message_type);
+            let mut text = RichText::new(format!("{} {}", icon, message.content)).color(color);
+            if message.message_type == MessageType::Error {
+                text = text.strong();
+            }
+
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::Label::new(RichText::new(format_age(message.age_seconds())).weak())
+                        .selectable(false),
+                );
+                ui.add(egui::Label::new(text).wrap());
+            });
+        }
+    });
+
+    ui.ctx().data_mut(|d| d.insert_temp(count_id, history.len()));
+}
+
+/// Compact relative timestamp ("12s ago", "3m ago", "1h ago")
+fn format_age(seconds: f32) -> String {
+    let seconds = seconds as u64;
+    if seconds < 60 {
+        t!("history.seconds_ago", format!("{:>2}", seconds))
+    } else if seconds < 3600 {
+        t!("history.minutes_ago", format!("{:>2}", seconds / 60))
+    } else {
+        t!("history.hours_ago", format!("{:>2}", seconds / 3600))
+    }
+}
@@ -1,3 +1,5 @@
+use super::a11y;
+use crate::i18n::{t, tr};
 use eframe::egui;
 
 // Discrete brightness levels that the keyboard actually supports
@@ -20,32 +22,36 @@ const BRIGHTNESS_LEVELS: &[u8] = &[
     209, // Step 14
     225, // Step 15
 ];
+pub const MAX_BRIGHTNESS_STEP: usize = BRIGHTNESS_LEVELS.len() - 1;
 
 /// Actions that can be triggered from the lighting UI
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct LightingAction {
     /// Logo lighting mode to set
     pub logo_mode: Option<String>,
     /// Brightness value to set (0-255 raw value)
     pub brightness: Option<u8>,
+    /// Keyboard backlight power to set
+    pub keyboard_backlight: Option<bool>,
+    /// Whether the brightness quick toggle was clicked (dim to 0 or restore the saved step)
+    pub brightness_toggle: bool,
+    /// Static keyboard color to set
+    pub keyboard_color: Option<[u8; 3]>,
     /// Whether the lights always on setting was toggled
     pub lights_always_on: bool,
     /// Whether the brightness slider is currently being interacted with
     pub slider_active: Option<bool>,
 }
 
-impl Default for LightingAction {
-    fn default() -> Self {
-        Self { logo_mode: None, brightness: None, lights_always_on: false, slider_active: None }
-    }
-}
-
 /// Renders the lighting section UI
 ///
 /// # Arguments
 /// * `ui` - The egui UI context
 /// * `logo_mode` - The current logo lighting mode
+/// * `logo_supported` - Whether the device answered the logo query; `false` disables the row
 /// * `temp_brightness_step` - Mutable reference to brightness step index (0-15)
+/// * `keyboard_backlight` - Backlight power, `None` hides the Off toggle (unsupported device)
+/// * `keyboard_color` - Current keyboard color, `None` hides the picker (unsupported device)
 /// * `lights_always_on` - Mutable reference to lights always on setting
 ///
 /// # Returns
@@ -53,20 +59,32 @@ impl Default for LightingAction {
 pub fn render_lighting_section(
     ui: &mut egui::Ui,
     logo_mode: &str,
+    logo_supported: bool,
     temp_brightness_step: &mut usize,
+    keyboard_backlight: Option<bool>,
+    keyboard_color: Option<&mut [u8; 3]>,
     lights_always_on: &mut bool,
 ) -> LightingAction {
     let mut action = LightingAction::default();
 
     ui.group(|ui| {
-        ui.add(egui::Label::new("💡 Lighting").selectable(false));
+        ui.add(egui::Label::new(format!("💡 {}", t!("lighting.title"))).selectable(false));
         ui.separator();
 
         // Logo Mode Selection
-        render_logo_mode_selection(ui, logo_mode, &mut action);
+        ui.add_enabled_ui(logo_supported, |ui| {
+            render_logo_mode_selection(ui, logo_mode, &mut action);
+        })
+        .response
+        .on_disabled_hover_text(t!("lighting.unsupported"));
 
         // Brightness Slider
-        render_brightness_controls(ui, temp_brightness_step, &mut action);
+        render_brightness_controls(ui, temp_brightness_step, keyboard_backlight, &mut action);
+
+        // Keyboard Color Picker
+        if let Some(keyboard_color) = keyboard_color {
+            render_keyboard_color(ui, keyboard_color, &mut action);
+        }
 
         // Lights Always On Toggle
         render_always_on_toggle(ui, lights_always_on, &mut action);
@@ -78,12 +96,19 @@ pub fn render_lighting_section(
 /// Renders the logo mode selection controls
 fn render_logo_mode_selection(ui: &mut egui::Ui, logo_mode: &str, action: &mut LightingAction) {
     ui.horizontal(|ui| {
-        ui.add(egui::Label::new("Logo Mode:").selectable(false));
-        const LOGO_MODES: &[&str] = &["Static", "Breathing", "Off"];
-
-        for mode in LOGO_MODES {
+        ui.add(egui::Label::new(t!("lighting.logo_mode")).selectable(false));
+        // Device mode names paired with the key of their translated label
+        const LOGO_MODES: &[(&str, &str)] = &[
+            ("Static", "lighting.logo_static"),
+            ("Breathing", "lighting.logo_breathing"),
+            ("Off", "lighting.logo_off"),
+        ];
+
+        for (mode, label) in LOGO_MODES {
             let selected = logo_mode == *mode;
-            if ui.selectable_label(selected, *mode).clicked() && !selected {
+            let response = ui.selectable_label(selected, tr(label));
+            a11y::toggle(&response, &t!("lighting.a11y_logo_mode", tr(label)), selected);
+            if response.clicked() && !selected {
                 action.logo_mode = Some(mode.to_string());
             }
         }
@@ -94,20 +119,48 @@ fn render_logo_mode_selection(ui: &mut egui::Ui, logo_mode: &str, action: &mut L
 fn render_brightness_controls(
     ui: &mut egui::Ui,
     temp_brightness_step: &mut usize,
+    keyboard_backlight: Option<bool>,
     action: &mut LightingAction,
 ) {
     ui.horizontal(|ui| {
-        ui.add(egui::Label::new("Keyboard Brightness:").selectable(false));
+        let label = ui.add(egui::Label::new(t!("lighting.keyboard_brightness")).selectable(false));
+
+        // The lowest step still lights the keys; Off is a separate power state
+        let backlight_off = keyboard_backlight == Some(false);
+        if keyboard_backlight.is_some() {
+            let response = ui.selectable_label(backlight_off, t!("lighting.backlight_off"));
+            a11y::toggle(&response, t!("lighting.a11y_backlight_off"), backlight_off);
+            if response.on_hover_text(t!("lighting.backlight_off_hint")).clicked() {
+                action.keyboard_backlight = Some(backlight_off);
+            }
+        }
 
         // Ensure step index is within bounds
-        *temp_brightness_step = (*temp_brightness_step).min(BRIGHTNESS_LEVELS.len() - 1);
+        *temp_brightness_step = (*temp_brightness_step).min(MAX_BRIGHTNESS_STEP);
+
+        let lit = *temp_brightness_step > 0;
+        let toggle_response = ui.add_enabled(!backlight_off, egui::Button::selectable(lit, "💡"));
+        a11y::toggle(&toggle_response, t!("lighting.a11y_lights"), lit);
+        if toggle_response
+            .on_hover_text(if lit { t!("lighting.dim_hint") } else { t!("lighting.restore_hint") })
+            .clicked()
+        {
+            action.brightness_toggle = true;
+        }
 
         let mut step_index = *temp_brightness_step;
-        let brightness_response = ui.add(
-            egui::Slider::new(&mut step_index, 0..=(BRIGHTNESS_LEVELS.len() - 1))
-                .custom_formatter(|val, _| format!("{}", val as usize))
-                .custom_parser(|s| s.parse::<f64>().ok()),
-        );
+        let brightness_response = ui
+            .add_enabled(
+                !backlight_off,
+                egui::Slider::new(&mut step_index, 0..=MAX_BRIGHTNESS_STEP)
+                    .custom_formatter(|val, _| format!("{}%", step_index_to_percent(val as usize)))
+                    .custom_parser(|s| {
+                        let percent = s.trim().trim_end_matches('%').parse::<f64>().ok()?;
+                        Some((percent / 100.0 * MAX_BRIGHTNESS_STEP as f64).round())
+                    }),
+            )
+            .labelled_by(label.id)
+            .on_hover_text(t!("lighting.brightness_shortcut"));
 
         // Check if the value actually changed
         let value_changed = step_index != *temp_brightness_step;
@@ -133,6 +186,20 @@ fn render_brightness_controls(
     });
 }
 
+/// Renders the static keyboard color picker
+fn render_keyboard_color(
+    ui: &mut egui::Ui,
+    keyboard_color: &mut [u8; 3],
+    action: &mut LightingAction,
+) {
+    ui.horizontal(|ui| {
+        let label = ui.add(egui::Label::new(t!("lighting.keyboard_color")).selectable(false));
+        if ui.color_edit_button_srgb(keyboard_color).labelled_by(label.id).changed() {
+            action.keyboard_color = Some(*keyboard_color);
+        }
+    });
+}
+
 /// Renders the always on toggle control
 fn render_always_on_toggle(
     ui: &mut egui::Ui,
@@ -140,12 +207,22 @@ fn render_always_on_toggle(
     action: &mut LightingAction,
 ) {
     ui.horizontal(|ui| {
-        if ui.checkbox(lights_always_on, "Keyboard Backlight Always On").clicked() {
+        if ui.checkbox(lights_always_on, t!("lighting.always_on")).clicked() {
             action.lights_always_on = true;
         }
     });
 }
 
+/// Percentage shown for a step index (the top step is 100%)
+pub fn step_index_to_percent(step: usize) -> u8 {
+    (step.min(MAX_BRIGHTNESS_STEP) as f32 / MAX_BRIGHTNESS_STEP as f32 * 100.0).round() as u8
+}
+
+/// Raw brightness value (0-255) for a step index
+pub fn step_index_to_raw_brightness(step: usize) -> u8 {
+    BRIGHTNESS_LEVELS[step.min(MAX_BRIGHTNESS_STEP)]
+}
+
 /// Converts raw brightness (0-255) to the closest supported step index
 pub fn raw_brightness_to_step_index(brightness: u8) -> usize {
     BRIGHTNESS_LEVELS
@@ -1,8 +1,16 @@
 // UI modules
 
+pub mod a11y;
+pub mod app_rules;
 pub mod battery;
+pub mod compact;
+pub mod confirm;
+pub mod elevation;
 pub mod fan;
 pub mod footer;
 pub mod header;
+pub mod history;
 pub mod lighting;
 pub mod performance;
+pub mod profiles;
+pub mod settings;
@@ -1,3 +1,5 @@
+use super::a11y;
+use crate::i18n::{t, tr};
 use eframe::egui::{self, Align, Color32, Layout, RichText};
 use librazer::types::{CpuBoost, GpuBoost, PerfMode};
 
@@ -10,6 +12,53 @@ const ORANGE_COLOR: Color32 = Color32::from_rgb(255, 165, 0);
 // Muted green for disabled-but-active Custom state
 const CUSTOM_ACTIVE_FILL: Color32 = Color32::from_rgb(40, 80, 55);
 const CUSTOM_ACTIVE_STROKE: Color32 = Color32::from_rgb(70, 130, 90);
+// Outline accent on high-power modes while the CPU is already running hot
+const THERMAL_WARNING_STROKE: Color32 = Color32::from_rgb(230, 120, 20);
+/// Display order of the standard modes
+pub const MODE_ORDER: [PerfMode; 5] = [
+    PerfMode::Battery,
+    PerfMode::Silent,
+    PerfMode::Balanced,
+    PerfMode::Performance,
+    PerfMode::Hyperboost,
+];
+/// Number keys selecting the shown modes, left to right (see `ordered_modes`)
+pub const MODE_KEYS: [egui::Key; 5] =
+    [egui::Key::Num1, egui::Key::Num2, egui::Key::Num3, egui::Key::Num4, egui::Key::Num5];
+// Modes that cannot help once the CPU is thermal-throttling
+const HIGH_POWER_MODES: [PerfMode; 2] = [PerfMode::Performance, PerfMode::Hyperboost];
+// Key of what each mode does, shown on hover
+const MODE_DESCRIPTIONS: [(PerfMode, &str); 6] = [
+    (PerfMode::Battery, "performance.describe_battery"),
+    (PerfMode::Silent, "performance.describe_silent"),
+    (PerfMode::Balanced, "performance.describe_balanced"),
+    (PerfMode::Performance, "performance.describe_performance"),
+    (PerfMode::Hyperboost, "performance.describe_hyperboost"),
+    (PerfMode::Custom, "performance.describe_custom"),
+];
+
+/// Hover description for `mode`, if one is known
+fn mode_description(mode: PerfMode) -> Option<&'static str> {
+    MODE_DESCRIPTIONS.iter().find(|(known, _)| *known == mode).map(|(_, key)| tr(key))
+}
+
+/// Standard modes in display order: `MODE_ORDER` first, then any other modes alphabetically.
+/// Custom is left out since it is drawn separately on the right.
+pub fn ordered_modes(available: &[PerfMode]) -> Vec<PerfMode> {
+    order_modes(available, &MODE_ORDER)
+}
+
+/// `ordered_modes` against any fixed order
+fn order_modes(available: &[PerfMode], order: &[PerfMode]) -> Vec<PerfMode> {
+    let mut modes: Vec<PerfMode> =
+        available.iter().copied().filter(|mode| *mode != PerfMode::Custom).collect();
+    modes.sort_by_cached_key(|mode| {
+        let priority = order.iter().position(|known| known == mode);
+        (priority.unwrap_or(order.len()), format!("{:?}", mode))
+    });
+    modes.dedup();
+    modes
+}
 
 // Actions that can be triggered from the performance UI
 #[derive(Debug, Clone, PartialEq)]
@@ -22,7 +71,7 @@ pub enum PerformanceAction {
 }
 
 // Renders the performance section UI
-
+#[allow(clippy::too_many_arguments)]
 pub fn render_performance_section(
     ui: &mut egui::Ui,
     current_performance_mode: &str,
@@ -35,9 +84,11 @@ pub fn render_performance_section(
     allowed_cpu_boosts: &[CpuBoost],
     allowed_gpu_boosts: &[GpuBoost],
     disallowed_pairs: &[(CpuBoost, GpuBoost)],
+    pairs_confirmed: bool,
     base_cpu_boosts: &[CpuBoost],
     base_gpu_boosts: &[GpuBoost],
     no_device: bool,
+    thermal_warning: Option<&str>,
 ) -> PerformanceAction {
     let mut action = PerformanceAction::None;
 
@@ -52,6 +103,7 @@ pub fn render_performance_section(
             ac_power,
             available_modes,
             base_modes,
+            thermal_warning,
         );
 
         // Custom boost controls only when in Custom mode, UNLESS no device detected and hidden toggle used (discovery UX)
@@ -74,6 +126,7 @@ pub fn render_performance_section(
                 allowed_cpu_boosts,
                 allowed_gpu_boosts,
                 disallowed_pairs,
+                pairs_confirmed,
                 base_cpu_boosts,
                 base_gpu_boosts,
             ) {
@@ -86,6 +139,7 @@ pub fn render_performance_section(
 }
 
 // Renders CPU / GPU boost selectors when Custom is active (or debug mode to preview UI)
+#[allow(clippy::too_many_arguments)]
 fn render_custom_boosts(
     ui: &mut egui::Ui,
     ac_power: bool,
@@ -96,82 +150,89 @@ fn render_custom_boosts(
     allowed_cpu: &[CpuBoost],
     allowed_gpu: &[GpuBoost],
     disallowed_pairs: &[(CpuBoost, GpuBoost)],
+    pairs_confirmed: bool,
     base_cpu: &[CpuBoost],
     base_gpu: &[GpuBoost],
 ) -> Option<PerformanceAction> {
     let mut out = None;
-    // CPU row: left side label + standard boosts, right-aligned Undervolt (eye toggle only)
-    let row_height = ui.spacing().interact_size.y;
-    let full_width = ui.available_width();
-    ui.allocate_ui_with_layout(
-        egui::Vec2::new(full_width, row_height),
-        Layout::right_to_left(Align::Center),
-        |ui| {
-            // Right-most: Undervolt shown only when hidden boosts are revealed via eye toggle
-            let showing_hidden =
-                ui.ctx().data(|d| d.get_temp::<bool>("perf_hidden_show".into()).unwrap_or(false));
-            if showing_hidden {
-                let boost = CpuBoost::Undervolt;
-                let label = "Undervolt";
-                let selected = boost == current_cpu;
-                let color = get_button_color(ac_power, selected);
-                let style_text = if selected {
-                    egui::RichText::new(label).color(Color32::WHITE)
-                } else {
-                    egui::RichText::new(label).italics().color(Color32::from_gray(170))
-                };
-                let mut btn = egui::Button::new(style_text);
-                btn = btn.fill(if selected { color } else { Color32::TRANSPARENT }).stroke(
-                    egui::Stroke::new(1.0, if selected { color } else { Color32::from_gray(90) }),
-                );
-                let response = ui.add_enabled(custom_active, btn);
-                if response.clicked() && !selected {
-                    out = Some(PerformanceAction::SetCpuBoost(boost));
-                }
-                if !custom_active {
-                    response
-                        .on_hover_text("Hidden preset (Undervolt). Activate Custom mode to apply.");
-                } else {
-                    response
-                        .on_hover_text("Hidden preset (Undervolt). Behavior not fully confirmed.");
-                }
+    // CPU row: left side label + standard boosts, right-aligned Undervolt (eye toggle only).
+    // Built left to right so Tab reaches the standard boosts before Undervolt.
+    ui.horizontal(|ui| {
+        ui.add(egui::Label::new("CPU").selectable(false));
+        for boost in allowed_cpu.iter().copied() {
+            let label = format!("{:?}", boost);
+            let selected = boost == current_cpu;
+            let color = get_button_color(ac_power, selected);
+            let mut btn =
+                egui::Button::new(egui::RichText::new(&label).color(label_color(ui, selected)));
+            btn = btn
+                .fill(if selected { color } else { Color32::TRANSPARENT })
+                .stroke(egui::Stroke::new(1.0, color));
+            let invalid_combo = !debug_mode
+                && disallowed_pairs.iter().any(|(c, g)| *c == boost && *g == current_gpu);
+            let is_extra = !base_cpu.contains(&boost);
+            if is_extra && !selected {
+                // Dim & italicize extra (revealed) boosts
+                btn = egui::Button::new(
+                    egui::RichText::new(&label).italics().color(ui.visuals().weak_text_color()),
+                )
+                .fill(Color32::TRANSPARENT)
+                .stroke(egui::Stroke::new(1.0, muted_stroke_color(ui)));
+            }
+            let response = ui.add_enabled(custom_active && !invalid_combo, btn);
+            a11y::toggle(&response, &t!("performance.a11y_cpu_boost", label), selected);
+            if response.clicked() && !selected {
+                out = Some(PerformanceAction::SetCpuBoost(boost));
             }
-            // Left group: label + standard boosts
-            ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                ui.add(egui::Label::new("CPU").selectable(false));
-                for boost in allowed_cpu.iter().copied() {
-                    let label = format!("{:?}", boost);
+            if !custom_active {
+                response.on_hover_text(t!("performance.activate_custom"));
+            } else if invalid_combo {
+                response.on_hover_text(t!("performance.combination_not_allowed"));
+            } else if !pairs_confirmed {
+                response.on_hover_text(t!("performance.unconfirmed_pairs"));
+            }
+        }
+
+        // Right-most: Undervolt shown only when hidden boosts are revealed via eye toggle
+        let showing_hidden =
+            ui.ctx().data(|d| d.get_temp::<bool>("perf_hidden_show".into()).unwrap_or(false));
+        if showing_hidden {
+            let width = ui.available_width();
+            let height = ui.spacing().interact_size.y;
+            ui.allocate_ui_with_layout(
+                egui::Vec2::new(width, height),
+                Layout::right_to_left(Align::Center),
+                |ui| {
+                    let boost = CpuBoost::Undervolt;
+                    let label = "Undervolt";
                     let selected = boost == current_cpu;
                     let color = get_button_color(ac_power, selected);
-                    let mut btn =
-                        egui::Button::new(egui::RichText::new(&label).color(Color32::WHITE));
-                    btn = btn
-                        .fill(if selected { color } else { Color32::TRANSPARENT })
-                        .stroke(egui::Stroke::new(1.0, color));
-                    let invalid_combo = !debug_mode
-                        && disallowed_pairs.iter().any(|(c, g)| *c == boost && *g == current_gpu);
-                    let is_extra = !base_cpu.contains(&boost);
-                    if is_extra && !selected {
-                        // Dim & italicize extra (revealed) boosts
-                        btn = egui::Button::new(
-                            egui::RichText::new(&label).italics().color(Color32::from_gray(170)),
-                        )
-                        .fill(Color32::TRANSPARENT)
-                        .stroke(egui::Stroke::new(1.0, Color32::from_gray(90)));
-                    }
-                    let response = ui.add_enabled(custom_active && !invalid_combo, btn);
+                    let style_text = if selected {
+                        egui::RichText::new(label).color(Color32::WHITE)
+                    } else {
+                        egui::RichText::new(label).italics().color(ui.visuals().weak_text_color())
+                    };
+                    let mut btn = egui::Button::new(style_text);
+                    btn = btn.fill(if selected { color } else { Color32::TRANSPARENT }).stroke(
+                        egui::Stroke::new(
+                            1.0,
+                            if selected { color } else { muted_stroke_color(ui) },
+                        ),
+                    );
+                    let response = ui.add_enabled(custom_active, btn);
+                    a11y::toggle(&response, &t!("performance.a11y_cpu_boost", label), selected);
                     if response.clicked() && !selected {
                         out = Some(PerformanceAction::SetCpuBoost(boost));
                     }
                     if !custom_active {
-                        response.on_hover_text("Activate Custom mode to apply");
-                    } else if invalid_combo {
-                        response.on_hover_text("Combination not allowed by firmware descriptor");
+                        response.on_hover_text(t!("performance.undervolt_inactive"));
+                    } else {
+                        response.on_hover_text(t!("performance.undervolt_unconfirmed"));
                     }
-                }
-            });
-        },
-    );
+                },
+            );
+        }
+    });
 
     // GPU row
     ui.horizontal(|ui| {
@@ -180,7 +241,8 @@ fn render_custom_boosts(
             let label = format!("{:?}", boost);
             let selected = boost == current_gpu;
             let color = get_button_color(ac_power, selected);
-            let mut btn = egui::Button::new(egui::RichText::new(&label).color(Color32::WHITE));
+            let mut btn =
+                egui::Button::new(egui::RichText::new(&label).color(label_color(ui, selected)));
             btn = btn
                 .fill(if selected { color } else { Color32::TRANSPARENT })
                 .stroke(egui::Stroke::new(1.0, color));
@@ -189,19 +251,22 @@ fn render_custom_boosts(
             let is_extra = !base_gpu.contains(&boost);
             if is_extra && !selected {
                 btn = egui::Button::new(
-                    egui::RichText::new(&label).italics().color(Color32::from_gray(170)),
+                    egui::RichText::new(&label).italics().color(ui.visuals().weak_text_color()),
                 )
                 .fill(Color32::TRANSPARENT)
-                .stroke(egui::Stroke::new(1.0, Color32::from_gray(90)));
+                .stroke(egui::Stroke::new(1.0, muted_stroke_color(ui)));
             }
             let response = ui.add_enabled(custom_active && !invalid_combo, btn);
+            a11y::toggle(&response, &t!("performance.a11y_gpu_boost", label), selected);
             if response.clicked() && !selected {
                 out = Some(PerformanceAction::SetGpuBoost(boost));
             }
             if !custom_active {
-                response.on_hover_text("Activate Custom mode to apply");
+                response.on_hover_text(t!("performance.activate_custom"));
             } else if invalid_combo {
-                response.on_hover_text("Combination not allowed by firmware descriptor");
+                response.on_hover_text(t!("performance.combination_not_allowed"));
+            } else if !pairs_confirmed {
+                response.on_hover_text(t!("performance.unconfirmed_pairs"));
             }
         }
     });
@@ -212,7 +277,7 @@ fn render_custom_boosts(
 // Renders the performance section header with power status
 fn render_performance_header(ui: &mut egui::Ui, ac_power: bool, show_probe_button: bool) {
     ui.horizontal(|ui| {
-        ui.add(egui::Label::new("🚀 Performance Mode").selectable(false));
+        ui.add(egui::Label::new(format!("🚀 {}", t!("performance.title"))).selectable(false));
 
         // Power status indicator
         let (power_icon, power_color) =
@@ -228,9 +293,11 @@ fn render_performance_header(ui: &mut egui::Ui, ac_power: bool, show_probe_butto
                     let highlight = AC_SELECTED_COLOR; // reuse green
                     eye_btn = eye_btn.fill(highlight).stroke(egui::Stroke::new(1.0, highlight));
                 } else {
-                    eye_btn = eye_btn.stroke(egui::Stroke::new(1.0, Color32::from_gray(90)));
+                    eye_btn = eye_btn.stroke(egui::Stroke::new(1.0, muted_stroke_color(ui)));
                 }
-                let resp = ui.add(eye_btn).on_hover_text("Show/Hide hidden modes & boosts");
+                let resp = ui.add(eye_btn);
+                a11y::toggle(&resp, t!("performance.a11y_show_hidden"), active);
+                let resp = resp.on_hover_text(t!("performance.show_hidden_hint"));
                 if resp.clicked() {
                     ui.ctx().data_mut(|d| d.insert_temp("perf_toggle_hidden".into(), true));
                 }
@@ -239,96 +306,77 @@ fn render_performance_header(ui: &mut egui::Ui, ac_power: bool, show_probe_butto
                 egui::Label::new(RichText::new(power_icon).color(power_color)).selectable(false),
             );
             ui.add(
-                egui::Label::new(RichText::new(if ac_power { "AC Power" } else { "Battery" }))
-                    .selectable(false),
+                egui::Label::new(RichText::new(if ac_power {
+                    t!("performance.ac_power")
+                } else {
+                    t!("performance.battery")
+                }))
+                .selectable(false),
             );
         });
     });
 }
 
-// Renders the performance mode selection buttons
+// Renders the performance mode selection buttons.
+// `thermal_warning` is the hover text shown on high-power modes while the CPU runs hot.
 fn render_performance_modes(
     ui: &mut egui::Ui,
     current_performance_mode: &str,
     ac_power: bool,
     available_modes: &[PerfMode],
     base_modes: &[PerfMode],
+    thermal_warning: Option<&str>,
 ) -> PerformanceAction {
     let mut action = PerformanceAction::None;
 
     ui.horizontal(|ui| {
-        // Define the desired order for performance modes
-        let ordered_modes = [
-            PerfMode::Battery,
-            PerfMode::Silent,
-            PerfMode::Balanced,
-            PerfMode::Performance,
-            PerfMode::Hyperboost,
-        ];
-
         // Render main performance modes (active ones) in preferred order
         if ui.ctx().data(|d| d.get_temp::<bool>("perf_toggle_hidden".into()).unwrap_or(false)) {
             ui.ctx().data_mut(|d| d.remove::<bool>("perf_toggle_hidden".into()));
             action = PerformanceAction::ToggleHidden;
         }
-        let base_vec: Vec<PerfMode> = base_modes.iter().cloned().collect();
+        let base_vec: Vec<PerfMode> = base_modes.to_vec();
         let showing_hidden = available_modes.iter().any(|m| !base_vec.contains(m));
 
         // Left-aligned standard modes (exclude Custom)
-        let mut rendered: Vec<PerfMode> = Vec::new();
-        for mode in &ordered_modes {
-            if available_modes.contains(mode) && *mode != PerfMode::Custom {
-                let mode_str = format!("{:?}", mode);
-                let selected = current_performance_mode == mode_str;
-                let button_color = get_button_color(ac_power, selected);
-                let is_hidden = showing_hidden && !base_vec.contains(mode);
-                let mut btn =
-                    egui::Button::new(RichText::new(&mode_str).color(if is_hidden && !selected {
-                        Color32::from_gray(160)
-                    } else {
-                        Color32::WHITE
-                    }));
-                btn = btn.fill(if selected { button_color } else { Color32::TRANSPARENT }).stroke(
-                    egui::Stroke::new(
-                        1.0,
-                        if is_hidden && !selected { Color32::from_gray(90) } else { button_color },
-                    ),
-                );
-                let response = ui.add(btn);
-                if response.clicked() && !selected {
-                    action = PerformanceAction::SetPerformanceMode(mode_str);
-                }
-                if is_hidden {
-                    response.on_hover_text("Hidden / unsupported by descriptor");
-                }
-                rendered.push(*mode);
+        for (position, mode) in ordered_modes(available_modes).into_iter().enumerate() {
+            let mode_str = format!("{:?}", mode);
+            let selected = current_performance_mode == mode_str;
+            let button_color = get_button_color(ac_power, selected);
+            let is_hidden = showing_hidden && !base_vec.contains(&mode);
+            let mut btn =
+                egui::Button::new(RichText::new(&mode_str).color(if is_hidden && !selected {
+                    ui.visuals().weak_text_color()
+                } else {
+                    label_color(ui, selected)
+                }));
+            let warning = thermal_warning.filter(|_| HIGH_POWER_MODES.contains(&mode));
+            let stroke_color = if warning.is_some() {
+                THERMAL_WARNING_STROKE
+            } else if is_hidden && !selected {
+                muted_stroke_color(ui)
+            } else {
+                button_color
+            };
+            btn = btn
+                .fill(if selected { button_color } else { Color32::TRANSPARENT })
+                .stroke(egui::Stroke::new(1.0, stroke_color));
+            let response = ui.add(btn);
+            a11y::toggle(&response, &t!("performance.a11y_mode", mode_str), selected);
+            if response.clicked() && !selected {
+                action = PerformanceAction::SetPerformanceMode(mode_str);
             }
-        }
-        for mode in available_modes {
-            if *mode != PerfMode::Custom && !rendered.contains(mode) {
-                let mode_str = format!("{:?}", mode);
-                let selected = current_performance_mode == mode_str;
-                let button_color = get_button_color(ac_power, selected);
-                let is_hidden = showing_hidden && !base_vec.contains(mode);
-                let mut btn =
-                    egui::Button::new(RichText::new(&mode_str).color(if is_hidden && !selected {
-                        Color32::from_gray(160)
-                    } else {
-                        Color32::WHITE
-                    }));
-                btn = btn.fill(if selected { button_color } else { Color32::TRANSPARENT }).stroke(
-                    egui::Stroke::new(
-                        1.0,
-                        if is_hidden && !selected { Color32::from_gray(90) } else { button_color },
-                    ),
-                );
-                let response = ui.add(btn);
-                if response.clicked() && !selected {
-                    action = PerformanceAction::SetPerformanceMode(mode_str);
-                }
-                if is_hidden {
-                    response.on_hover_text("Hidden / unsupported by descriptor");
-                }
+            let shortcut =
+                (position < MODE_KEYS.len()).then(|| t!("performance.mode_shortcut", position + 1));
+            let hover = [mode_description(mode), warning, shortcut.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join("\n");
+            if is_hidden {
+                response.on_hover_text(t!("performance.hidden_mode"));
+            } else if !hover.is_empty() {
+                response.on_hover_text(hover);
             }
         }
 
@@ -345,19 +393,28 @@ fn render_performance_modes(
                     let fill_color =
                         if selected { CUSTOM_ACTIVE_FILL } else { Color32::TRANSPARENT };
                     let stroke_color =
-                        if selected { CUSTOM_ACTIVE_STROKE } else { Color32::from_gray(80) };
-                    let btn = egui::Button::new(RichText::new(&custom_str).color(Color32::WHITE))
-                        .fill(fill_color)
-                        .stroke(egui::Stroke::new(1.0, stroke_color));
+                        if selected { CUSTOM_ACTIVE_STROKE } else { muted_stroke_color(ui) };
+                    let btn = egui::Button::new(
+                        RichText::new(&custom_str).color(label_color(ui, selected)),
+                    )
+                    .fill(fill_color)
+                    .stroke(egui::Stroke::new(1.0, stroke_color));
                     let response = ui.add(btn);
+                    a11y::toggle(&response, &t!("performance.a11y_mode", custom_str), selected);
                     if response.clicked() && !selected {
                         action = PerformanceAction::SetPerformanceMode(custom_str);
                     }
-                    if selected {
-                        response.on_hover_text("Custom mode active");
+                    let state = if selected {
+                        t!("performance.custom_active")
                     } else {
-                        response.on_hover_text("Switch to Custom mode");
-                    }
+                        t!("performance.custom_switch")
+                    };
+                    match mode_description(PerfMode::Custom) {
+                        Some(description) => {
+                            response.on_hover_text(format!("{}\n{}", description, state))
+                        }
+                        None => response.on_hover_text(state),
+                    };
                 },
             );
         }
@@ -375,3 +432,52 @@ fn get_button_color(ac_power: bool, selected: bool) -> Color32 {
         (false, false) => BATTERY_UNSELECTED_COLOR,
     }
 }
+
+// Text on filled (selected) buttons stays white; unfilled buttons follow the theme
+fn label_color(ui: &egui::Ui, selected: bool) -> Color32 {
+    if selected {
+        Color32::WHITE
+    } else {
+        ui.visuals().strong_text_color()
+    }
+}
+
+// Outline for dimmed and inactive buttons
+fn muted_stroke_color(ui: &egui::Ui) -> Color32 {
+    ui.visuals().widgets.noninteractive.bg_stroke.color
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn modes_follow_mode_order_without_custom() {
+        let mut available: Vec<PerfMode> = PerfMode::iter().collect();
+        available.reverse();
+        assert_eq!(ordered_modes(&available), MODE_ORDER.to_vec());
+    }
+
+    #[test]
+    fn modes_outside_the_order_follow_alphabetically() {
+        let available = [
+            PerfMode::Performance,
+            PerfMode::Custom,
+            PerfMode::Hyperboost,
+            PerfMode::Balanced,
+            PerfMode::Battery,
+            PerfMode::Silent,
+        ];
+        assert_eq!(
+            order_modes(&available, &[PerfMode::Silent, PerfMode::Balanced]),
+            [
+                PerfMode::Silent,
+                PerfMode::Balanced,
+                PerfMode::Battery,
+                PerfMode::Hyperboost,
+                PerfMode::Performance,
+            ]
+        );
+    }
+}
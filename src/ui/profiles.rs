@@ -0,0 +1,379 @@
+use crate::config::{AutoSwitchScope, AutoSwitchSettings, PowerPlanSettings};
+use crate::device::CompleteDeviceState;
+use crate::i18n::{t, tr};
+use crate::power::PowerPlan;
+use eframe::egui::{self, Align, Layout, RichText};
+
+// Profiles UI actions
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProfilesAction {
+    // No action
+    None,
+    // Save the current device state under a new name
+    Create(String),
+    // Rename the profile at the given index
+    Rename(usize, String),
+    // Write the profile at the given index to the device
+    Apply(usize),
+    // Remove the profile at the given index
+    Delete(usize),
+    // The AC/Battery auto-switch, power plan or startup profile settings were edited
+    AutoSwitchChanged,
+    // Save all named profiles to a file
+    Export,
+    // Add profiles from a file
+    Import,
+    // Copy the current settings into the other power source's profile
+    CopyToOtherPowerProfile,
+}
+
+/// Renders the collapsible named profiles section
+///
+/// # Arguments
+/// * `profiles` - Saved profiles as (name, state) pairs
+/// * `new_name` - Text buffer for the "save current" name field
+/// * `renaming` - Index and text buffer of the profile currently being renamed
+/// * `no_device` - Disables apply/create when no device is connected
+/// * `auto_switch` - Settings the AC/Battery auto-switch applies, edited in place
+/// * `power_plan` - Profiles applied on Windows power plan changes, edited in place
+/// * `startup_profile` - Profile applied at every launch, edited in place
+/// * `ac_power` - Current power source; the copy button targets the other one
+/// * `edited_power_profile` - AC (`true`) or Battery profile changed but not yet applied
+#[allow(clippy::too_many_arguments)]
+pub fn render_profiles_section(
+    ui: &mut egui::Ui,
+    profiles: &[(String, CompleteDeviceState)],
+    new_name: &mut String,
+    renaming: &mut Option<(usize, String)>,
+    no_device: bool,
+    auto_switch: &mut AutoSwitchSettings,
+    power_plan: &mut PowerPlanSettings,
+    startup_profile: &mut Option<String>,
+    ac_power: bool,
+    edited_power_profile: Option<bool>,
+) -> ProfilesAction {
+    let mut action = ProfilesAction::None;
+
+    ui.group(|ui| {
+        egui::CollapsingHeader::new(format!("📁 {}", t!("profiles.title")))
+            .id_salt("profiles_section")
+            .show(ui, |ui| {
+                if profiles.is_empty() {
+                    ui.add(
+                        egui::Label::new(RichText::new(t!("profiles.none")).weak())
+                            .selectable(false),
+                    );
+                }
+
+                for (index, (name, state)) in profiles.iter().enumerate() {
+                    if let Some(row_action) =
+                        render_profile_row(ui, index, name, state, renaming, no_device)
+                    {
+                        action = row_action;
+                    }
+                }
+
+                ui.separator();
+                render_create_row(ui, profiles, new_name, no_device, &mut action);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(
+                            !profiles.is_empty(),
+                            egui::Button::new(format!("📤 {}", t!("profiles.export"))),
+                        )
+                        .on_hover_text(t!("profiles.export_hint"))
+                        .clicked()
+                    {
+                        action = ProfilesAction::Export;
+                    }
+                    if ui
+                        .button(format!("📥 {}", t!("profiles.import")))
+                        .on_hover_text(t!("profiles.import_hint"))
+                        .clicked()
+                    {
+                        action = ProfilesAction::Import;
+                    }
+                });
+
+                if render_startup_profile(ui, startup_profile, profiles) {
+                    action = ProfilesAction::AutoSwitchChanged;
+                }
+
+                ui.separator();
+                if render_power_profile_copy(ui, ac_power, edited_power_profile, no_device) {
+                    action = ProfilesAction::CopyToOtherPowerProfile;
+                }
+                if render_auto_switch_scope(ui, auto_switch) {
+                    action = ProfilesAction::AutoSwitchChanged;
+                }
+                if render_power_plan_profiles(ui, power_plan, profiles) {
+                    action = ProfilesAction::AutoSwitchChanged;
+                }
+            });
+    });
+
+    action
+}
+
+/// Renders one profile: name (or rename field) with Apply / Rename / Delete buttons
+fn render_profile_row(
+    ui: &mut egui::Ui,
+    index: usize,
+    name: &str,
+    state: &CompleteDeviceState,
+    renaming: &mut Option<(usize, String)>,
+    no_device: bool,
+) -> Option<ProfilesAction> {
+    let mut action = None;
+
+    ui.horizontal(|ui| match renaming {
+        Some((rename_index, buffer)) if *rename_index == index => {
+            let response = ui.add(egui::TextEdit::singleline(buffer).desired_width(160.0));
+            let confirmed = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if ui.button("✔").clicked() || confirmed {
+                action = Some(ProfilesAction::Rename(index, buffer.trim().to_string()));
+            }
+            if ui.button("✖").clicked() {
+                *renaming = None;
+            }
+        }
+        _ => {
+            ui.add(egui::Label::new(name).selectable(false)).on_hover_text(t!(
+                "profiles.row_hint",
+                format!("{:?}", state.perf_mode),
+                super::fan::fan_mode_label(&format!("{:?}", state.fan_mode))
+            ));
+
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                if ui.button("🗑").on_hover_text(t!("profiles.delete")).clicked() {
+                    action = Some(ProfilesAction::Delete(index));
+                }
+                if ui.button("✏").on_hover_text(t!("profiles.rename")).clicked() {
+                    *renaming = Some((index, name.to_string()));
+                }
+                if ui.add_enabled(!no_device, egui::Button::new(t!("profiles.apply"))).clicked() {
+                    action = Some(ProfilesAction::Apply(index));
+                }
+            });
+        }
+    });
+
+    action
+}
+
+/// Renders the name field and button for saving the current state as a new profile
+fn render_create_row(
+    ui: &mut egui::Ui,
+    profiles: &[(String, CompleteDeviceState)],
+    new_name: &mut String,
+    no_device: bool,
+    action: &mut ProfilesAction,
+) {
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(new_name)
+                .hint_text(t!("profiles.new_name"))
+                .desired_width(160.0),
+        );
+
+        let name = new_name.trim();
+        let duplicate = profiles.iter().any(|(existing, _)| existing == name);
+        let enabled = !no_device && !name.is_empty() && !duplicate;
+        let response = ui
+            .add_enabled(enabled, egui::Button::new(format!("➕ {}", t!("profiles.save_current"))));
+        let response = if duplicate {
+            response.on_disabled_hover_text(t!("profiles.duplicate_name"))
+        } else {
+            response.on_hover_text(t!("profiles.save_current_hint"))
+        };
+        if response.clicked() {
+            *action = ProfilesAction::Create(name.to_string());
+        }
+    });
+}
+
+/// Renders the AC/Battery checkbox grid of settings the auto-switch applies,
+/// returning true when changed
+fn render_auto_switch_scope(ui: &mut egui::Ui, auto_switch: &mut AutoSwitchSettings) -> bool {
+    type ScopeField = fn(&mut AutoSwitchScope) -> &mut bool;
+    const ROWS: [(&str, ScopeField); 5] = [
+        ("profiles.scope_logo", |scope| &mut scope.logo),
+        ("profiles.scope_keyboard", |scope| &mut scope.keyboard),
+        ("profiles.scope_fan", |scope| &mut scope.fan),
+        ("profiles.scope_lights_always_on", |scope| &mut scope.lights_always_on),
+        ("profiles.scope_battery_care", |scope| &mut scope.battery_care),
+    ];
+    let mut changed = false;
+
+    egui::CollapsingHeader::new(format!("⚡ {}", t!("profiles.auto_switch_scope")))
+        .id_salt("auto_switch_scope")
+        .show(ui, |ui| {
+            changed |= ui
+                .checkbox(&mut auto_switch.enabled, t!("profiles.auto_switch"))
+                .on_hover_text(t!("profiles.auto_switch_hint"))
+                .changed();
+            ui.add(
+                egui::Label::new(RichText::new(t!("profiles.mode_follows_power")).weak())
+                    .selectable(false),
+            );
+            egui::Grid::new("auto_switch_scope_grid").show(ui, |ui| {
+                ui.label("");
+                ui.add(egui::Label::new(t!("profiles.ac")).selectable(false));
+                ui.add(egui::Label::new(t!("profiles.battery")).selectable(false));
+                ui.end_row();
+
+                for (key, field) in ROWS {
+                    ui.add(egui::Label::new(tr(key)).selectable(false));
+                    changed |= ui.checkbox(field(&mut auto_switch.ac), "").changed();
+                    changed |= ui.checkbox(field(&mut auto_switch.battery), "").changed();
+                    ui.end_row();
+                }
+            });
+
+            changed |= ui
+                .checkbox(
+                    &mut auto_switch.battery_lights_off,
+                    format!("💡 {}", t!("profiles.battery_lights_off")),
+                )
+                .on_hover_text(t!("profiles.battery_lights_off_hint"))
+                .changed();
+            if auto_switch.battery_lights_off {
+                changed |= ui
+                    .checkbox(&mut auto_switch.battery_logo_off, t!("profiles.logo_too"))
+                    .changed();
+            }
+        });
+
+    changed
+}
+
+/// Renders the profile-on-launch picker, returning true when changed
+fn render_startup_profile(
+    ui: &mut egui::Ui,
+    startup_profile: &mut Option<String>,
+    profiles: &[(String, CompleteDeviceState)],
+) -> bool {
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        let label = ui
+            .add(egui::Label::new(format!("🚀 {}", t!("profiles.on_launch"))).selectable(false))
+            .on_hover_text(t!("profiles.on_launch_hint"));
+        let selected = startup_profile.as_deref().unwrap_or(t!("profiles.nothing"));
+        egui::ComboBox::from_id_salt("startup_profile")
+            .selected_text(selected)
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(startup_profile.is_none(), t!("profiles.nothing")).clicked()
+                    && startup_profile.is_some()
+                {
+                    *startup_profile = None;
+                    changed = true;
+                }
+                for (name, _) in profiles {
+                    let is_selected = startup_profile.as_deref() == Some(name.as_str());
+                    if ui.selectable_label(is_selected, name.as_str()).clicked() && !is_selected {
+                        *startup_profile = Some(name.clone());
+                        changed = true;
+                    }
+                }
+            })
+            .response
+            .labelled_by(label.id);
+    });
+
+    changed
+}
+
+/// Renders the copy-to-other-power-profile button and the pending edit marker,
+/// returning true when the copy was requested
+fn render_power_profile_copy(
+    ui: &mut egui::Ui,
+    ac_power: bool,
+    edited_power_profile: Option<bool>,
+    no_device: bool,
+) -> bool {
+    let target = if ac_power { t!("profiles.battery") } else { t!("profiles.ac") };
+    let mut copy = false;
+
+    ui.horizontal(|ui| {
+        copy = ui
+            .add_enabled(
+                !no_device,
+                egui::Button::new(format!("📋 {}", t!("profiles.copy_to", target))),
+            )
+            .on_hover_text(t!("profiles.copy_to_hint", target))
+            .clicked();
+        if let Some(edited_ac) = edited_power_profile {
+            let (name, hint) = if edited_ac {
+                (t!("profiles.ac_edited"), t!("profiles.ac_edited_hint"))
+            } else {
+                (t!("profiles.battery_edited"), t!("profiles.battery_edited_hint"))
+            };
+            ui.add(egui::Label::new(RichText::new(name).weak()).selectable(false))
+                .on_hover_text(hint);
+        }
+    });
+
+    copy
+}
+
+/// Renders the Windows power plan → profile mapping, returning true when changed
+fn render_power_plan_profiles(
+    ui: &mut egui::Ui,
+    power_plan: &mut PowerPlanSettings,
+    profiles: &[(String, CompleteDeviceState)],
+) -> bool {
+    let mut changed = false;
+
+    egui::CollapsingHeader::new(format!("🖥 {}", t!("profiles.power_plan")))
+        .id_salt("power_plan_profiles")
+        .show(ui, |ui| {
+            changed |= ui
+                .checkbox(&mut power_plan.enabled, t!("profiles.power_plan_enabled"))
+                .on_hover_text(t!("profiles.power_plan_hint"))
+                .changed();
+
+            ui.add_enabled_ui(power_plan.enabled, |ui| {
+                egui::Grid::new("power_plan_grid").show(ui, |ui| {
+                    for plan in PowerPlan::ALL {
+                        ui.add(egui::Label::new(power_plan_label(plan)).selectable(false));
+                        let slot = power_plan.profile_mut(plan);
+                        let selected = slot.as_deref().unwrap_or(t!("profiles.do_nothing"));
+                        egui::ComboBox::from_id_salt(("power_plan_profile", plan.label()))
+                            .selected_text(selected)
+                            .show_ui(ui, |ui| {
+                                if ui
+                                    .selectable_label(slot.is_none(), t!("profiles.do_nothing"))
+                                    .clicked()
+                                    && slot.is_some()
+                                {
+                                    *slot = None;
+                                    changed = true;
+                                }
+                                for (name, _) in profiles {
+                                    let is_selected = slot.as_deref() == Some(name.as_str());
+                                    if ui.selectable_label(is_selected, name).clicked()
+                                        && !is_selected
+                                    {
+                                        *slot = Some(name.clone());
+                                        changed = true;
+                                    }
+                                }
+                            });
+                        ui.end_row();
+                    }
+                });
+            });
+        });
+
+    changed
+}
+
+/// Windows' name for `plan` in the active language
+fn power_plan_label(plan: PowerPlan) -> &'static str {
+    match plan {
+        PowerPlan::HighPerformance => t!("profiles.plan_high_performance"),
+        PowerPlan::Balanced => t!("profiles.plan_balanced"),
+        PowerPlan::PowerSaver => t!("profiles.plan_power_saver"),
+    }
+}
@@ -0,0 +1,372 @@
+use super::footer::FooterAction;
+use crate::config::{ApiSettings, Settings, UI_SCALE_MAX, UI_SCALE_MIN};
+use crate::i18n::{t, Language};
+use crate::messaging::{MessageDurations, MAX_STACKED_MESSAGES};
+use crate::system::autostart::AutostartState;
+use crate::system::{TempUnit, Theme};
+use eframe::egui::{self, Color32, RichText};
+
+/// Renders the settings hub shown from the footer's gear button, grouped by topic.
+/// Every persisted option lives in `Settings`; changes are reported as `SettingsChanged`.
+pub fn render_settings_menu(
+    ui: &mut egui::Ui,
+    settings: &mut Settings,
+    tray_available: bool,
+    autostart: &AutostartState,
+) -> Option<FooterAction> {
+    let mut action = None;
+
+    section_heading(ui, t!("settings.appearance"));
+    ui.horizontal(|ui| {
+        ui.add(egui::Label::new(format!("🌐 {}", t!("settings.language"))).selectable(false));
+        for language in Language::ALL {
+            if ui.selectable_value(&mut settings.language, language, language.label()).changed() {
+                action = Some(FooterAction::SettingsChanged);
+            }
+        }
+    });
+
+    ui.horizontal(|ui| {
+        ui.add(egui::Label::new(format!("🎨 {}", t!("settings.theme"))).selectable(false));
+        for theme in Theme::ALL {
+            if ui.selectable_value(&mut settings.theme, theme, theme.label()).changed() {
+                action = Some(FooterAction::SettingsChanged);
+            }
+        }
+    });
+
+    if render_ui_scale(ui, &mut settings.ui_scale) {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    ui.horizontal(|ui| {
+        ui.add(egui::Label::new(format!("🌡 {}", t!("settings.temperature"))).selectable(false));
+        for unit in [TempUnit::Celsius, TempUnit::Fahrenheit] {
+            if ui.selectable_value(&mut settings.temp_unit, unit, unit.symbol()).changed() {
+                action = Some(FooterAction::SettingsChanged);
+            }
+        }
+    });
+
+    if ui
+        .checkbox(&mut settings.fan_speed_percent, format!("🌀 {}", t!("settings.fan_percent")))
+        .on_hover_text(t!("settings.fan_percent_hint"))
+        .changed()
+    {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    if ui
+        .checkbox(&mut settings.brightness_fade, format!("🌅 {}", t!("settings.fade_brightness")))
+        .on_hover_text(t!("settings.fade_brightness_hint"))
+        .changed()
+    {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    ui.separator();
+    section_heading(ui, t!("settings.messages"));
+    if ui
+        .checkbox(
+            &mut settings.switch_notifications,
+            format!("🔔 {}", t!("settings.notify_switch")),
+        )
+        .on_hover_text(t!("settings.notify_switch_hint"))
+        .changed()
+    {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    if render_message_durations(ui, &mut settings.message_durations) {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    ui.horizontal(|ui| {
+        ui.add(egui::Label::new(format!("🗂 {}", t!("settings.messages_shown"))).selectable(false))
+            .on_hover_text(t!("settings.messages_shown_hint"));
+        if ui
+            .add(
+                egui::DragValue::new(&mut settings.visible_messages)
+                    .range(1..=MAX_STACKED_MESSAGES),
+            )
+            .changed()
+        {
+            action = Some(FooterAction::SettingsChanged);
+        }
+    });
+
+    ui.separator();
+    section_heading(ui, t!("settings.control"));
+    if ui
+        .checkbox(&mut settings.monitor_only, format!("👁 {}", t!("settings.monitor_only")))
+        .on_hover_text(t!("settings.monitor_only_hint"))
+        .changed()
+    {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    if ui
+        .checkbox(&mut settings.manual_control_only, format!("✋ {}", t!("settings.manual_only")))
+        .on_hover_text(t!("settings.manual_only_hint"))
+        .changed()
+    {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    if ui
+        .checkbox(
+            &mut settings.confirm_perf_change_manual_fan,
+            format!("❔ {}", t!("settings.confirm_mode")),
+        )
+        .on_hover_text(t!("settings.confirm_mode_hint"))
+        .changed()
+    {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::Label::new(format!("🔥 {}", t!("settings.throttle_warning"))).selectable(false),
+        )
+        .on_hover_text(t!("settings.throttle_warning_hint"));
+        if ui
+            .add(
+                egui::DragValue::new(&mut settings.throttle_warning_temp)
+                    .range(60..=105)
+                    .suffix(" °C"),
+            )
+            .changed()
+        {
+            action = Some(FooterAction::SettingsChanged);
+        }
+    });
+
+    ui.horizontal(|ui| {
+        if ui
+            .button(format!("🏠 {}", t!("settings.reset_home")))
+            .on_hover_text(t!("settings.reset_home_hint"))
+            .clicked()
+        {
+            action = Some(FooterAction::ResetToHome);
+        }
+        if ui
+            .button(t!("settings.home_to_current"))
+            .on_hover_text(t!("settings.home_to_current_hint"))
+            .clicked()
+        {
+            action = Some(FooterAction::SetHomeToCurrent);
+        }
+        if ui
+            .button(t!("settings.home_defaults"))
+            .on_hover_text(t!("settings.home_defaults_hint"))
+            .clicked()
+        {
+            action = Some(FooterAction::RestoreHomeDefaults);
+        }
+    });
+
+    ui.separator();
+    section_heading(ui, t!("settings.integration"));
+    if tray_available
+        && ui
+            .checkbox(&mut settings.close_to_tray, format!("📥 {}", t!("settings.close_to_tray")))
+            .on_hover_text(t!("settings.close_to_tray_hint"))
+            .changed()
+    {
+        action = Some(FooterAction::SettingsChanged);
+    }
+    if ui
+        .checkbox(&mut settings.start_minimized, format!("🗕 {}", t!("settings.start_minimized")))
+        .on_hover_text(if tray_available {
+            t!("settings.start_minimized_tray_hint")
+        } else {
+            t!("settings.start_minimized_hint")
+        })
+        .changed()
+    {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    if cfg!(target_os = "windows") {
+        if let Some(autostart_action) = render_autostart_toggle(ui, autostart) {
+            action = Some(autostart_action);
+        }
+    }
+
+    if ui
+        .checkbox(&mut settings.check_for_updates, format!("⬆ {}", t!("settings.check_updates")))
+        .on_hover_text(t!("settings.check_updates_hint"))
+        .changed()
+    {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    if render_api_settings(ui, &mut settings.api) {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    if cfg!(target_os = "windows")
+        && ui
+            .checkbox(&mut settings.telemetry_pipe, format!("📡 {}", t!("settings.telemetry_pipe")))
+            .on_hover_text(t!("settings.telemetry_pipe_hint"))
+            .changed()
+    {
+        action = Some(FooterAction::SettingsChanged);
+    }
+
+    ui.separator();
+    if ui
+        .button(format!("🗕 {}", t!("settings.compact_view")))
+        .on_hover_text(t!("settings.compact_view_hint"))
+        .clicked()
+    {
+        action = Some(FooterAction::EnterCompact);
+    }
+    if ui
+        .button(format!("🗗 {}", t!("settings.reset_window")))
+        .on_hover_text(t!("settings.reset_window_hint"))
+        .clicked()
+    {
+        action = Some(FooterAction::ResetWindow);
+    }
+    if ui
+        .button(format!("📋 {}", t!("settings.copy_diagnostics")))
+        .on_hover_text(t!("settings.copy_diagnostics_hint"))
+        .clicked()
+    {
+        action = Some(FooterAction::CopyDiagnostics);
+    }
+
+    action
+}
+
+/// Scale slider with a "System" toggle that follows the monitor's DPI.
+/// The slider value is committed on release so the UI does not rescale under the pointer.
+fn render_ui_scale(ui: &mut egui::Ui, ui_scale: &mut Option<f32>) -> bool {
+    let mut changed = false;
+    let drag_id = egui::Id::new("ui_scale_drag");
+    let system_scale = ui.ctx().native_pixels_per_point().unwrap_or(1.0);
+
+    ui.horizontal(|ui| {
+        let label =
+            ui.add(egui::Label::new(format!("🔍 {}", t!("settings.ui_scale"))).selectable(false));
+        let mut follow_system = ui_scale.is_none();
+        if ui
+            .checkbox(&mut follow_system, t!("settings.system_scale"))
+            .on_hover_text(t!("settings.system_scale_hint"))
+            .changed()
+        {
+            *ui_scale = if follow_system {
+                None
+            } else {
+                Some(system_scale.clamp(UI_SCALE_MIN, UI_SCALE_MAX))
+            };
+            changed = true;
+        }
+
+        let committed = ui_scale.unwrap_or(system_scale).clamp(UI_SCALE_MIN, UI_SCALE_MAX);
+        let mut value = ui.data(|d| d.get_temp::<f32>(drag_id)).unwrap_or(committed);
+        let response = ui
+            .add_enabled(
+                ui_scale.is_some(),
+                egui::Slider::new(&mut value, UI_SCALE_MIN..=UI_SCALE_MAX)
+                    .step_by(0.05)
+                    .fixed_decimals(2)
+                    .suffix("×"),
+            )
+            .labelled_by(label.id);
+        if response.dragged() {
+            ui.data_mut(|d| d.insert_temp(drag_id, value));
+        } else {
+            ui.data_mut(|d| d.remove::<f32>(drag_id));
+            if value != committed {
+                *ui_scale = Some(value);
+                changed = true;
+            }
+        }
+    });
+
+    changed
+}
+
+/// Small bold heading above a group of settings
+fn section_heading(ui: &mut egui::Ui, title: &str) {
+    ui.add(egui::Label::new(RichText::new(title).strong()).selectable(false));
+}
+
+/// Renders the status/error message display time fields, returning true when changed
+fn render_message_durations(ui: &mut egui::Ui, durations: &mut MessageDurations) -> bool {
+    let range = MessageDurations::MIN_SECS..=MessageDurations::MAX_SECS;
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        ui.add(egui::Label::new(format!("💬 {}", t!("settings.show_messages"))).selectable(false))
+            .on_hover_text(t!("settings.show_messages_hint"));
+        changed |= ui
+            .add(egui::DragValue::new(&mut durations.normal_secs).range(range.clone()).suffix(" s"))
+            .on_hover_text(t!("settings.status_messages"))
+            .changed();
+        changed |= ui
+            .add(egui::DragValue::new(&mut durations.critical_secs).range(range).suffix(" s"))
+            .on_hover_text(t!("settings.errors"))
+            .changed();
+    });
+
+    changed
+}
+
+/// Renders the automation endpoint toggle, port and token, returning true when changed
+fn render_api_settings(ui: &mut egui::Ui, api: &mut ApiSettings) -> bool {
+    let mut changed = ui
+        .checkbox(&mut api.enabled, format!("🔌 {}", t!("settings.api")))
+        .on_hover_text(t!("settings.api_hint"))
+        .changed();
+
+    if api.enabled {
+        ui.horizontal(|ui| {
+            ui.add(egui::Label::new(t!("settings.api_port")).selectable(false));
+            changed |= ui.add(egui::DragValue::new(&mut api.port).range(1024..=65535)).changed();
+        });
+        ui.horizontal(|ui| {
+            ui.add(egui::Label::new(t!("settings.api_token")).selectable(false))
+                .on_hover_text(t!("settings.api_token_hint"));
+            changed |= ui
+                .add(egui::TextEdit::singleline(&mut api.token).password(true).desired_width(120.0))
+                .changed();
+        });
+    }
+
+    changed
+}
+
+/// Renders the launch-at-login toggle, with a repair button when the entry is stale
+fn render_autostart_toggle(ui: &mut egui::Ui, autostart: &AutostartState) -> Option<FooterAction> {
+    let mut action = None;
+
+    let mut enabled = *autostart != AutostartState::Disabled;
+    if ui.checkbox(&mut enabled, format!("🚀 {}", t!("settings.autostart"))).changed() {
+        action = Some(FooterAction::SetAutostart(enabled));
+    }
+
+    if let AutostartState::Stale(path) = autostart {
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::Label::new(
+                    RichText::new(format!("⚠ {}", t!("settings.autostart_stale")))
+                        .color(Color32::ORANGE),
+                )
+                .selectable(false),
+            )
+            .on_hover_text(path);
+            if ui
+                .button(t!("settings.autostart_repair"))
+                .on_hover_text(t!("settings.autostart_repair_hint"))
+                .clicked()
+            {
+                action = Some(FooterAction::SetAutostart(true));
+            }
+        });
+    }
+
+    action
+}
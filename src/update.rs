@@ -0,0 +1,89 @@
+// Check GitHub releases for a newer build
+use anyhow::{Context, Result};
+use std::process::Command;
+use std::sync::mpsc;
+
+const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/Fatalution/r-helper/releases/latest";
+/// Give up quickly so an offline or slow network never keeps the thread around
+const REQUEST_TIMEOUT_SECS: &str = "5";
+
+/// A published release newer than the running build
+#[derive(Debug, Clone, PartialEq)]
+pub struct Release {
+    /// Tag as published, e.g. "v0.5.0"
+    pub tag: String,
+    /// Release page on GitHub
+    pub url: String,
+}
+
+/// Look up the latest release on a background thread.
+/// Yields `Some` only when it is newer than this build; failures are logged and yield `None`.
+pub fn spawn_check() -> mpsc::Receiver<Option<Release>> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let newer = match fetch_latest_release() {
+            Ok(release) => is_newer(&release.tag, APP_VERSION).then_some(release),
+            Err(e) => {
+                crate::utils::log_debug(&format!("Update check failed: {:#}", e));
+                None
+            }
+        };
+        let _ = sender.send(newer);
+    });
+    receiver
+}
+
+fn fetch_latest_release() -> Result<Release> {
+    // curl ships with Windows 10 and later, which avoids pulling in an HTTP stack
+    let mut cmd = Command::new("curl");
+    cmd.args([
+        "--silent",
+        "--fail",
+        "--location",
+        "--max-time",
+        REQUEST_TIMEOUT_SECS,
+        "--header",
+        "Accept: application/vnd.github+json",
+        "--user-agent",
+        concat!("r-helper/", env!("CARGO_PKG_VERSION")),
+        LATEST_RELEASE_URL,
+    ]);
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(crate::utils::CREATE_NO_WINDOW);
+    }
+
+    let output = cmd.output().context("Failed to run curl")?;
+    if !output.status.success() {
+        anyhow::bail!("Release request failed ({})", output.status);
+    }
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).context("Invalid release response")?;
+    let tag = json["tag_name"].as_str().context("Release has no tag")?.to_string();
+    let url = json["html_url"].as_str().context("Release has no page")?.to_string();
+    Ok(Release { tag, url })
+}
+
+/// Compare dotted numeric versions, ignoring a leading "v" and any pre-release suffix
+fn is_newer(tag: &str, current: &str) -> bool {
+    fn parse(version: &str) -> Vec<u64> {
+        version
+            .trim()
+            .trim_start_matches(['v', 'V'])
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    }
+
+    let (mut latest, mut running) = (parse(tag), parse(current));
+    let len = latest.len().max(running.len());
+    latest.resize(len, 0);
+    running.resize(len, 0);
+    latest > running
+}
@@ -1,10 +1,19 @@
 // Utility functions shared across the app
 
 use anyhow::Result;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+#[cfg(target_os = "windows")]
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 pub use anyhow;
 
+const CONFIG_DIR_NAME: &str = "r-helper";
+
 // System Command Execution
 
 /// Execute a PowerShell script with consistent configuration
@@ -16,7 +25,7 @@ pub fn execute_powershell_command(script: &str) -> Result<String> {
     use std::os::windows::process::CommandExt;
 
     let mut cmd = Command::new(POWERSHELL_PATH);
-    cmd.args(&["-NoProfile", "-ExecutionPolicy", "Bypass", "-Command"])
+    cmd.args(["-NoProfile", "-ExecutionPolicy", "Bypass", "-Command"])
         .arg(script)
         .creation_flags(CREATE_NO_WINDOW);
 
@@ -37,13 +46,70 @@ pub fn execute_powershell_command(script: &str) -> Result<String> {
 }
 
 #[cfg(not(target_os = "windows"))]
+#[allow(dead_code)]
 pub fn execute_powershell_command(_script: &str) -> Result<String> {
     Err(anyhow::anyhow!("PowerShell is only available on Windows"))
 }
 
+/// Like `execute_powershell_command`, but kills the script once `timeout` has passed.
+/// For queries that can hang on broken drivers (WMI), where no answer beats a late one.
+#[cfg(target_os = "windows")]
+pub fn execute_powershell_command_timeout(script: &str, timeout: Duration) -> Result<String> {
+    use std::io::Read;
+    use std::os::windows::process::CommandExt;
+    use std::process::Stdio;
+
+    const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+    let mut child = Command::new(POWERSHELL_PATH)
+        .args(["-NoProfile", "-ExecutionPolicy", "Bypass", "-Command"])
+        .arg(script)
+        .creation_flags(CREATE_NO_WINDOW)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| anyhow::anyhow!("Failed to execute PowerShell: {}", e))?;
+
+    // Drain both pipes off-thread so a chatty script cannot stall on a full pipe
+    let drain = |pipe: Option<Box<dyn Read + Send>>| {
+        std::thread::spawn(move || {
+            let mut text = String::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_string(&mut text);
+            }
+            text
+        })
+    };
+    let stdout = drain(child.stdout.take().map(|pipe| Box::new(pipe) as Box<dyn Read + Send>));
+    let stderr = drain(child.stderr.take().map(|pipe| Box::new(pipe) as Box<dyn Read + Send>));
+
+    let started = std::time::Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if started.elapsed() >= timeout {
+            // Killing closes the pipes, which ends the drain threads
+            let _ = child.kill();
+            let _ = child.wait();
+            anyhow::bail!("PowerShell timed out after {:.1}s", timeout.as_secs_f32());
+        }
+        std::thread::sleep(WAIT_POLL_INTERVAL);
+    };
+
+    let stdout = stdout.join().unwrap_or_default().trim().to_string();
+    let stderr = stderr.join().unwrap_or_default();
+    if !stderr.trim().is_empty() && status.code() != Some(0) {
+        Err(anyhow::anyhow!("PowerShell error: {}", stderr.trim()))
+    } else {
+        Ok(stdout)
+    }
+}
+
 // String Processing Utilities
 
 /// Clean and format strings for display
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
 pub fn clean_display_string(input: &str) -> String {
     input
         .trim()
@@ -54,29 +120,41 @@ pub fn clean_display_string(input: &str) -> String {
         .join(" ")
 }
 
-// Device Command Utilities
+// Retries
 
-/// Helper function for device command execution with standard error handling
-/// Returns Ok(success_message) or Err(error_message)
-pub fn execute_device_command_simple<T, F>(
-    device_opt: Option<&librazer::device::Device>,
-    command: F,
-    success_msg: &str,
-    error_prefix: &str,
-) -> Result<String, String>
+/// Attempts made by `with_default_retry`; some devices NAK the first request after idling
+pub const DEFAULT_RETRY_ATTEMPTS: u32 = 2;
+/// Pause between attempts made by `with_default_retry`
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(30);
+
+/// Run `op` up to `attempts` times, sleeping `delay` between failures.
+/// Returns the first success or the last error.
+pub fn with_retry<T, F>(attempts: u32, delay: Duration, mut op: F) -> Result<T>
 where
-    F: FnOnce(&librazer::device::Device) -> Result<T>,
+    F: FnMut() -> Result<T>,
 {
-    if let Some(device) = device_opt {
-        match command(device) {
-            Ok(_) => Ok(success_msg.to_string()),
-            Err(e) => Err(format!("{}: {}", error_prefix, e)),
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(e) => {
+                log_debug(&format!("Device request failed (attempt {}), retrying: {}", attempt, e));
+                attempt += 1;
+                std::thread::sleep(delay);
+            }
         }
-    } else {
-        Err("No device connected".to_string())
     }
 }
 
+/// `with_retry` using the default attempts and delay
+pub fn with_default_retry<T, F>(op: F) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+{
+    with_retry(DEFAULT_RETRY_ATTEMPTS, DEFAULT_RETRY_DELAY, op)
+}
+
 // Device State Management
 
 /// Batch read multiple device states with error handling
@@ -92,9 +170,9 @@ impl<'a> DeviceStateReader<'a> {
 
     pub fn read<T, F>(&mut self, operation: F, operation_name: &str) -> Option<T>
     where
-        F: FnOnce(&librazer::device::Device) -> Result<T>,
+        F: Fn(&librazer::device::Device) -> Result<T>,
     {
-        match operation(self.device) {
+        match with_default_retry(|| operation(self.device)) {
             Ok(value) => Some(value),
             Err(e) => {
                 self.errors.push(format!("Failed to read {}: {}", operation_name, e));
@@ -108,6 +186,97 @@ impl<'a> DeviceStateReader<'a> {
     }
 }
 
+// Logging
+
+const LOG_FILE_NAME: &str = "r-helper.log";
+const LOG_MAX_BYTES: u64 = 1024 * 1024;
+
+static VERBOSE_LOGGING: AtomicBool = AtomicBool::new(false);
+static LOG_LOCK: Mutex<()> = Mutex::new(());
+
+/// Enable logging of background (normally silent) failures; follows the Debug checkbox
+pub fn set_verbose_logging(enabled: bool) {
+    VERBOSE_LOGGING.store(enabled, Ordering::Relaxed);
+}
+
+/// Append an error to the log file (always written)
+pub fn log_error(message: &str) {
+    append_log_line("ERROR", message);
+}
+
+/// Append a warning to the log file (always written)
+pub fn log_warning(message: &str) {
+    append_log_line("WARN", message);
+}
+
+/// Append a diagnostic line to the log file, only when verbose logging is enabled
+pub fn log_debug(message: &str) {
+    if VERBOSE_LOGGING.load(Ordering::Relaxed) {
+        append_log_line("DEBUG", message);
+    }
+}
+
+/// Directory holding all persisted app data (`%APPDATA%\r-helper` on Windows)
+pub fn config_dir() -> Option<PathBuf> {
+    #[cfg(target_os = "windows")]
+    let base = std::env::var_os("APPDATA").map(PathBuf::from);
+    #[cfg(not(target_os = "windows"))]
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")));
+
+    base.map(|dir| dir.join(CONFIG_DIR_NAME))
+}
+
+/// Append one timestamped line to `r-helper.log`, rotating to `r-helper.log.1` at ~1MB.
+/// Logging must never disturb the app, so all I/O errors are ignored.
+fn append_log_line(level: &str, message: &str) {
+    let Some(dir) = config_dir() else {
+        return;
+    };
+    let _guard = LOG_LOCK.lock();
+    if fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+
+    let path = dir.join(LOG_FILE_NAME);
+    if fs::metadata(&path).is_ok_and(|meta| meta.len() >= LOG_MAX_BYTES) {
+        let _ = fs::rename(&path, dir.join(format!("{}.1", LOG_FILE_NAME)));
+    }
+
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&path) {
+        let _ =
+            writeln!(file, "{} [{}] {}", format_utc_timestamp(SystemTime::now()), level, message);
+    }
+}
+
+/// Format as `YYYY-MM-DD HH:MM:SS UTC` without pulling in a date crate
+fn format_utc_timestamp(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (days, day_secs) = (secs / 86_400, secs % 86_400);
+
+    // Civil-from-days (Howard Hinnant's algorithm)
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        day_secs / 3_600,
+        (day_secs % 3_600) / 60,
+        day_secs % 60
+    )
+}
+
 // Constants
 
 /// PowerShell executable path on Windows